@@ -0,0 +1,101 @@
+use cantools::decode::TryDecode;
+use cantools::logging::{CANDumpEntryRef, CANDumpLogEntry, CANDumpLogEntryRef};
+use cantools::signals::{Decoder, Signed, Unsigned};
+use cantools::utils::Endian;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+const DATA: [u8; 8] = [0x81, 0x42, 0x24, 0x18, 0xFF, 0x00, 0xA5, 0x5A];
+
+/// Signal lengths commonly found in DBC files.
+const LENGTHS: [u16; 4] = [8, 16, 32, 64];
+
+fn decode_little_endian(c: &mut Criterion) {
+    let data = DATA;
+
+    let unsigned = Unsigned::new(4, 16, 0.5, 10.0, Endian::Little).unwrap();
+    c.bench_function("unsigned little-endian 16 bits", |b| {
+        b.iter(|| unsigned.try_decode(black_box(&data)))
+    });
+
+    let signed = Signed::new(3, 61, 0.5, 10.0, Endian::Little).unwrap();
+    c.bench_function("signed little-endian 61 bits", |b| {
+        b.iter(|| signed.try_decode(black_box(&data)))
+    });
+}
+
+fn decode_lengths(c: &mut Criterion) {
+    let data = DATA;
+
+    for length in LENGTHS {
+        for (name, endian, start) in [
+            ("little-endian", Endian::Little, 0),
+            ("big-endian", Endian::Big, 7),
+        ] {
+            let unsigned = Unsigned::new(start, length, 0.5, 10.0, endian).unwrap();
+            c.bench_function(&format!("unsigned {} {} bits", name, length), |b| {
+                b.iter(|| unsigned.try_decode(black_box(&data)))
+            });
+        }
+
+        for (name, endian, start) in [
+            ("little-endian", Endian::Little, 0),
+            ("big-endian", Endian::Big, 7),
+        ] {
+            let signed = Signed::new(start, length, 0.5, 10.0, endian).unwrap();
+            c.bench_function(&format!("signed {} {} bits", name, length), |b| {
+                b.iter(|| signed.try_decode(black_box(&data)))
+            });
+        }
+    }
+}
+
+fn decode_optimized(c: &mut Criterion) {
+    let data = DATA;
+    let unsigned = Unsigned::new(4, 32, 0.5, 10.0, Endian::Little).unwrap();
+
+    let compiled = unsigned.compile();
+    c.bench_function("compiled unsigned little-endian 32 bits", |b| {
+        b.iter(|| compiled.try_decode(black_box(&data)))
+    });
+
+    let mut decoder = Decoder::new();
+    c.bench_function("decoder unsigned little-endian 32 bits", |b| {
+        b.iter(|| decoder.decode(&unsigned, black_box(&data)))
+    });
+}
+
+fn parse_and_decode(c: &mut Criterion) {
+    let speed = Unsigned::new(0, 16, 0.01, 0.0, Endian::Little).unwrap();
+
+    let line = "(1647037105.079609) vcan0 00000042#81422418FF00A55A";
+    c.bench_function("candump log line parse and decode", |b| {
+        b.iter(|| {
+            let entry = CANDumpLogEntryRef::parse(black_box(line)).unwrap();
+            speed.try_decode(&entry)
+        })
+    });
+
+    c.bench_function("candump log line parse owned and decode", |b| {
+        b.iter(|| {
+            let entry = black_box(line).parse::<CANDumpLogEntry>().unwrap();
+            speed.try_decode(&entry)
+        })
+    });
+
+    let line = "vcan0 00000042 [8] 81 42 24 18 FF 00 A5 5A";
+    c.bench_function("candump raw line parse and decode", |b| {
+        b.iter(|| {
+            let entry = CANDumpEntryRef::parse(black_box(line)).unwrap();
+            speed.try_decode(&entry)
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    decode_little_endian,
+    decode_lengths,
+    decode_optimized,
+    parse_and_decode
+);
+criterion_main!(benches);
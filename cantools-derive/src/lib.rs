@@ -0,0 +1,249 @@
+//! Derive macros for [cantools](https://docs.rs/cantools).
+//!
+//! The macros are re-exported by `cantools` if its `derive` feature is enabled. Use them through
+//! `cantools::decode::CanDecode` rather than depending on this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{
+    parse_macro_input, Data, DeriveInput, Expr, ExprLit, ExprUnary, Field, Fields, Lit, LitInt,
+    LitStr, UnOp,
+};
+
+/// Derives `cantools::decode::CanDecode` for a struct with named fields.
+///
+/// Every field carries a `#[signal(...)]` attribute describing the signal it is decoded from:
+/// - `bit = N` decodes the single bit `N` using `Bit`.
+/// - `start = N, length = M` decodes `M` bits starting at bit `N` using `Unsigned`, or `Signed` if
+///   the flag `signed` is given as well. The optional `factor` and `offset` default to `1.0` and
+///   `0.0`, the optional `endian` accepts the strings parsed by `Endian`, e.g., `"big"`, and
+///   defaults to little-endian.
+///
+/// Literal factors and offsets yielding a non-finite range are rejected at compile time. Other
+/// expressions, e.g., constants, are checked when decoding, and a non-finite range makes
+/// `try_decode_from` return `DecodeError::Overflow`.
+///
+/// The type of the field selects the decoded type, e.g., `bool` or `f64` for bits and `f64` for
+/// `Unsigned` and `Signed` signals.
+#[proc_macro_derive(CanDecode, attributes(signal))]
+pub fn derive_can_decode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_can_decode(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand_can_decode(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new(
+                    input.span(),
+                    "CanDecode requires a struct with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                input.span(),
+                "CanDecode can only be derived for structs",
+            ))
+        }
+    };
+
+    let decoded_fields = fields
+        .iter()
+        .map(expand_field)
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::cantools::decode::CanDecode for #name #ty_generics #where_clause {
+            fn try_decode_from<D: ::cantools::data::CANRead>(
+                data: &D,
+            ) -> ::core::result::Result<Self, ::cantools::decode::DecodeError> {
+                ::core::result::Result::Ok(#name {
+                    #(#decoded_fields,)*
+                })
+            }
+        }
+    })
+}
+
+/// The signal described by the `#[signal(...)]` attribute of a field.
+#[derive(Default)]
+struct SignalAttribute {
+    bit: Option<LitInt>,
+    start: Option<LitInt>,
+    length: Option<LitInt>,
+    factor: Option<Expr>,
+    offset: Option<Expr>,
+    endian: Option<LitStr>,
+    signed: bool,
+}
+
+fn parse_signal_attribute(field: &Field) -> syn::Result<SignalAttribute> {
+    let attr = field
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("signal"))
+        .ok_or_else(|| syn::Error::new(field.span(), "missing #[signal(...)] attribute"))?;
+
+    let mut signal = SignalAttribute::default();
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("bit") {
+            signal.bit = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("start") {
+            signal.start = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("length") {
+            signal.length = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("factor") {
+            signal.factor = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("offset") {
+            signal.offset = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("endian") {
+            signal.endian = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("signed") {
+            signal.signed = true;
+        } else {
+            return Err(meta.error("unknown signal property"));
+        }
+        Ok(())
+    })?;
+    Ok(signal)
+}
+
+fn expand_endian(endian: &Option<LitStr>) -> syn::Result<TokenStream2> {
+    let value = match endian {
+        Some(endian) => endian.value(),
+        None => return Ok(quote!(::cantools::utils::Endian::Little)),
+    };
+
+    if value.eq_ignore_ascii_case("little") || value.eq_ignore_ascii_case("intel") {
+        Ok(quote!(::cantools::utils::Endian::Little))
+    } else if value.eq_ignore_ascii_case("big") || value.eq_ignore_ascii_case("motorola") {
+        Ok(quote!(::cantools::utils::Endian::Big))
+    } else if value.eq_ignore_ascii_case("native") {
+        Ok(quote!(::cantools::utils::Endian::native()))
+    } else {
+        Err(syn::Error::new(endian.span(), "unknown endian"))
+    }
+}
+
+/// Evaluates `expr` if it is a, possibly negated, integer or float literal.
+fn literal_value(expr: &Expr) -> Option<f64> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => lit.base10_parse::<f64>().ok(),
+        Expr::Lit(ExprLit {
+            lit: Lit::Float(lit),
+            ..
+        }) => lit.base10_parse::<f64>().ok(),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => literal_value(expr).map(|value| -value),
+        Expr::Paren(paren) => literal_value(&paren.expr),
+        Expr::Group(group) => literal_value(&group.expr),
+        _ => None,
+    }
+}
+
+/// Rejects literal `factor` and `offset` attributes scaling the raw range of a signal with
+/// `length` bits to a non-finite range, mirroring the check of `Unsigned::new` and `Signed::new`.
+fn check_range(signal: &SignalAttribute, length: u16, field: &Field) -> syn::Result<()> {
+    let factor = match &signal.factor {
+        Some(factor) => literal_value(factor),
+        None => Some(1.0),
+    };
+    let offset = match &signal.offset {
+        Some(offset) => literal_value(offset),
+        None => Some(0.0),
+    };
+    let (factor, offset) = match (factor, offset) {
+        (Some(factor), Some(offset)) => (factor, offset),
+        _ => return Ok(()),
+    };
+
+    let (raw_min, raw_max) = if signal.signed {
+        let half = 2f64.powi(length as i32 - 1);
+        (-half, half - 1.0)
+    } else {
+        (0.0, 2f64.powi(length as i32) - 1.0)
+    };
+    let min = raw_min * factor + offset;
+    let max = raw_max * factor + offset;
+    if min.is_finite() && max.is_finite() {
+        Ok(())
+    } else {
+        let message = "factor and offset scale the signal to a non-finite range";
+        Err(match (&signal.factor, &signal.offset) {
+            (Some(factor), _) => syn::Error::new_spanned(factor, message),
+            (None, Some(offset)) => syn::Error::new_spanned(offset, message),
+            (None, None) => syn::Error::new(field.span(), message),
+        })
+    }
+}
+
+fn expand_field(field: &Field) -> syn::Result<TokenStream2> {
+    let ident = &field.ident;
+    let signal = parse_signal_attribute(field)?;
+
+    let constructor = match (&signal.bit, &signal.start, &signal.length) {
+        (Some(bit), None, None) => {
+            let bit = bit.base10_parse::<u16>()?;
+            quote!(::cantools::signals::Bit::new(#bit))
+        }
+        (None, Some(start), Some(length_lit)) => {
+            let start = start.base10_parse::<u16>()?;
+            let length = length_lit.base10_parse::<u16>()?;
+            if !(1..=64).contains(&length) {
+                return Err(syn::Error::new(
+                    length_lit.span(),
+                    "length must lie between 1 and 64",
+                ));
+            }
+            check_range(&signal, length, field)?;
+
+            let factor = match &signal.factor {
+                Some(factor) => quote!((#factor) as f64),
+                None => quote!(1.0),
+            };
+            let offset = match &signal.offset {
+                Some(offset) => quote!((#offset) as f64),
+                None => quote!(0.0),
+            };
+            let endian = expand_endian(&signal.endian)?;
+            let kind = if signal.signed {
+                quote!(::cantools::signals::Signed)
+            } else {
+                quote!(::cantools::signals::Unsigned)
+            };
+
+            // the length is checked above, so construction only fails for non-finite ranges of
+            // non-literal factors and offsets
+            quote! {
+                #kind::new(#start, #length, #factor, #offset, #endian)
+                    .map_err(|_| ::cantools::decode::DecodeError::Overflow)?
+            }
+        }
+        _ => {
+            return Err(syn::Error::new(
+                field.span(),
+                "expected either `bit` or both `start` and `length`",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        #ident: ::cantools::decode::TryDecode::try_decode(&#constructor, data)?
+    })
+}
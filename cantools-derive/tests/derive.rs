@@ -0,0 +1,90 @@
+use cantools::decode::{CanDecode, DecodeError};
+use cantools::signals::Unsigned;
+use cantools::utils::Endian;
+
+#[derive(Debug, PartialEq, CanDecode)]
+struct Status {
+    #[signal(start = 0, length = 8, factor = 0.5)]
+    speed: f64,
+    #[signal(start = 8, length = 8, offset = -40, signed)]
+    temperature: f64,
+    #[signal(bit = 16)]
+    active: bool,
+}
+
+#[derive(Debug, PartialEq, CanDecode)]
+struct Motorola {
+    #[signal(start = 7, length = 16, endian = "big")]
+    value: f64,
+    #[signal(bit = 16)]
+    flag: f64,
+}
+
+#[test]
+fn derive_can_decode_001() {
+    let data = [0x64u8, 0xF6, 0x01];
+    assert_eq!(
+        Status::try_decode_from(&data),
+        Ok(Status {
+            speed: 50.0,
+            temperature: -50.0,
+            active: true,
+        })
+    );
+}
+
+#[test]
+fn derive_can_decode_002() {
+    let data = [0x12u8, 0x34, 0x00];
+    assert_eq!(
+        Motorola::try_decode_from(&data),
+        Ok(Motorola {
+            value: 0x1234 as f64,
+            flag: 0.0,
+        })
+    );
+}
+
+#[test]
+fn derive_can_decode_003() {
+    let data = [0x64u8, 0xF6];
+    assert_eq!(
+        Status::try_decode_from(&data),
+        Err(DecodeError::StartOutOfRange { start: 16, dlc: 2 })
+    );
+}
+
+const SCALE: f64 = f64::MAX;
+
+#[derive(Debug, PartialEq, CanDecode)]
+struct Scaled {
+    #[signal(start = 0, length = 8, factor = SCALE)]
+    value: f64,
+}
+
+#[derive(Debug, PartialEq, CanDecode)]
+struct Native {
+    #[signal(start = 0, length = 16, endian = "native")]
+    value: f64,
+}
+
+#[test]
+fn derive_can_decode_004() {
+    // a non-finite range of a non-literal factor fails the decoding instead of panicking
+    assert_eq!(
+        Scaled::try_decode_from(&[0x01u8]),
+        Err(DecodeError::Overflow)
+    );
+}
+
+#[test]
+fn derive_can_decode_005() {
+    let data = [0x12u8, 0x34];
+    let expected = Unsigned::new(0, 16, 1.0, 0.0, Endian::native()).unwrap();
+    assert_eq!(
+        Native::try_decode_from(&data),
+        Ok(Native {
+            value: cantools::decode::Decode::decode(&expected, &data),
+        })
+    );
+}
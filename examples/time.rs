@@ -5,9 +5,11 @@ fn main() {
     let candump = CANDumpLog::open("candump/testdump_3.log").unwrap();
 
     let now = Instant::now();
-    for _l in candump {
-        // println!("{:?}", l);
-    }
+    candump
+        .for_each_ref(|_l| {
+            // println!("{:?}", _l);
+        })
+        .unwrap();
     let duration = now.elapsed();
     println!("{}", duration.as_secs_f32());
 }
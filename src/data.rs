@@ -5,7 +5,27 @@
 //! [data](CANRead::data) slice is needed since one can retrieve the DLC from the slice as well.
 //!
 //! The [CANWrite] trait provides one additional methods. The [mut_data](CANWrite::mut_data) method
-//! allows for mutating the slice.
+//! allows for mutating the slice. The signal types write their bits through
+//! [set_bit](CANWrite::set_bit) which is implemented on top of [mut_data](CANWrite::mut_data).
+//!
+//! The [TrackingBuffer] wraps CAN-bus data and records which bits were written through
+//! [set_bit](CANWrite::set_bit). This reveals bits that no signal has written.
+//!
+//! With the `bytes` feature enabled, [CANRead] is implemented for `bytes::Bytes` and
+//! `bytes::BytesMut`, and [CANWrite] for `bytes::BytesMut`. With the `heapless` feature enabled,
+//! [CANRead] and [CANWrite] are implemented for `heapless::Vec<u8, N>`, whose length may vary up to
+//! `N` bytes without allocating.
+//!
+//! The [GrowableFrame] starts out empty and grows whenever a bit past its current end is written.
+//! This allows for encoding signals without knowing the final DLC up front.
+//!
+//! [merge] combines CAN-bus data encoded independently, e.g., by several subsystems each owning a
+//! few signals of the same frame, into one frame.
+
+use crate::encode::EncodeError;
+use crate::utils::Mask;
+use std::collections::BTreeSet;
+use std::ops::Div;
 
 /// A trait providing methods for accessing the underlying bytes of some CAN-bus data.
 pub trait CANRead {
@@ -20,6 +40,49 @@ pub trait CANRead {
 pub trait CANWrite: CANRead {
     /// Returns a mutable slice representing the mutable data.
     fn mut_data(&mut self) -> &mut [u8];
+
+    /// Sets the bit at position `bit` to `value`. Bit `0` is the least significant bit of the
+    /// first byte, bit `8` the least significant bit of the second byte, and so on.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::data::CANWrite;
+    /// let mut data = [0u8, 0u8];
+    /// assert!(data.set_bit(9, true).is_ok());
+    /// assert!(data.set_bit(16, true).is_err());
+    /// assert_eq!(data, [0x00, 0x02]);
+    /// ```
+    fn set_bit(&mut self, bit: u16, value: bool) -> Result<(), EncodeError> {
+        let mask = u8::mask(1, bit % 8);
+        match self.mut_data().get_mut(bit.div(8) as usize) {
+            Some(byte) => {
+                if value {
+                    *byte |= mask;
+                } else {
+                    *byte &= !mask;
+                }
+                Ok(())
+            }
+            None => Err(EncodeError::NotEnoughData),
+        }
+    }
+
+    /// Sets every byte to zero. Encoding only writes the bits of the encoded signals, so clearing
+    /// a reused buffer keeps bits of a previous message from leaking into the next one.
+    ///
+    /// Types with an inherent `clear` method, e.g., `Vec<u8>`, remove their elements instead when
+    /// calling `data.clear()`. Use `CANWrite::clear(&mut data)` for these.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::data::CANWrite;
+    /// let mut data = [0xFFu8, 0x42];
+    /// data.clear();
+    /// assert_eq!(data, [0x00, 0x00]);
+    /// ```
+    fn clear(&mut self) {
+        self.mut_data().fill(0);
+    }
 }
 
 impl CANRead for Vec<u8> {
@@ -103,9 +166,246 @@ impl<const N: usize> CANWrite for [u8; N] {
     }
 }
 
+#[cfg(feature = "bytes")]
+impl CANRead for bytes::Bytes {
+    fn data(&self) -> &[u8] {
+        self.as_ref()
+    }
+
+    fn dlc(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl CANRead for bytes::BytesMut {
+    fn data(&self) -> &[u8] {
+        self.as_ref()
+    }
+
+    fn dlc(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl CANWrite for bytes::BytesMut {
+    fn mut_data(&mut self) -> &mut [u8] {
+        self.as_mut()
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> CANRead for heapless::Vec<u8, N> {
+    fn data(&self) -> &[u8] {
+        self.as_slice()
+    }
+
+    fn dlc(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl<const N: usize> CANWrite for heapless::Vec<u8, N> {
+    fn mut_data(&mut self) -> &mut [u8] {
+        self.as_mut_slice()
+    }
+}
+
+/// Modes of combining two CAN-bus data buffers with [merge].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MergeMode {
+    /// Sets the bits that are set in the source, i.e., ORs the source into the destination. Bits
+    /// cleared in the source leave the destination unchanged.
+    Or,
+    /// Replaces every bit of the destination covered by the source with the bit of the source.
+    Overwrite,
+}
+
+/// Merges the bytes of `src` into `dst` according to `mode`. The bits are written through
+/// [set_bit](CANWrite::set_bit) such that a [TrackingBuffer] records them and a [GrowableFrame]
+/// grows to fit them. Returns [EncodeError::NotEnoughData] if a bit to write lies outside of
+/// `dst`. The bits written up to that point remain written.
+///
+/// # Example
+/// ```
+/// use cantools::data::{merge, MergeMode};
+/// let mut frame = [0x0Fu8, 0x00];
+/// merge(&mut frame, &[0xF0u8, 0x42], MergeMode::Or).unwrap();
+/// assert_eq!(frame, [0xFF, 0x42]);
+///
+/// merge(&mut frame, &[0x01u8], MergeMode::Overwrite).unwrap();
+/// assert_eq!(frame, [0x01, 0x42]);
+/// ```
+pub fn merge<D: CANWrite, S: CANRead>(
+    dst: &mut D,
+    src: &S,
+    mode: MergeMode,
+) -> Result<(), EncodeError> {
+    for (index, byte) in src.data().iter().enumerate() {
+        for offset in 0..8 {
+            let bit = u16::try_from(8 * index + offset).map_err(|_| EncodeError::NotEnoughData)?;
+            let value = byte & (1 << offset) != 0;
+            if value || mode == MergeMode::Overwrite {
+                dst.set_bit(bit, value)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// A type recording which bits of the wrapped CAN-bus data were written.
+///
+/// Every bit written through [set_bit](CANWrite::set_bit) is recorded, regardless of whether its
+/// value changed. Writes through the slice returned by [mut_data](CANWrite::mut_data) cannot be
+/// observed and are therefore not recorded.
+///
+/// # Example
+/// ```
+/// use cantools::data::TrackingBuffer;
+/// use cantools::encode::Encode;
+/// use cantools::signals::Bit;
+///
+/// let mut buffer = TrackingBuffer::new([0u8]);
+/// Bit::new(3).encode(&mut buffer, false);
+/// assert_eq!(buffer.written_bits(), vec![3]);
+/// assert_eq!(buffer.untouched_bits(), vec![0, 1, 2, 4, 5, 6, 7]);
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct TrackingBuffer<D> {
+    inner: D,
+    written: BTreeSet<u16>,
+}
+
+impl<D: CANWrite> TrackingBuffer<D> {
+    /// Constructs a new TrackingBuffer wrapping `inner` with no bits recorded as written.
+    pub fn new(inner: D) -> TrackingBuffer<D> {
+        TrackingBuffer {
+            inner,
+            written: BTreeSet::new(),
+        }
+    }
+
+    /// Returns the written bits in ascending order.
+    pub fn written_bits(&self) -> Vec<u16> {
+        self.written.iter().copied().collect()
+    }
+
+    /// Returns the bits that were not written in ascending order.
+    pub fn untouched_bits(&self) -> Vec<u16> {
+        (0..(8 * self.inner.dlc()) as u16)
+            .filter(|bit| !self.written.contains(bit))
+            .collect()
+    }
+
+    /// Returns the wrapped CAN-bus data.
+    pub fn into_inner(self) -> D {
+        self.inner
+    }
+}
+
+impl<D: CANWrite> CANRead for TrackingBuffer<D> {
+    fn data(&self) -> &[u8] {
+        self.inner.data()
+    }
+
+    fn dlc(&self) -> usize {
+        self.inner.dlc()
+    }
+}
+
+impl<D: CANWrite> CANWrite for TrackingBuffer<D> {
+    fn mut_data(&mut self) -> &mut [u8] {
+        self.inner.mut_data()
+    }
+
+    fn set_bit(&mut self, bit: u16, value: bool) -> Result<(), EncodeError> {
+        self.inner.set_bit(bit, value)?;
+        self.written.insert(bit);
+        Ok(())
+    }
+}
+
+/// A type modeling CAN-bus data that grows to fit the written bits.
+///
+/// Writing a bit through [set_bit](CANWrite::set_bit) that lies past the current end of the data
+/// extends the data with zero bytes up to the byte containing the bit. The data never grows
+/// beyond `max` bytes, typically `8` for CAN and `64` for CAN-FD. To let signals check their
+/// bounds against this limit, [dlc](CANRead::dlc) and [data](CANRead::data) cover all `max` bytes,
+/// the unwritten ones being zero, while [written](GrowableFrame::written) only returns the bytes up
+/// to the last written one. Writes through [mut_data](CANWrite::mut_data) do not grow the frame.
+///
+/// # Example
+/// ```
+/// use cantools::data::GrowableFrame;
+/// use cantools::encode::Encode;
+/// use cantools::signals::Bit;
+///
+/// let mut frame = GrowableFrame::new(8);
+/// Bit::new(9).encode(&mut frame, true);
+/// assert_eq!(frame.into_inner(), vec![0x00, 0x02]);
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct GrowableFrame {
+    data: Vec<u8>,
+    len: usize,
+}
+
+impl GrowableFrame {
+    /// Constructs a new, empty GrowableFrame that grows up to `max` bytes.
+    pub fn new(max: usize) -> GrowableFrame {
+        GrowableFrame {
+            data: vec![0; max],
+            len: 0,
+        }
+    }
+
+    /// Returns the maximal number of bytes the frame grows to.
+    pub fn max(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns the bytes up to and including the last written one.
+    pub fn written(&self) -> &[u8] {
+        &self.data[..self.len]
+    }
+
+    /// Returns the bytes up to and including the last written one.
+    pub fn into_inner(mut self) -> Vec<u8> {
+        self.data.truncate(self.len);
+        self.data
+    }
+}
+
+impl CANRead for GrowableFrame {
+    fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    fn dlc(&self) -> usize {
+        self.data.len()
+    }
+}
+
+impl CANWrite for GrowableFrame {
+    fn mut_data(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+
+    fn set_bit(&mut self, bit: u16, value: bool) -> Result<(), EncodeError> {
+        self.data.set_bit(bit, value)?;
+        self.len = self.len.max(bit.div(8) as usize + 1);
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::CANRead;
+    use super::{merge, CANRead, CANWrite, GrowableFrame, MergeMode, TrackingBuffer};
+    use crate::encode::{EncodeError, TryEncode};
+    use crate::signals::Unsigned;
+    use crate::utils::Endian;
 
     #[test]
     fn test_001() {
@@ -128,4 +428,181 @@ mod tests {
             assert_eq!(CANRead::data(&v), v.as_slice());
         }
     }
+
+    #[test]
+    fn test_set_bit_001() {
+        let mut data = vec![0xF0u8, 0x0F];
+        assert_eq!(data.set_bit(0, true), Ok(()));
+        assert_eq!(data.set_bit(4, false), Ok(()));
+        assert_eq!(data.set_bit(15, true), Ok(()));
+        assert_eq!(data.set_bit(16, true), Err(EncodeError::NotEnoughData));
+        assert_eq!(data, vec![0xE1, 0x8F]);
+    }
+
+    #[test]
+    fn test_tracking_buffer_001() {
+        let sig = Unsigned::new(4, 4, 1.0, 0.0, Endian::Little).unwrap();
+        let mut buffer = TrackingBuffer::new([0u8; 8]);
+
+        // zero bits are recorded as written as well
+        assert_eq!(sig.try_encode(&mut buffer, 0.0), Ok(()));
+        assert_eq!(buffer.written_bits(), vec![4, 5, 6, 7]);
+        assert_eq!(buffer.untouched_bits().len(), 60);
+        assert_eq!(buffer.into_inner(), [0u8; 8]);
+    }
+
+    #[test]
+    fn test_tracking_buffer_002() {
+        let sig = Unsigned::new(7, 4, 1.0, 0.0, Endian::Big).unwrap();
+        let mut buffer = TrackingBuffer::new(vec![0u8; 2]);
+
+        assert_eq!(sig.try_encode(&mut buffer, 15.0), Ok(()));
+        assert_eq!(buffer.written_bits(), vec![4, 5, 6, 7]);
+        assert_eq!(
+            buffer.untouched_bits(),
+            vec![0, 1, 2, 3, 8, 9, 10, 11, 12, 13, 14, 15]
+        );
+        assert_eq!(buffer.data(), &[0xF0, 0x00]);
+    }
+
+    #[test]
+    fn test_clear_001() {
+        let sig = Unsigned::new(4, 8, 1.0, 0.0, Endian::Little).unwrap();
+
+        let mut fresh = [0u8; 2];
+        sig.try_encode(&mut fresh, 0x42 as f64).unwrap();
+
+        let mut reused = [0xFFu8; 2];
+        reused.clear();
+        sig.try_encode(&mut reused, 0x42 as f64).unwrap();
+        assert_eq!(reused, fresh);
+
+        let mut reused = vec![0xFFu8; 2];
+        CANWrite::clear(&mut reused);
+        assert_eq!(reused, vec![0x00, 0x00]);
+    }
+
+    #[test]
+    fn test_growable_frame_001() {
+        let sig = Unsigned::new(40, 8, 1.0, 0.0, Endian::Little).unwrap();
+        let mut frame = GrowableFrame::new(8);
+
+        assert_eq!(sig.try_encode(&mut frame, 0x42 as f64), Ok(()));
+        assert_eq!(frame.into_inner(), vec![0, 0, 0, 0, 0, 0x42]);
+    }
+
+    #[test]
+    fn test_growable_frame_002() {
+        let sig = Unsigned::new(60, 8, 1.0, 0.0, Endian::Little).unwrap();
+        let mut frame = GrowableFrame::new(8);
+
+        assert_eq!(
+            sig.try_encode(&mut frame, 1.0),
+            Err(EncodeError::NotEnoughData)
+        );
+        assert_eq!(frame.set_bit(64, true), Err(EncodeError::NotEnoughData));
+        assert_eq!(frame.written(), &[]);
+
+        let mut frame = GrowableFrame::new(64);
+        assert_eq!(sig.try_encode(&mut frame, 1.0), Ok(()));
+        assert_eq!(frame.written().len(), 9);
+        assert_eq!(frame.data().len(), frame.dlc());
+    }
+
+    #[test]
+    fn test_growable_frame_003() {
+        use crate::signals::Checksum;
+
+        fn xor(data: &[u8]) -> u8 {
+            data.iter().fold(0, |acc, byte| acc ^ byte)
+        }
+
+        // the unwritten bytes read as zero instead of being out of bounds
+        let mut frame = GrowableFrame::new(8);
+        assert_eq!(Checksum::new(7, xor).verify(&frame), Ok(true));
+
+        frame.set_bit(9, true).unwrap();
+        assert_eq!(frame.data(), &[0x00, 0x02, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(frame.written(), &[0x00, 0x02]);
+        assert_eq!(Checksum::new(7, xor).try_update(&mut frame), Ok(0x02));
+        assert_eq!(frame.into_inner(), vec![0x00, 0x02, 0, 0, 0, 0, 0, 0x02]);
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn test_bytes_001() {
+        use crate::decode::TryDecode;
+        use crate::signals::Bit;
+        use bytes::{Bytes, BytesMut};
+
+        let data = Bytes::from_static(&[0x00, 0x02]);
+        assert_eq!(Bit::new(9).try_decode(&data), Ok(true));
+        assert_eq!(Bit::new(8).try_decode(&data), Ok(false));
+
+        let mut data = BytesMut::zeroed(2);
+        assert_eq!(Bit::new(9).try_encode(&mut data, true), Ok(()));
+        assert_eq!(data.dlc(), 2);
+        assert_eq!(&data[..], &[0x00, 0x02]);
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn test_heapless_001() {
+        use crate::decode::TryDecode;
+
+        let sig = Unsigned::new(4, 12, 1.0, 0.0, Endian::Little).unwrap();
+        let mut data = heapless::Vec::<u8, 8>::new();
+        data.resize(2, 0).unwrap();
+        assert_eq!(sig.try_encode(&mut data, 0xABC as f64), Ok(()));
+        assert_eq!(data.dlc(), 2);
+        assert_eq!(data.data(), &[0xC0, 0xAB]);
+        assert_eq!(sig.try_decode(&data), Ok(0xABC as f64));
+
+        // the runtime length limits the encodable signals, not the capacity
+        let sig = Unsigned::new(16, 8, 1.0, 0.0, Endian::Little).unwrap();
+        assert_eq!(
+            sig.try_encode(&mut data, 1.0),
+            Err(EncodeError::NotEnoughData)
+        );
+        data.resize(8, 0).unwrap();
+        assert_eq!(sig.try_encode(&mut data, 1.0), Ok(()));
+    }
+
+    #[test]
+    fn test_merge_001() {
+        let engine = Unsigned::new(0, 12, 1.0, 0.0, Endian::Little).unwrap();
+        let brake = Unsigned::new(16, 8, 1.0, 0.0, Endian::Little).unwrap();
+
+        let mut expected = [0u8; 4];
+        engine.try_encode(&mut expected, 0xABC as f64).unwrap();
+        brake.try_encode(&mut expected, 0x42 as f64).unwrap();
+
+        let mut frame = [0u8; 4];
+        engine.try_encode(&mut frame, 0xABC as f64).unwrap();
+        let mut partial = [0u8; 4];
+        brake.try_encode(&mut partial, 0x42 as f64).unwrap();
+
+        assert_eq!(merge(&mut frame, &partial, MergeMode::Or), Ok(()));
+        assert_eq!(frame, expected);
+        assert_eq!(frame, [0xBC, 0x0A, 0x42, 0x00]);
+
+        assert_eq!(merge(&mut frame, &[0x00u8], MergeMode::Overwrite), Ok(()));
+        assert_eq!(frame, [0x00, 0x0A, 0x42, 0x00]);
+
+        assert_eq!(
+            merge(&mut [0u8; 1], &[0x00u8, 0x01], MergeMode::Or),
+            Err(EncodeError::NotEnoughData)
+        );
+    }
+
+    #[test]
+    fn test_merge_002() {
+        let mut buffer = TrackingBuffer::new([0u8; 2]);
+        merge(&mut buffer, &[0x00u8, 0x81], MergeMode::Or).unwrap();
+        assert_eq!(buffer.written_bits(), vec![8, 15]);
+
+        let mut frame = GrowableFrame::new(8);
+        merge(&mut frame, &[0x00u8, 0x01], MergeMode::Or).unwrap();
+        assert_eq!(frame.into_inner(), vec![0x00, 0x01]);
+    }
 }
@@ -1,7 +1,8 @@
 //! Module providing utility traits for decoding CAN-bus data.
 //!
 //! The module provides three traits: [TryDecode], [DefaultDecode], and [Decode].
-//! [try_decode](TryDecode::try_decode) models the possibility of the decoding to fail.
+//! [try_decode](TryDecode::try_decode) models the possibility of the decoding to fail, and
+//! [decode_opt](TryDecode::decode_opt) turns a failure into `None`.
 //! [default_decode](DefaultDecode::default_decode) returns a default value if the decoding fails.
 //! Finally, [decode](Decode::decode) panics if the internal decoding fails. Otherwise, it returns
 //! the decoded value.
@@ -15,20 +16,131 @@
 //! let bit = Bit::new(20);
 //! let mut data = [1u8, 2u8, 3u8, 4u8];
 //!
-//! let result_1 = bit.try_decode(&data);
-//! let result_2 = bit.default_decode(&data);
-//! let result_3 = bit.decode(&data);
+//! let result_1: Result<bool, _> = bit.try_decode(&data);
+//! let result_2: bool = bit.default_decode(&data);
+//! let result_3: bool = bit.decode(&data);
 //! ```
 
 use crate::data::CANRead;
+use std::error::Error;
+use std::fmt;
 
 /// Type representing possible decoding errors.
 #[derive(Debug, PartialEq)]
 pub enum DecodeError {
     /// There is not enough byte data available from which one can decode a value.
     NotEnoughData,
+    /// The start bit of the signal lies outside of the `dlc` bytes of data.
+    StartOutOfRange { start: u16, dlc: usize },
+    /// The signal starts inside of the data but runs past its end. `needed` is the number of bits
+    /// the data must contain to hold the signal, `available` is the number of bits it contains.
+    LengthExceedsBuffer { needed: u16, available: u16 },
+    /// The decoded value does not fit into the requested integer type.
+    Overflow,
+    /// The factor or offset of the signal is not a whole multiple of `1 / out_scale` for the
+    /// requested output scale, such that the value cannot be decoded exactly.
+    InexactScale,
 }
 
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::NotEnoughData => write!(f, "not enough data to decode signal"),
+            DecodeError::StartOutOfRange { start, dlc } => write!(
+                f,
+                "start bit {} lies outside of the {} bytes of data",
+                start, dlc
+            ),
+            DecodeError::LengthExceedsBuffer { needed, available } => write!(
+                f,
+                "signal needs {} bits of data but only {} bits are available",
+                needed, available
+            ),
+            DecodeError::Overflow => write!(f, "decoded value does not fit into the target type"),
+            DecodeError::InexactScale => {
+                write!(f, "factor or offset is not exact at the output scale")
+            }
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+/// Type representing possible errors when decoding a raw value into an enum, see
+/// [try_decode_enum](crate::signals::Unsigned::try_decode_enum).
+#[derive(Debug, PartialEq)]
+pub enum EnumDecodeError {
+    /// The raw value cannot be decoded from the data.
+    Decode(DecodeError),
+    /// The raw value was decoded but the enum has no variant for it.
+    UnknownValue(i64),
+}
+
+impl fmt::Display for EnumDecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EnumDecodeError::Decode(_) => write!(f, "cannot decode raw value"),
+            EnumDecodeError::UnknownValue(raw) => {
+                write!(f, "no enum variant for raw value {}", raw)
+            }
+        }
+    }
+}
+
+impl Error for EnumDecodeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            EnumDecodeError::Decode(err) => Some(err),
+            EnumDecodeError::UnknownValue(_) => None,
+        }
+    }
+}
+
+impl From<DecodeError> for EnumDecodeError {
+    fn from(err: DecodeError) -> Self {
+        EnumDecodeError::Decode(err)
+    }
+}
+
+/// Type bundling the different representations of one decoded value.
+#[derive(Debug, PartialEq)]
+pub struct DecodedValue {
+    /// The raw, i.e., unscaled integer value.
+    pub raw: i64,
+    /// The physical value, i.e., the raw value multiplied with the factor and summed up with the
+    /// offset.
+    pub physical: f64,
+    /// The label assigned to the raw value by the value table of the signal, if any.
+    pub label: Option<String>,
+}
+
+/// A physical value, i.e., a raw value multiplied with the factor and summed up with the offset.
+///
+/// Returned by the `decode_physical` methods of the signals. Other than a plain `f64`, the type
+/// cannot be confused with a [Raw] value.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Physical(pub f64);
+
+/// A raw value, i.e., the unscaled bit sequence of a signal as transmitted on the bus.
+///
+/// Returned by the `decode_raw` methods of the signals. Other than a plain `f64`, the type cannot
+/// be confused with a [Physical] value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Raw(pub u64);
+
+/// A trait modeling the decoding of a whole type, e.g., a struct grouping several signals.
+///
+/// With the `derive` feature enabled, `#[derive(CanDecode)]` implements the trait for structs
+/// whose fields are annotated with the signal they are decoded from, e.g.,
+/// `#[signal(start = 0, length = 8, factor = 0.5)]` or `#[signal(bit = 16)]`.
+pub trait CanDecode: Sized {
+    /// Tries to decode a value of the implementing type from `data`.
+    fn try_decode_from<D: CANRead>(data: &D) -> Result<Self, DecodeError>;
+}
+
+#[cfg(feature = "derive")]
+pub use cantools_derive::CanDecode;
+
 /// A trait modeling the failable decoding of data.
 pub trait TryDecode<T> {
     /// A type modeling the different possible failures of the decoding.
@@ -36,6 +148,12 @@ pub trait TryDecode<T> {
 
     /// Tries to decode a value.
     fn try_decode<D: CANRead>(&self, data: &D) -> Result<T, Self::Error>;
+
+    /// Tries to decode a value and returns `None` if the decoding fails. Contrary to
+    /// [default_decode](DefaultDecode::default_decode), `T` needs no [Default] value.
+    fn decode_opt<D: CANRead>(&self, data: &D) -> Option<T> {
+        self.try_decode(data).ok()
+    }
 }
 
 /// A trait modeling the failable decoding of data.
@@ -43,10 +161,7 @@ pub trait DefaultDecode<T: Default>: TryDecode<T> {
     /// Tries to decode a value. If the data is not decodable, a default value is returned.
     /// Otherwise, the decoded value is returned.
     fn default_decode<D: CANRead>(&self, data: &D) -> T {
-        match self.try_decode(data) {
-            Ok(value) => value,
-            Err(_) => T::default(),
-        }
+        self.try_decode(data).unwrap_or_default()
     }
 }
 
@@ -63,3 +178,128 @@ pub trait Decode<T>: TryDecode<T> {
         }
     }
 }
+
+/// A trait modeling the decoding of a fixed group of signals into a typed tuple.
+///
+/// The trait is implemented for tuples of up to six references to signals decoding into [f64],
+/// e.g., `(&Unsigned, &Signed)`. The signals are decoded in order; the first failing signal aborts
+/// the decoding.
+///
+/// # Example
+/// ```
+/// use cantools::decode::DecodeTuple;
+/// use cantools::signals::{Signed, Unsigned};
+/// use cantools::utils::Endian;
+///
+/// let speed = Unsigned::new(0, 8, 0.5, 0.0, Endian::Little).unwrap();
+/// let torque = Signed::new(8, 8, 1.0, 0.0, Endian::Little).unwrap();
+/// let data = [0x10u8, 0xFE];
+///
+/// assert_eq!((&speed, &torque).decode_tuple(&data), Ok((8.0, -2.0)));
+/// ```
+pub trait DecodeTuple {
+    /// The tuple of decoded values.
+    type Output;
+
+    /// Tries to decode every signal of the tuple from `data`.
+    fn decode_tuple<D: CANRead>(&self, data: &D) -> Result<Self::Output, DecodeError>;
+}
+
+macro_rules! impl_decode_tuple {
+    ($($name:ident : $index:tt),+) => {
+        impl<'a, $($name),+> DecodeTuple for ($(&'a $name,)+)
+        where
+            $($name: TryDecode<f64, Error = DecodeError>),+
+        {
+            type Output = ($(impl_decode_tuple!(@f64 $name),)+);
+
+            fn decode_tuple<D: CANRead>(&self, data: &D) -> Result<Self::Output, DecodeError> {
+                Ok(($(self.$index.try_decode(data)?,)+))
+            }
+        }
+    };
+    (@f64 $name:ident) => { f64 };
+}
+
+impl_decode_tuple!(A: 0);
+impl_decode_tuple!(A: 0, B: 1);
+impl_decode_tuple!(A: 0, B: 1, C: 2);
+impl_decode_tuple!(A: 0, B: 1, C: 2, E: 3);
+impl_decode_tuple!(A: 0, B: 1, C: 2, E: 3, F: 4);
+impl_decode_tuple!(A: 0, B: 1, C: 2, E: 3, F: 4, G: 5);
+
+#[cfg(test)]
+mod tests {
+    use super::{DecodeError, DecodeTuple, TryDecode};
+    use crate::signals::{Bit, Signed, Unsigned};
+    use crate::utils::Endian;
+
+    #[test]
+    fn test_decode_error_display_001() {
+        assert_eq!(
+            DecodeError::NotEnoughData.to_string(),
+            "not enough data to decode signal"
+        );
+        assert_eq!(
+            DecodeError::StartOutOfRange { start: 16, dlc: 2 }.to_string(),
+            "start bit 16 lies outside of the 2 bytes of data"
+        );
+        assert_eq!(
+            DecodeError::LengthExceedsBuffer {
+                needed: 24,
+                available: 16
+            }
+            .to_string(),
+            "signal needs 24 bits of data but only 16 bits are available"
+        );
+        assert_eq!(
+            DecodeError::Overflow.to_string(),
+            "decoded value does not fit into the target type"
+        );
+        assert_eq!(
+            DecodeError::InexactScale.to_string(),
+            "factor or offset is not exact at the output scale"
+        );
+    }
+
+    #[test]
+    fn test_decode_opt_001() {
+        let data = [0x01u8, 0x00];
+
+        let bit = Bit::new(0);
+        assert_eq!(bit.decode_opt(&data), Some(true));
+
+        let bit = Bit::new(16);
+        let value: Option<bool> = bit.decode_opt(&data);
+        assert_eq!(value, None);
+
+        let sig = Signed::new(8, 16, 1.0, 0.0, Endian::Little).unwrap();
+        assert_eq!(sig.decode_opt(&data), None);
+    }
+
+    #[test]
+    fn test_decode_tuple_001() {
+        let unsigned = Unsigned::new(0, 8, 1.0, 0.0, Endian::Little).unwrap();
+        let signed = Signed::new(8, 8, 1.0, 0.0, Endian::Little).unwrap();
+        let data = [0x2Au8, 0xFF];
+
+        assert_eq!((&unsigned, &signed).decode_tuple(&data), Ok((42.0, -1.0)));
+    }
+
+    #[test]
+    fn test_decode_tuple_002() {
+        let unsigned = Unsigned::new(0, 8, 0.5, 1.0, Endian::Little).unwrap();
+        let signed = Signed::new(8, 8, 1.0, 0.0, Endian::Little).unwrap();
+        let bit = Bit::new(16);
+        let data = [0x04u8, 0x80, 0x01];
+
+        assert_eq!(
+            (&unsigned, &signed, &bit).decode_tuple(&data),
+            Ok((3.0, -128.0, 1.0))
+        );
+        assert_eq!(
+            (&unsigned, &signed, &bit).decode_tuple(&[0x04u8, 0x80]),
+            Err(DecodeError::StartOutOfRange { start: 16, dlc: 2 })
+        );
+    }
+}
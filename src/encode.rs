@@ -18,6 +18,8 @@
 //! ```
 
 use crate::data::CANWrite;
+use std::error::Error;
+use std::fmt;
 
 /// Type representing possible encoding errors.
 #[derive(Debug, PartialEq)]
@@ -28,8 +30,24 @@ pub enum EncodeError {
     MinError,
     /// The value to encode is greater than the maximum value encodable.
     MaxError,
+    /// The label to encode is not part of the value table of the signal, or the signal has no
+    /// value table.
+    UnknownLabel,
 }
 
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodeError::NotEnoughData => write!(f, "not enough data to encode signal"),
+            EncodeError::MinError => write!(f, "value is smaller than the minimal encodable value"),
+            EncodeError::MaxError => write!(f, "value is greater than the maximal encodable value"),
+            EncodeError::UnknownLabel => write!(f, "label is not part of the value table"),
+        }
+    }
+}
+
+impl Error for EncodeError {}
+
 /// A trait modeling the failable encoding of data.
 pub trait TryEncode<T> {
     /// A type modelling the different possible failures of the encoding.
@@ -57,3 +75,24 @@ pub trait Encode<T>: TryEncode<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::EncodeError;
+
+    #[test]
+    fn test_encode_error_display_001() {
+        assert_eq!(
+            EncodeError::NotEnoughData.to_string(),
+            "not enough data to encode signal"
+        );
+        assert_eq!(
+            EncodeError::MinError.to_string(),
+            "value is smaller than the minimal encodable value"
+        );
+        assert_eq!(
+            EncodeError::MaxError.to_string(),
+            "value is greater than the maximal encodable value"
+        );
+    }
+}
@@ -0,0 +1,300 @@
+//! Module providing types modeling CAN-bus frames.
+//!
+//! The [CanId] type models the identifier of a frame. CAN-bus distinguishes between
+//! [Standard](CanId::Standard) identifiers consisting of 11 bits and
+//! [Extended](CanId::Extended) identifiers consisting of 29 bits. Logfiles, e.g., written by
+//! `candump`, encode this distinction by the number of hexadecimal digits: three digits for
+//! standard and eight digits for extended identifiers.
+//!
+//! The [Frame] type combines a [CanId] with up to [MAX_DATA_LENGTH] bytes of data stored inline.
+//! It implements [CANRead] and [CANWrite] such that signals decode from and encode into it.
+//!
+//! # Example
+//! ```
+//! use cantools::frame::CanId;
+//!
+//! let standard: CanId = "123".parse().unwrap();
+//! let extended: CanId = "1FFFFFFF".parse().unwrap();
+//! assert_eq!(standard, CanId::Standard(0x123));
+//! assert_eq!(extended.to_string(), "1FFFFFFF");
+//! ```
+
+use crate::data::{CANRead, CANWrite};
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// The maximum number of data bytes of a CAN-FD frame.
+pub const MAX_DATA_LENGTH: usize = 64;
+
+/// Type modeling the identifier of a CAN-bus frame.
+///
+/// Identifiers are ordered by their kind first, every standard identifier precedes every extended
+/// identifier, and by their numeric value second.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CanId {
+    /// An 11-bit identifier.
+    Standard(u16),
+    /// A 29-bit identifier.
+    Extended(u32),
+}
+
+impl CanId {
+    /// Returns the numeric value of the identifier.
+    pub fn raw(&self) -> u32 {
+        match self {
+            CanId::Standard(id) => *id as u32,
+            CanId::Extended(id) => *id,
+        }
+    }
+
+    /// Returns `true` if the identifier is an extended identifier.
+    pub fn is_extended(&self) -> bool {
+        matches!(self, CanId::Extended(_))
+    }
+}
+
+impl From<u32> for CanId {
+    /// Converts `id` into an [Extended](CanId::Extended) identifier.
+    fn from(id: u32) -> Self {
+        CanId::Extended(id)
+    }
+}
+
+/// Type representing possible errors when parsing a [CanId].
+#[derive(Debug, PartialEq)]
+pub enum CanIdParseError {
+    /// The string is no hexadecimal number.
+    ParseIdError,
+    /// The identifier does not fit into 11 bits for standard or 29 bits for extended identifiers.
+    IdOutOfRange,
+}
+
+impl fmt::Display for CanIdParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CanIdParseError::ParseIdError => write!(f, "invalid hexadecimal identifier"),
+            CanIdParseError::IdOutOfRange => write!(f, "identifier out of range"),
+        }
+    }
+}
+
+impl Error for CanIdParseError {}
+
+impl FromStr for CanId {
+    type Err = CanIdParseError;
+
+    /// Parses a hexadecimal identifier. Up to three digits denote a standard identifier, more
+    /// digits an extended identifier.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let id = match u32::from_str_radix(s, 16) {
+            Ok(id) => id,
+            Err(_) => return Err(CanIdParseError::ParseIdError),
+        };
+
+        if s.len() <= 3 {
+            if id > 0x7FF {
+                return Err(CanIdParseError::IdOutOfRange);
+            }
+            Ok(CanId::Standard(id as u16))
+        } else {
+            if id > 0x1FFF_FFFF {
+                return Err(CanIdParseError::IdOutOfRange);
+            }
+            Ok(CanId::Extended(id))
+        }
+    }
+}
+
+impl fmt::Display for CanId {
+    /// Formats standard identifiers with three and extended identifiers with eight hexadecimal
+    /// digits.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CanId::Standard(id) => write!(f, "{:03X}", id),
+            CanId::Extended(id) => write!(f, "{:08X}", id),
+        }
+    }
+}
+
+/// Type modeling a CAN-bus frame consisting of a [CanId] and its data.
+///
+/// # Example
+/// ```
+/// use cantools::data::CANRead;
+/// use cantools::decode::TryDecode;
+/// use cantools::frame::{CanId, Frame};
+/// use cantools::signals::Unsigned;
+/// use cantools::utils::Endian;
+///
+/// let frame = Frame::new(CanId::Standard(0x123), &[0x12, 0x34]).unwrap();
+/// let sig = Unsigned::new(8, 8, 1.0, 0.0, Endian::Little).unwrap();
+/// assert_eq!(frame.id(), CanId::Standard(0x123));
+/// assert_eq!(frame.dlc(), 2);
+/// assert_eq!(sig.try_decode(&frame), Ok(52.0));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    id: CanId,
+    data: [u8; MAX_DATA_LENGTH],
+    length: usize,
+}
+
+/// Type representing possible errors when constructing a [Frame].
+#[derive(Debug, PartialEq)]
+pub enum FrameConstructionError {
+    /// The data exceeds [MAX_DATA_LENGTH] bytes.
+    DataTooLong,
+}
+
+impl fmt::Display for FrameConstructionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FrameConstructionError::DataTooLong => {
+                write!(f, "data exceeds {} bytes", MAX_DATA_LENGTH)
+            }
+        }
+    }
+}
+
+impl Error for FrameConstructionError {}
+
+impl Frame {
+    /// Constructs a [Frame] with identifier `id` and a copy of `data`.
+    pub fn new(id: impl Into<CanId>, data: &[u8]) -> Result<Self, FrameConstructionError> {
+        let mut frame = Frame::zeroed(id, data.len())?;
+        frame.data[..data.len()].copy_from_slice(data);
+        Ok(frame)
+    }
+
+    /// Constructs a [Frame] with identifier `id` and `length` zero bytes, e.g., for encoding
+    /// signals into it.
+    pub fn zeroed(id: impl Into<CanId>, length: usize) -> Result<Self, FrameConstructionError> {
+        if length > MAX_DATA_LENGTH {
+            return Err(FrameConstructionError::DataTooLong);
+        }
+
+        Ok(Frame {
+            id: id.into(),
+            data: [0u8; MAX_DATA_LENGTH],
+            length,
+        })
+    }
+
+    /// Returns the identifier of the frame.
+    pub fn id(&self) -> CanId {
+        self.id
+    }
+}
+
+impl CANRead for Frame {
+    fn data(&self) -> &[u8] {
+        &self.data[..self.length]
+    }
+
+    fn dlc(&self) -> usize {
+        self.length
+    }
+}
+
+impl CANWrite for Frame {
+    fn mut_data(&mut self) -> &mut [u8] {
+        &mut self.data[..self.length]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CanId, CanIdParseError, Frame, FrameConstructionError};
+    use crate::data::CANRead;
+    use crate::decode::TryDecode;
+    use crate::encode::TryEncode;
+    use crate::signals::{Bit, Signed};
+    use crate::utils::Endian;
+
+    #[test]
+    fn test_can_id_001() {
+        assert_eq!("123".parse(), Ok(CanId::Standard(0x123)));
+        assert_eq!("7ff".parse(), Ok(CanId::Standard(0x7FF)));
+        assert_eq!("00000123".parse(), Ok(CanId::Extended(0x123)));
+        assert_eq!("1FFFFFFF".parse(), Ok(CanId::Extended(0x1FFF_FFFF)));
+    }
+
+    #[test]
+    fn test_can_id_002() {
+        assert_eq!("800".parse::<CanId>(), Err(CanIdParseError::IdOutOfRange));
+        assert_eq!(
+            "20000000".parse::<CanId>(),
+            Err(CanIdParseError::IdOutOfRange)
+        );
+        assert_eq!("".parse::<CanId>(), Err(CanIdParseError::ParseIdError));
+        assert_eq!("12G".parse::<CanId>(), Err(CanIdParseError::ParseIdError));
+    }
+
+    #[test]
+    fn test_can_id_003() {
+        for id in ["000", "123", "7FF", "00000000", "00000123", "1FFFFFFF"] {
+            assert_eq!(id.parse::<CanId>().unwrap().to_string(), id);
+        }
+        assert_eq!(CanId::from(0x42).to_string(), "00000042");
+        assert_eq!(CanId::Standard(0x42).raw(), 0x42);
+        assert!(!CanId::Standard(0x42).is_extended());
+    }
+
+    #[test]
+    fn test_can_id_004() {
+        let mut ids = vec![
+            CanId::Extended(0x10),
+            CanId::Standard(0x7FF),
+            CanId::Extended(0x01),
+            CanId::Standard(0x123),
+        ];
+        ids.sort();
+        assert_eq!(
+            ids,
+            vec![
+                CanId::Standard(0x123),
+                CanId::Standard(0x7FF),
+                CanId::Extended(0x01),
+                CanId::Extended(0x10),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_frame_001() {
+        let frame = Frame::new(CanId::Extended(0x42), &[0x00, 0xFE, 0xFF]).unwrap();
+        assert_eq!(frame.id(), CanId::Extended(0x42));
+        assert_eq!(frame.data(), &[0x00, 0xFE, 0xFF]);
+
+        let sig = Signed::new(8, 16, 1.0, 0.0, Endian::Little).unwrap();
+        assert_eq!(sig.try_decode(&frame), Ok(-2.0));
+
+        let bit = Bit::new(9);
+        assert_eq!(bit.try_decode(&frame), Ok(true));
+    }
+
+    #[test]
+    fn test_frame_002() {
+        let mut frame = Frame::zeroed(0x42, 2).unwrap();
+        let sig = Signed::new(0, 16, 1.0, 0.0, Endian::Little).unwrap();
+        assert_eq!(sig.try_encode(&mut frame, -2.0), Ok(()));
+        assert_eq!(frame.data(), &[0xFE, 0xFF]);
+        assert!(sig
+            .try_encode(&mut Frame::zeroed(0x42, 1).unwrap(), 1.0)
+            .is_err());
+    }
+
+    #[test]
+    fn test_frame_003() {
+        assert!(Frame::new(0x42, &[0u8; 64]).is_ok());
+        assert_eq!(
+            Frame::new(0x42, &[0u8; 65]),
+            Err(FrameConstructionError::DataTooLong)
+        );
+        assert_eq!(
+            Frame::zeroed(0x42, 65),
+            Err(FrameConstructionError::DataTooLong)
+        );
+    }
+}
@@ -1,13 +1,86 @@
 //! Module contains logfile types used to access the underlying CAN-bus data.
+//!
+//! Besides the `candump` formats, the [mdf4] module reads frames from ASAM MDF 4.x files.
+
+pub mod mdf4;
+pub use mdf4::{Mdf4, Mdf4Entry, Mdf4Error};
 
 use crate::data::CANRead;
+use crate::decode::TryDecode;
+use crate::frame::{CanId, MAX_DATA_LENGTH};
+use crate::signals::Unsigned;
+use crate::utils::{parse_hex_bytes_into, write_hex_bytes, Endian, HexError};
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap};
+use std::error::Error;
+use std::fmt;
 use std::fs::File;
 use std::io;
-use std::io::{BufRead, BufReader, Lines};
+use std::io::{BufRead, BufReader, Seek, SeekFrom, StdinLock};
 use std::iter::{IntoIterator, Iterator};
-use std::ops::Div;
 use std::path::Path;
-use std::str::FromStr;
+use std::str::{FromStr, Utf8Error};
+
+/// Parses a timestamp of the form `(1647037105.000000)` into seconds.
+fn parse_epoch_timestamp(s: &str) -> Option<f64> {
+    s.strip_prefix('(')?.strip_suffix(')')?.parse().ok()
+}
+
+/// Parses a timestamp of the form `(2024-01-02 15:04:05.123456)`, split into the tokens `date` and
+/// `time`, into seconds since the Unix epoch. The date and time are interpreted as UTC.
+#[cfg(feature = "chrono")]
+fn parse_date_time_timestamp(date: &str, time: &str) -> Option<f64> {
+    use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+    let date = NaiveDate::parse_from_str(date.strip_prefix('(')?, "%Y-%m-%d").ok()?;
+    let time = NaiveTime::parse_from_str(time.strip_suffix(')')?, "%H:%M:%S%.f").ok()?;
+    let date_time = NaiveDateTime::new(date, time).and_utc();
+    Some(date_time.timestamp() as f64 + date_time.timestamp_subsec_nanos() as f64 / 1e9)
+}
+
+/// Absolute timestamps require the `chrono` feature.
+#[cfg(not(feature = "chrono"))]
+fn parse_date_time_timestamp(_date: &str, _time: &str) -> Option<f64> {
+    None
+}
+
+/// Removes the trailing line ending the same way [BufRead::lines] does.
+fn trim_line_ending(line: &str) -> &str {
+    let line = line.strip_suffix('\n').unwrap_or(line);
+    line.strip_suffix('\r').unwrap_or(line)
+}
+
+/// Reads the next line from `reader` into `buffer` and advances `offset` by its length in bytes.
+/// Returns `None` at the end of `reader` and at the first I/O error, e.g., a truncated gzip
+/// stream, since retrying would fail the same way again.
+fn next_line<'a, R: BufRead>(
+    reader: &mut R,
+    buffer: &'a mut Vec<u8>,
+    offset: &mut u64,
+) -> Option<Result<&'a str, Utf8Error>> {
+    buffer.clear();
+    match reader.read_until(b'\n', buffer) {
+        Ok(0) | Err(_) => None,
+        Ok(length) => {
+            *offset += length as u64;
+            Some(std::str::from_utf8(buffer).map(trim_line_ending))
+        }
+    }
+}
+
+/// Reads lines from `reader` until one parses as a `T`. Lines that are not valid UTF-8 or cannot
+/// be parsed are skipped.
+fn next_entry<R: BufRead, T: FromStr>(
+    reader: &mut R,
+    buffer: &mut Vec<u8>,
+    offset: &mut u64,
+) -> Option<T> {
+    loop {
+        if let Ok(Ok(entry)) = next_line(reader, buffer, offset)?.map(str::parse) {
+            return Some(entry);
+        }
+    }
+}
 
 ///
 ///
@@ -35,9 +108,91 @@ impl CANDump {
         Ok(CANDump { file })
     }
 
+    /// Opens the gzip-compressed logfile located at `path`, e.g., `capture.log.gz`. The returned
+    /// iterator decompresses the logfile while iterating.
+    #[cfg(feature = "flate2")]
+    pub fn open_gz<P>(
+        path: P,
+    ) -> io::Result<CANDumpIterator<BufReader<flate2::read::GzDecoder<File>>>>
+    where
+        P: AsRef<Path>,
+    {
+        let file = File::open(path)?;
+        Ok(CANDump::from_reader(BufReader::new(
+            flate2::read::GzDecoder::new(file),
+        )))
+    }
+
+    /// Returns an iterator over the entries read line by line from the standard input. The
+    /// iterator yields every entry as soon as its line arrives, which makes it suitable for live
+    /// streams, e.g., `candump vcan0 | my_tool`.
+    pub fn from_stdin() -> CANDumpIterator<StdinLock<'static>> {
+        CANDump::from_reader(io::stdin().lock())
+    }
+
+    /// Returns an iterator over the entries read line by line from `reader`, e.g., a socket or
+    /// an in-memory buffer.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::logging::CANDump;
+    /// use std::io::Cursor;
+    /// let mut iterator = CANDump::from_reader(Cursor::new("can0 1FF [1] 01"));
+    /// assert!(iterator.next().is_some());
+    /// ```
+    pub fn from_reader<R: BufRead>(reader: R) -> CANDumpIterator<R> {
+        CANDumpIterator::new(reader, 0)
+    }
+
+    /// Opens the logfile located at `path` and returns an iterator starting at the byte `offset`,
+    /// e.g., one returned by [CANDumpIterator::byte_offset], to resume an earlier iteration.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use cantools::logging::CANDump;
+    /// let mut iterator = CANDump::open("log_file").unwrap().into_iter();
+    /// iterator.next();
+    /// let checkpoint = iterator.byte_offset();
+    ///
+    /// let resumed = CANDump::open_at("log_file", checkpoint).unwrap();
+    /// ```
+    pub fn open_at<P>(path: P, offset: u64) -> io::Result<CANDumpIterator>
+    where
+        P: AsRef<Path>,
+    {
+        let mut file = File::open(path)?;
+        file.seek(SeekFrom::Start(offset))?;
+        Ok(CANDumpIterator::new(BufReader::new(file), offset))
+    }
+
     pub fn into_inner(self) -> File {
         self.file
     }
+
+    /// Returns an iterator over the results of parsing every line of the logfile. Other than the
+    /// iterator returned by [into_iter](IntoIterator::into_iter), lines that cannot be parsed
+    /// yield their parse error instead of being skipped. See [CANDumpIterator::with_errors].
+    pub fn into_iter_with_errors(self) -> CANDumpResultIterator {
+        self.into_iter().with_errors()
+    }
+
+    /// Calls `f` with every entry of the logfile. Other than the iterator returned by
+    /// [into_iter](IntoIterator::into_iter), this reuses a single line buffer and does not
+    /// allocate per entry. Lines that cannot be parsed are skipped.
+    pub fn for_each_ref<F>(self, mut f: F) -> io::Result<()>
+    where
+        F: FnMut(CANDumpEntryRef),
+    {
+        let mut reader = BufReader::new(self.file);
+        let mut line = String::new();
+        while reader.read_line(&mut line)? != 0 {
+            if let Ok(entry) = CANDumpEntryRef::parse(trim_line_ending(&line)) {
+                f(entry);
+            }
+            line.clear();
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -59,8 +214,23 @@ impl CANRead for CANDumpEntry {
 #[derive(Debug, PartialEq)]
 pub enum CANDumpEntryConstructionError {
     EmptyInterface,
+    /// The data exceeds the 64 bytes of a CAN-FD frame.
+    DataTooLong,
+}
+
+impl fmt::Display for CANDumpEntryConstructionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CANDumpEntryConstructionError::EmptyInterface => write!(f, "interface is empty"),
+            CANDumpEntryConstructionError::DataTooLong => {
+                write!(f, "data exceeds {} bytes", MAX_DATA_LENGTH)
+            }
+        }
+    }
 }
 
+impl Error for CANDumpEntryConstructionError {}
+
 impl CANDumpEntry {
     pub fn new(
         interface: &str,
@@ -69,6 +239,8 @@ impl CANDumpEntry {
     ) -> Result<Self, CANDumpEntryConstructionError> {
         if interface.is_empty() {
             Err(CANDumpEntryConstructionError::EmptyInterface)
+        } else if data.len() > MAX_DATA_LENGTH {
+            Err(CANDumpEntryConstructionError::DataTooLong)
         } else {
             Ok(CANDumpEntry {
                 interface: String::from(interface),
@@ -77,6 +249,68 @@ impl CANDumpEntry {
             })
         }
     }
+
+    /// Parses a line of a [CANDump]-like format described by `format`.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::logging::{CANDumpEntry, CANDumpFormat};
+    ///
+    /// let format = CANDumpFormat {
+    ///     separator: '\t',
+    ///     ..CANDumpFormat::default()
+    /// };
+    /// let entry = CANDumpEntry::parse_with("can0\t1ff\t[1]\t0a", &format).unwrap();
+    /// assert_eq!(entry, CANDumpEntry::new("can0", 0x1FF, vec![0x0A]).unwrap());
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the `id_radix` of `format` is not in the range from 2 to 36.
+    pub fn parse_with(s: &str, format: &CANDumpFormat) -> Result<Self, CANDumpEntryParseError> {
+        CANDumpEntryRef::parse_with(s, format).map(|entry| entry.into_owned())
+    }
+}
+
+/// Type describing the layout of lines of a [CANDump]-like format.
+///
+/// The default describes the format written by `candump`, i.e., fields separated by spaces, a
+/// hexadecimal CAN-ID, and a DLC matching the number of data bytes. Runs of separators count as
+/// one, and whitespace surrounding the line is ignored.
+#[derive(Debug, PartialEq)]
+pub struct CANDumpFormat {
+    /// The character separating the fields of a line. A whitespace separator, e.g., the default
+    /// `' '`, matches any whitespace character, i.e., spaces and tabs alike.
+    pub separator: char,
+    /// The radix of the CAN-ID.
+    pub id_radix: u32,
+    /// Whether the DLC has to match the number of data bytes.
+    pub strict_dlc: bool,
+    /// The byte order of the CAN-ID. [Endian::Big] reads the CAN-ID as a number of radix
+    /// `id_radix`. [Endian::Little] reads it as up to four hexadecimal bytes, least significant
+    /// byte first, as written by some loggers, e.g., `37130000` for `0x1337`. `id_radix` is
+    /// ignored in that case.
+    pub id_endian: Endian,
+}
+
+impl Default for CANDumpFormat {
+    fn default() -> Self {
+        CANDumpFormat {
+            separator: ' ',
+            id_radix: 16,
+            strict_dlc: true,
+            id_endian: Endian::Big,
+        }
+    }
+}
+
+/// Parses a CAN-ID consisting of up to four hexadecimal bytes, least significant byte first.
+fn parse_little_endian_id(s: &str) -> Option<u32> {
+    let mut bytes = [0u8; 4];
+    let length = parse_hex_bytes_into(s, &mut bytes).ok()?;
+    if length == 0 {
+        return None;
+    }
+    Some(u32::from_le_bytes(bytes))
 }
 
 #[derive(Debug, PartialEq)]
@@ -89,90 +323,258 @@ pub enum CANDumpEntryParseError {
     ParseCanDataError,
     DlcDataMismatch,
     ConstructionError(CANDumpEntryConstructionError),
+    /// The line is not valid UTF-8.
+    InvalidUtf8,
+}
+
+impl fmt::Display for CANDumpEntryParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CANDumpEntryParseError::MissingInterfaceData => write!(f, "missing interface"),
+            CANDumpEntryParseError::MissingCanIdData => write!(f, "missing CAN-bus identifier"),
+            CANDumpEntryParseError::MissingDlcData => write!(f, "missing dlc"),
+            CANDumpEntryParseError::ParseDlcError => write!(f, "invalid dlc"),
+            CANDumpEntryParseError::ParseCanIdError => write!(f, "invalid CAN-bus identifier"),
+            CANDumpEntryParseError::ParseCanDataError => write!(f, "invalid CAN-bus data"),
+            CANDumpEntryParseError::DlcDataMismatch => {
+                write!(f, "dlc does not match the number of data bytes")
+            }
+            CANDumpEntryParseError::ConstructionError(err) => write!(f, "invalid entry: {}", err),
+            CANDumpEntryParseError::InvalidUtf8 => write!(f, "line is not valid UTF-8"),
+        }
+    }
+}
+
+impl Error for CANDumpEntryParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CANDumpEntryParseError::ConstructionError(err) => Some(err),
+            _ => None,
+        }
+    }
 }
 
 impl FromStr for CANDumpEntry {
     type Err = CANDumpEntryParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let splits = s.split(' ').collect::<Vec<_>>();
+        CANDumpEntryRef::parse(s).map(|entry| entry.into_owned())
+    }
+}
+
+/// A [CANDumpEntry] borrowing its interface from the parsed line and storing its data inline.
+///
+/// Parsing a [CANDumpEntryRef] does not allocate, which makes it suitable for decoding large
+/// logfiles where every entry is discarded after use (see [CANDump::for_each_ref]).
+///
+/// # Example
+/// ```
+/// use cantools::data::CANRead;
+/// use cantools::logging::CANDumpEntryRef;
+///
+/// let entry = CANDumpEntryRef::parse("vcan0 00001337 [2] 01 02").unwrap();
+/// assert_eq!(entry.interface(), "vcan0");
+/// assert_eq!(entry.data(), &[0x01, 0x02]);
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct CANDumpEntryRef<'a> {
+    interface: &'a str,
+    can_id: u32,
+    data: [u8; MAX_DATA_LENGTH],
+    length: usize,
+}
 
-        let interface = match splits.get(0).copied() {
+impl<'a> CANDumpEntryRef<'a> {
+    /// Parses a line of the [CANDump] format without allocating.
+    pub fn parse(s: &'a str) -> Result<Self, CANDumpEntryParseError> {
+        CANDumpEntryRef::parse_with(s, &CANDumpFormat::default())
+    }
+
+    /// Parses a line of a [CANDump]-like format described by `format` without allocating.
+    ///
+    /// The `[dlc]` token is optional, e.g., `can0 1FF 01 02`. Without it, the DLC is the number of
+    /// data bytes. Frames without data bytes still require the token, e.g., `can0 1FF [0]`.
+    ///
+    /// # Panics
+    /// Panics if the `id_radix` of `format` is not in the range from 2 to 36.
+    pub fn parse_with(s: &'a str, format: &CANDumpFormat) -> Result<Self, CANDumpEntryParseError> {
+        // runs of separators count as one, e.g., in column-aligned output, and a whitespace
+        // separator matches any whitespace, e.g., tabs mixed with spaces
+        let separator = format.separator;
+        let mut splits = s
+            .trim()
+            .split(|c: char| {
+                if separator.is_whitespace() {
+                    c.is_whitespace()
+                } else {
+                    c == separator
+                }
+            })
+            .filter(|token| !token.is_empty());
+
+        let interface = match splits.next() {
             Some(interface) => interface,
             None => return Err(CANDumpEntryParseError::MissingInterfaceData),
         };
 
-        let can_id = match splits.get(1).copied().map(|e| u32::from_str_radix(e, 16)) {
-            Some(Ok(can_id)) => can_id,
-            _ => return Err(CANDumpEntryParseError::MissingCanIdData),
+        let can_id = match (splits.next(), &format.id_endian) {
+            (Some(id), Endian::Big) => u32::from_str_radix(id, format.id_radix).ok(),
+            (Some(id), Endian::Little) => parse_little_endian_id(id),
+            (None, _) => None,
+        };
+
+        let can_id = match can_id {
+            Some(can_id) => can_id,
+            None => return Err(CANDumpEntryParseError::MissingCanIdData),
         };
 
-        let dlc_string = match splits.get(2).copied() {
+        let dlc_string = match splits.next() {
             Some(dlc_string) => dlc_string,
             None => return Err(CANDumpEntryParseError::MissingDlcData),
         };
 
-        let dlc = match dlc_string[1..dlc_string.len() - 1].parse::<usize>() {
-            Ok(dlc) => dlc,
-            Err(_) => return Err(CANDumpEntryParseError::ParseDlcError),
+        // without the bracketed dlc token, the token is the first data byte
+        let (dlc, first_byte) = if dlc_string.contains(['[', ']']) {
+            match dlc_string
+                .strip_prefix('[')
+                .and_then(|dlc| dlc.strip_suffix(']'))
+                .map(|dlc| dlc.parse::<usize>())
+            {
+                Some(Ok(dlc)) => (Some(dlc), None),
+                _ => return Err(CANDumpEntryParseError::ParseDlcError),
+            }
+        } else {
+            (None, Some(dlc_string))
         };
 
-        let mut data = Vec::new();
-        for entry in splits.into_iter().skip(3) {
-            match u8::from_str_radix(entry, 16) {
-                Ok(value) => data.push(value),
+        let mut data = [0u8; MAX_DATA_LENGTH];
+        let mut length = 0;
+        for entry in first_byte.into_iter().chain(splits) {
+            match (data.get_mut(length), u8::from_str_radix(entry, 16)) {
+                (Some(byte), Ok(value)) => *byte = value,
+                (None, Ok(_)) => {
+                    return Err(CANDumpEntryParseError::ConstructionError(
+                        CANDumpEntryConstructionError::DataTooLong,
+                    ))
+                }
                 _ => return Err(CANDumpEntryParseError::ParseCanDataError),
             }
+            length += 1;
         }
 
-        if dlc != data.len() {
+        if format.strict_dlc && dlc.is_some_and(|dlc| dlc != length) {
             return Err(CANDumpEntryParseError::DlcDataMismatch);
         }
 
-        match CANDumpEntry::new(interface, can_id, data) {
-            Ok(entry) => Ok(entry),
-            Err(err) => Err(CANDumpEntryParseError::ConstructionError(err)),
+        if interface.is_empty() {
+            return Err(CANDumpEntryParseError::ConstructionError(
+                CANDumpEntryConstructionError::EmptyInterface,
+            ));
+        }
+
+        Ok(CANDumpEntryRef {
+            interface,
+            can_id,
+            data,
+            length,
+        })
+    }
+
+    /// Returns the interface the frame was received on.
+    pub fn interface(&self) -> &'a str {
+        self.interface
+    }
+
+    /// Returns the CAN-ID of the frame.
+    pub fn can_id(&self) -> u32 {
+        self.can_id
+    }
+
+    /// Converts the borrowed entry into an owned [CANDumpEntry].
+    pub fn into_owned(self) -> CANDumpEntry {
+        CANDumpEntry {
+            interface: String::from(self.interface),
+            can_id: self.can_id,
+            data: self.data().to_vec(),
         }
     }
 }
 
-impl ToString for CANDumpEntry {
-    fn to_string(&self) -> String {
-        let data_string = self
-            .data
-            .iter()
-            .map(|x| format!("{:02X}", x))
-            .collect::<Vec<_>>()
-            .join(" ");
+impl CANRead for CANDumpEntryRef<'_> {
+    fn data(&self) -> &[u8] {
+        &self.data[..self.length]
+    }
 
-        format!(
-            "{} {:08X} [{}] {}",
+    fn dlc(&self) -> usize {
+        self.length
+    }
+}
+
+impl fmt::Display for CANDumpEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {:08X} [{}]",
             self.interface,
             self.can_id,
-            self.data.len(),
-            data_string
-        )
+            self.data.len()
+        )?;
+        // frames without data end after the DLC, without a trailing space
+        if !self.data.is_empty() {
+            write!(f, " ")?;
+        }
+        write_hex_bytes(f, &self.data, " ")
     }
 }
 
-pub struct CANDumpIterator {
-    lines: Lines<BufReader<File>>,
+pub struct CANDumpIterator<R = BufReader<File>> {
+    reader: R,
+    buffer: Vec<u8>,
+    offset: u64,
+}
+
+impl<R: BufRead> CANDumpIterator<R> {
+    fn new(reader: R, offset: u64) -> Self {
+        CANDumpIterator {
+            reader,
+            buffer: Vec::new(),
+            offset,
+        }
+    }
+
+    /// Returns the byte offset of the next line to read, i.e., the position right after the
+    /// last line read. Pass it to [CANDump::open_at] to resume the iteration at this position.
+    pub fn byte_offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Turns the iterator into one over the results of parsing every remaining line. Lines that
+    /// cannot be parsed yield their parse error instead of being skipped, lines that are not
+    /// valid UTF-8 yield [CANDumpEntryParseError::InvalidUtf8].
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::logging::{CANDump, CANDumpEntryParseError};
+    /// use std::io::Cursor;
+    /// let reader = Cursor::new(&b"can0 1FF [1] 01\n\xFF\n"[..]);
+    /// let mut iterator = CANDump::from_reader(reader).with_errors();
+    /// assert!(matches!(iterator.next(), Some(Ok(_))));
+    /// assert_eq!(iterator.next(), Some(Err(CANDumpEntryParseError::InvalidUtf8)));
+    /// assert_eq!(iterator.next(), None);
+    /// ```
+    pub fn with_errors(self) -> CANDumpResultIterator<R> {
+        CANDumpResultIterator {
+            reader: self.reader,
+            buffer: self.buffer,
+            offset: self.offset,
+        }
+    }
 }
 
-impl Iterator for CANDumpIterator {
+impl<R: BufRead> Iterator for CANDumpIterator<R> {
     type Item = CANDumpEntry;
     fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            let line = self.lines.next();
-            match line {
-                Some(Ok(line)) => match line.parse::<Self::Item>() {
-                    Ok(entry) => return Some(entry),
-                    Err(_) => continue,
-                },
-                Some(Err(_)) => continue,
-                None => return None,
-            }
-        }
+        next_entry(&mut self.reader, &mut self.buffer, &mut self.offset)
     }
 }
 
@@ -180,9 +582,34 @@ impl IntoIterator for CANDump {
     type Item = CANDumpEntry;
     type IntoIter = CANDumpIterator;
     fn into_iter(self) -> Self::IntoIter {
-        CANDumpIterator {
-            lines: io::BufReader::new(self.into_inner()).lines(),
-        }
+        CANDumpIterator::new(BufReader::new(self.into_inner()), 0)
+    }
+}
+
+/// Iterator over the results of parsing every line of a [CANDump], see
+/// [CANDumpIterator::with_errors].
+pub struct CANDumpResultIterator<R = BufReader<File>> {
+    reader: R,
+    buffer: Vec<u8>,
+    offset: u64,
+}
+
+impl<R: BufRead> CANDumpResultIterator<R> {
+    /// Returns the byte offset of the next line to read, i.e., the position right after the
+    /// last line read. Pass it to [CANDump::open_at] to resume the iteration at this position.
+    pub fn byte_offset(&self) -> u64 {
+        self.offset
+    }
+}
+
+impl<R: BufRead> Iterator for CANDumpResultIterator<R> {
+    type Item = Result<CANDumpEntry, CANDumpEntryParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = next_line(&mut self.reader, &mut self.buffer, &mut self.offset)?;
+        Some(
+            line.map_err(|_| CANDumpEntryParseError::InvalidUtf8)
+                .and_then(str::parse),
+        )
     }
 }
 
@@ -199,16 +626,115 @@ impl CANDumpLog {
         Ok(CANDumpLog { file })
     }
 
+    /// Opens the gzip-compressed logfile located at `path`, e.g., `capture.log.gz`. The returned
+    /// iterator decompresses the logfile while iterating.
+    #[cfg(feature = "flate2")]
+    pub fn open_gz<P>(
+        path: P,
+    ) -> io::Result<CANDumpLogIterator<BufReader<flate2::read::GzDecoder<File>>>>
+    where
+        P: AsRef<Path>,
+    {
+        let file = File::open(path)?;
+        Ok(CANDumpLog::from_reader(BufReader::new(
+            flate2::read::GzDecoder::new(file),
+        )))
+    }
+
+    /// Returns an iterator over the entries read line by line from the standard input. The
+    /// iterator yields every entry as soon as its line arrives, which makes it suitable for live
+    /// streams, e.g., `candump -L vcan0 | my_tool`.
+    pub fn from_stdin() -> CANDumpLogIterator<StdinLock<'static>> {
+        CANDumpLog::from_reader(io::stdin().lock())
+    }
+
+    /// Returns an iterator over the entries read line by line from `reader`, e.g., a socket or
+    /// an in-memory buffer.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::logging::CANDumpLog;
+    /// use std::io::Cursor;
+    /// let mut iterator = CANDumpLog::from_reader(Cursor::new("(1.0) can0 000001FF#01"));
+    /// assert!(iterator.next().is_some());
+    /// ```
+    pub fn from_reader<R: BufRead>(reader: R) -> CANDumpLogIterator<R> {
+        CANDumpLogIterator::new(reader, 0)
+    }
+
+    /// Opens the logfile located at `path` and returns an iterator starting at the byte `offset`,
+    /// e.g., one returned by [CANDumpLogIterator::byte_offset], to resume an earlier iteration.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use cantools::logging::CANDumpLog;
+    /// let mut iterator = CANDumpLog::open("log_file").unwrap().into_iter();
+    /// iterator.next();
+    /// let checkpoint = iterator.byte_offset();
+    ///
+    /// let resumed = CANDumpLog::open_at("log_file", checkpoint).unwrap();
+    /// ```
+    pub fn open_at<P>(path: P, offset: u64) -> io::Result<CANDumpLogIterator>
+    where
+        P: AsRef<Path>,
+    {
+        let mut file = File::open(path)?;
+        file.seek(SeekFrom::Start(offset))?;
+        Ok(CANDumpLogIterator::new(BufReader::new(file), offset))
+    }
+
     pub fn into_inner(self) -> File {
         self.file
     }
+
+    /// Returns an iterator over the results of parsing every line of the logfile. Other than the
+    /// iterator returned by [into_iter](IntoIterator::into_iter), lines that cannot be parsed
+    /// yield their parse error instead of being skipped. See [CANDumpLogIterator::with_errors].
+    pub fn into_iter_with_errors(self) -> CANDumpLogResultIterator {
+        self.into_iter().with_errors()
+    }
+
+    /// Returns an iterator over the frames, comments, and restart markers of the logfile. Other
+    /// than the iterator returned by [into_iter](IntoIterator::into_iter), comments and restart
+    /// markers are yielded instead of being skipped. See [LogEvent::parse] for the recognized
+    /// lines. Lines that are not valid UTF-8 are skipped like unrecognized ones.
+    pub fn into_events(self) -> impl Iterator<Item = LogEvent> {
+        let mut reader = BufReader::new(self.file);
+        let mut buffer = Vec::new();
+        let mut offset = 0;
+        std::iter::from_fn(move || loop {
+            if let Ok(line) = next_line(&mut reader, &mut buffer, &mut offset)? {
+                if let Some(event) = LogEvent::parse(line) {
+                    return Some(event);
+                }
+            }
+        })
+    }
+
+    /// Calls `f` with every entry of the logfile. Other than the iterator returned by
+    /// [into_iter](IntoIterator::into_iter), this reuses a single line buffer and does not
+    /// allocate per entry. Lines that cannot be parsed are skipped.
+    pub fn for_each_ref<F>(self, mut f: F) -> io::Result<()>
+    where
+        F: FnMut(CANDumpLogEntryRef),
+    {
+        let mut reader = BufReader::new(self.file);
+        let mut line = String::new();
+        while reader.read_line(&mut line)? != 0 {
+            if let Ok(entry) = CANDumpLogEntryRef::parse(trim_line_ending(&line)) {
+                f(entry);
+            }
+            line.clear();
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, PartialEq)]
 pub struct CANDumpLogEntry {
     timestamp: f64,
     interface: String,
-    can_id: u32,
+    can_id: CanId,
     data: Vec<u8>,
     flag: Option<u8>,
 }
@@ -217,17 +743,76 @@ pub struct CANDumpLogEntry {
 pub enum CANDumpLogEntryConstructionError {
     InvalidTimestamp,
     EmptyInterface,
+    /// Formerly returned for flags above `0x0F`. Every `u8` is a valid flag since full byte flags
+    /// are supported, so this error is never returned.
+    #[deprecated(note = "every u8 is a valid flag, the error is never returned")]
     InvalidFlagValue,
+    /// The data exceeds the 64 bytes of a CAN-FD frame.
+    DataTooLong,
+}
+
+impl fmt::Display for CANDumpLogEntryConstructionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CANDumpLogEntryConstructionError::InvalidTimestamp => {
+                write!(f, "timestamp is not finite")
+            }
+            CANDumpLogEntryConstructionError::EmptyInterface => write!(f, "interface is empty"),
+            #[allow(deprecated)]
+            CANDumpLogEntryConstructionError::InvalidFlagValue => write!(f, "invalid flag value"),
+            CANDumpLogEntryConstructionError::DataTooLong => {
+                write!(f, "data exceeds {} bytes", MAX_DATA_LENGTH)
+            }
+        }
+    }
 }
 
+impl Error for CANDumpLogEntryConstructionError {}
+
 impl CANDumpLogEntry {
     pub fn new(
         timestamp: f64,
         interface: &str,
-        can_id: u32,
+        can_id: impl Into<CanId>,
         data: Vec<u8>,
         flag: Option<u8>,
     ) -> Result<Self, CANDumpLogEntryConstructionError> {
+        CANDumpLogEntry::validate(timestamp, interface, data.len())?;
+
+        Ok(CANDumpLogEntry {
+            timestamp,
+            interface: String::from(interface),
+            can_id: can_id.into(),
+            data,
+            flag,
+        })
+    }
+
+    /// Returns the timestamp of the frame in seconds.
+    pub fn timestamp(&self) -> f64 {
+        self.timestamp
+    }
+
+    /// Returns the interface the frame was received on.
+    pub fn interface(&self) -> &str {
+        &self.interface
+    }
+
+    /// Returns the CAN-ID of the frame.
+    pub fn can_id(&self) -> CanId {
+        self.can_id
+    }
+
+    /// Returns the flag of a CAN-FD frame, if any.
+    pub fn flag(&self) -> Option<u8> {
+        self.flag
+    }
+
+    fn validate(
+        timestamp: f64,
+        interface: &str,
+        length: usize,
+    ) -> Result<(), CANDumpLogEntryConstructionError> {
         if timestamp.is_nan() || timestamp.is_infinite() {
             return Err(CANDumpLogEntryConstructionError::InvalidTimestamp);
         }
@@ -236,19 +821,11 @@ impl CANDumpLogEntry {
             return Err(CANDumpLogEntryConstructionError::EmptyInterface);
         }
 
-        if let Some(x) = flag {
-            if x > 0x0F {
-                return Err(CANDumpLogEntryConstructionError::InvalidFlagValue);
-            };
+        if length > MAX_DATA_LENGTH {
+            return Err(CANDumpLogEntryConstructionError::DataTooLong);
         }
 
-        Ok(CANDumpLogEntry {
-            timestamp,
-            interface: String::from(interface),
-            can_id,
-            data,
-            flag,
-        })
+        Ok(())
     }
 }
 
@@ -266,133 +843,230 @@ pub enum CANDumpLogEntryParseError {
     ParseFlagError,
     ConstructionError(CANDumpLogEntryConstructionError),
     Unspecified,
+    /// The line is not valid UTF-8.
+    InvalidUtf8,
+}
+
+impl fmt::Display for CANDumpLogEntryParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CANDumpLogEntryParseError::MissingTimestampData => write!(f, "missing timestamp"),
+            CANDumpLogEntryParseError::ParseTimestampError => write!(f, "invalid timestamp"),
+            CANDumpLogEntryParseError::MissingInterfaceData => write!(f, "missing interface"),
+            CANDumpLogEntryParseError::MissingCompoundCanData => {
+                write!(f, "missing CAN-bus identifier and data")
+            }
+            CANDumpLogEntryParseError::MissingCanIdData => {
+                write!(f, "missing CAN-bus identifier")
+            }
+            CANDumpLogEntryParseError::MissingCanData => write!(f, "missing CAN-bus data"),
+            CANDumpLogEntryParseError::MissingFlagData => write!(f, "missing flag"),
+            CANDumpLogEntryParseError::ParseCanIdError => write!(f, "invalid CAN-bus identifier"),
+            CANDumpLogEntryParseError::ParseCanDataError => write!(f, "invalid CAN-bus data"),
+            CANDumpLogEntryParseError::ParseFlagError => write!(f, "invalid flag"),
+            CANDumpLogEntryParseError::ConstructionError(err) => {
+                write!(f, "invalid entry: {}", err)
+            }
+            CANDumpLogEntryParseError::Unspecified => write!(f, "malformed entry"),
+            CANDumpLogEntryParseError::InvalidUtf8 => write!(f, "line is not valid UTF-8"),
+        }
+    }
+}
+
+impl Error for CANDumpLogEntryParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CANDumpLogEntryParseError::ConstructionError(err) => Some(err),
+            _ => None,
+        }
+    }
 }
 
 impl FromStr for CANDumpLogEntry {
     type Err = CANDumpLogEntryParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let splits = s.split(' ').take(3).collect::<Vec<_>>();
+        CANDumpLogEntryRef::parse(s).map(|entry| entry.into_owned())
+    }
+}
+
+/// A [CANDumpLogEntry] borrowing its interface from the parsed line and storing its data inline.
+///
+/// Parsing a [CANDumpLogEntryRef] does not allocate, which makes it suitable for decoding large
+/// logfiles where every entry is discarded after use (see [CANDumpLog::for_each_ref]).
+///
+/// # Example
+/// ```
+/// use cantools::data::CANRead;
+/// use cantools::logging::CANDumpLogEntryRef;
+///
+/// let entry = CANDumpLogEntryRef::parse("(1.5) vcan0 00000042#1234").unwrap();
+/// assert_eq!(entry.interface(), "vcan0");
+/// assert_eq!(entry.data(), &[0x12, 0x34]);
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct CANDumpLogEntryRef<'a> {
+    timestamp: f64,
+    interface: &'a str,
+    can_id: CanId,
+    data: [u8; MAX_DATA_LENGTH],
+    length: usize,
+    flag: Option<u8>,
+}
 
-        let timestamp = match splits.get(0).copied() {
+impl<'a> CANDumpLogEntryRef<'a> {
+    /// Parses a line of the [CANDumpLog] format without allocating.
+    ///
+    /// Timestamps are seconds since the Unix epoch, e.g., `(1647037105.000000)`. With the `chrono`
+    /// feature, absolute timestamps written by `candump -tA`, e.g.,
+    /// `(2024-01-02 15:04:05.123456)`, are accepted as well and converted into seconds since the
+    /// epoch, interpreting the date and time as UTC.
+    pub fn parse(s: &'a str) -> Result<Self, CANDumpLogEntryParseError> {
+        let mut splits = s.split_whitespace();
+
+        let timestamp = match splits.next() {
             Some(timestamp) => timestamp,
             None => return Err(CANDumpLogEntryParseError::MissingTimestampData),
         };
 
-        let timestamp = match timestamp[1..timestamp.len() - 1].parse::<f64>() {
-            Ok(timestamp) => timestamp,
-            Err(_) => return Err(CANDumpLogEntryParseError::ParseTimestampError),
+        let timestamp = if timestamp.ends_with(')') {
+            parse_epoch_timestamp(timestamp)
+        } else {
+            // absolute timestamps of the form `(2024-01-02 15:04:05.123456)` contain a space
+            splits
+                .next()
+                .and_then(|time| parse_date_time_timestamp(timestamp, time))
         };
 
-        let interface = match splits.get(1).copied() {
+        let timestamp = match timestamp {
+            Some(timestamp) => timestamp,
+            None => return Err(CANDumpLogEntryParseError::ParseTimestampError),
+        };
+
+        let interface = match splits.next() {
             Some(interface) => interface,
             None => return Err(CANDumpLogEntryParseError::MissingInterfaceData),
         };
 
-        let can_data = match splits.get(2).copied() {
+        let can_data = match splits.next() {
             Some(can_data) => can_data,
             None => return Err(CANDumpLogEntryParseError::MissingCompoundCanData),
         };
 
-        let can_data_splits = can_data.split('#').take(3).collect::<Vec<_>>();
-
-        return match can_data_splits.len() {
-            2 => {
-                let can_id_string = match can_data_splits.get(0).copied() {
-                    Some(can_id_string) => can_id_string,
-                    None => return Err(CANDumpLogEntryParseError::MissingCanIdData),
-                };
-
-                let can_id = match u32::from_str_radix(can_id_string, 16) {
-                    Ok(can_id) => can_id,
-                    Err(_) => return Err(CANDumpLogEntryParseError::ParseCanIdError),
-                };
-
-                let data_string = match can_data_splits.get(1).copied() {
-                    Some(data_string) => data_string,
-                    None => return Err(CANDumpLogEntryParseError::MissingCanData),
-                };
-
-                let mut data = Vec::new();
-                for i in 0..data_string.len().div(2) {
-                    match u8::from_str_radix(&data_string[2 * i..2 * i + 2], 16) {
-                        Ok(value) => data.push(value),
-                        Err(_) => return Err(CANDumpLogEntryParseError::ParseCanDataError),
-                    };
-                }
+        let mut can_data_splits = can_data.split('#');
+        let can_id_string = match can_data_splits.next() {
+            Some(can_id_string) => can_id_string,
+            None => return Err(CANDumpLogEntryParseError::MissingCanIdData),
+        };
 
-                match CANDumpLogEntry::new(timestamp, interface, can_id, data, None) {
-                    Ok(entry) => Ok(entry),
-                    Err(err) => Err(CANDumpLogEntryParseError::ConstructionError(err)),
-                }
-            }
-            3 => {
-                let can_id_string = match can_data_splits.get(0).copied() {
-                    Some(can_id_string) => can_id_string,
-                    None => return Err(CANDumpLogEntryParseError::MissingCanIdData),
-                };
-
-                let can_id = match u32::from_str_radix(can_id_string, 16) {
-                    Ok(can_id) => can_id,
-                    Err(_) => return Err(CANDumpLogEntryParseError::ParseCanIdError),
-                };
-
-                let data_string = match can_data_splits.get(2).copied() {
-                    Some(data_string) => data_string,
-                    None => return Err(CANDumpLogEntryParseError::MissingCanData),
-                };
-
-                let flag_string = match data_string.get(0..1) {
-                    Some(flag_string) => flag_string,
-                    None => return Err(CANDumpLogEntryParseError::MissingFlagData),
-                };
-
-                let flag = match u8::from_str_radix(flag_string, 16) {
-                    Ok(flag) => flag,
-                    Err(_) => return Err(CANDumpLogEntryParseError::ParseFlagError),
-                };
-
-                let mut data = Vec::new();
-                for i in 0..(data_string.len() - 1).div(2) {
-                    match u8::from_str_radix(&data_string[2 * i + 1..2 * i + 2 + 1], 16) {
-                        Ok(value) => data.push(value),
-                        Err(_) => return Err(CANDumpLogEntryParseError::ParseCanDataError),
-                    };
-                }
+        let (data_string, fd) = match (can_data_splits.next(), can_data_splits.next()) {
+            (Some(data_string), None) => (data_string, false),
+            (Some(_), Some(data_string)) => (data_string, true),
+            _ => return Err(CANDumpLogEntryParseError::Unspecified),
+        };
 
-                match CANDumpLogEntry::new(timestamp, interface, can_id, data, Some(flag)) {
-                    Ok(entry) => Ok(entry),
-                    Err(err) => Err(CANDumpLogEntryParseError::ConstructionError(err)),
-                }
+        let can_id = match can_id_string.parse::<CanId>() {
+            Ok(can_id) => can_id,
+            Err(_) => return Err(CANDumpLogEntryParseError::ParseCanIdError),
+        };
+
+        let (data_string, flag) = if fd {
+            // The data consists of whole bytes, so an odd number of characters denotes a
+            // single-nibble flag and an even number a full byte flag.
+            let flag_length = if data_string.len() % 2 == 1 { 1 } else { 2 };
+            let flag_string = match data_string.get(0..flag_length) {
+                Some(flag_string) => flag_string,
+                None => return Err(CANDumpLogEntryParseError::MissingFlagData),
+            };
+
+            let flag = match u8::from_str_radix(flag_string, 16) {
+                Ok(flag) => flag,
+                Err(_) => return Err(CANDumpLogEntryParseError::ParseFlagError),
+            };
+
+            (&data_string[flag_length..], Some(flag))
+        } else {
+            (data_string, None)
+        };
+
+        let mut data = [0u8; MAX_DATA_LENGTH];
+        let length = match parse_hex_bytes_into(data_string, &mut data) {
+            Ok(length) => length,
+            Err(HexError::BufferTooSmall) => {
+                return Err(CANDumpLogEntryParseError::ConstructionError(
+                    CANDumpLogEntryConstructionError::DataTooLong,
+                ))
             }
-            _ => Err(CANDumpLogEntryParseError::Unspecified),
+            Err(_) => return Err(CANDumpLogEntryParseError::ParseCanDataError),
         };
+
+        if let Err(err) = CANDumpLogEntry::validate(timestamp, interface, length) {
+            return Err(CANDumpLogEntryParseError::ConstructionError(err));
+        }
+
+        Ok(CANDumpLogEntryRef {
+            timestamp,
+            interface,
+            can_id,
+            data,
+            length,
+            flag,
+        })
+    }
+
+    /// Returns the timestamp of the frame in seconds.
+    pub fn timestamp(&self) -> f64 {
+        self.timestamp
+    }
+
+    /// Returns the interface the frame was received on.
+    pub fn interface(&self) -> &'a str {
+        self.interface
+    }
+
+    /// Returns the CAN-ID of the frame.
+    pub fn can_id(&self) -> CanId {
+        self.can_id
+    }
+
+    /// Returns the flag of a CAN-FD frame, if any.
+    pub fn flag(&self) -> Option<u8> {
+        self.flag
+    }
+
+    /// Converts the borrowed entry into an owned [CANDumpLogEntry].
+    pub fn into_owned(self) -> CANDumpLogEntry {
+        CANDumpLogEntry {
+            timestamp: self.timestamp,
+            interface: String::from(self.interface),
+            can_id: self.can_id,
+            data: self.data().to_vec(),
+            flag: self.flag,
+        }
     }
 }
 
-impl ToString for CANDumpLogEntry {
-    fn to_string(&self) -> String {
-        let data_string = self
-            .data
-            .iter()
-            .map(|x| format!("{:02X}", x))
-            .collect::<Vec<_>>()
-            .join("");
-
-        return match self.flag {
-            Some(flag) => {
-                format!(
-                    "({}) {} {:08X}##{:1X}{}",
-                    self.timestamp, self.interface, self.can_id, flag, data_string
-                )
-            }
-            None => {
-                format!(
-                    "({}) {} {:08X}#{}",
-                    self.timestamp, self.interface, self.can_id, data_string
-                )
-            }
-        };
+impl CANRead for CANDumpLogEntryRef<'_> {
+    fn data(&self) -> &[u8] {
+        &self.data[..self.length]
+    }
+
+    fn dlc(&self) -> usize {
+        self.length
+    }
+}
+
+impl fmt::Display for CANDumpLogEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "({}) {} {}#",
+            self.timestamp, self.interface, self.can_id
+        )?;
+        if let Some(flag) = self.flag {
+            write!(f, "#{:X}", flag)?;
+        }
+        write_hex_bytes(f, &self.data, "")
     }
 }
 
@@ -406,24 +1080,103 @@ impl CANRead for CANDumpLogEntry {
     }
 }
 
-pub struct CANDumpLogIterator {
-    lines: Lines<BufReader<File>>,
+impl From<CANDumpEntry> for CANDumpLogEntry {
+    /// Converts a raw entry into a log entry with a timestamp of `0.0`, no flag, and an
+    /// [Extended](CanId::Extended) identifier.
+    fn from(entry: CANDumpEntry) -> Self {
+        CANDumpLogEntry {
+            timestamp: 0.0,
+            interface: entry.interface,
+            can_id: entry.can_id.into(),
+            data: entry.data,
+            flag: None,
+        }
+    }
+}
+
+impl From<CANDumpLogEntry> for CANDumpEntry {
+    /// Converts a log entry into a raw entry. The conversion is lossy: the timestamp, the flag, and
+    /// whether the identifier is a standard or extended identifier are dropped.
+    fn from(entry: CANDumpLogEntry) -> Self {
+        CANDumpEntry {
+            interface: entry.interface,
+            can_id: entry.can_id.raw(),
+            data: entry.data,
+        }
+    }
+}
+
+pub struct CANDumpLogIterator<R = BufReader<File>> {
+    reader: R,
+    buffer: Vec<u8>,
+    offset: u64,
+}
+
+impl<R: BufRead> CANDumpLogIterator<R> {
+    fn new(reader: R, offset: u64) -> Self {
+        CANDumpLogIterator {
+            reader,
+            buffer: Vec::new(),
+            offset,
+        }
+    }
+
+    /// Returns the byte offset of the next line to read, i.e., the position right after the
+    /// last line read. Pass it to [CANDumpLog::open_at] to resume the iteration at this position.
+    pub fn byte_offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Turns the iterator into one over the results of parsing every remaining line. Lines that
+    /// cannot be parsed yield their parse error instead of being skipped, lines that are not
+    /// valid UTF-8 yield [CANDumpLogEntryParseError::InvalidUtf8].
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::logging::{CANDumpLog, CANDumpLogEntryParseError};
+    /// use std::io::Cursor;
+    /// let reader = Cursor::new(&b"(1.0) can0 000001FF#01\n\xFF\n"[..]);
+    /// let mut iterator = CANDumpLog::from_reader(reader).with_errors();
+    /// assert!(matches!(iterator.next(), Some(Ok(_))));
+    /// assert_eq!(iterator.next(), Some(Err(CANDumpLogEntryParseError::InvalidUtf8)));
+    /// assert_eq!(iterator.next(), None);
+    /// ```
+    pub fn with_errors(self) -> CANDumpLogResultIterator<R> {
+        CANDumpLogResultIterator {
+            reader: self.reader,
+            buffer: self.buffer,
+            offset: self.offset,
+        }
+    }
 }
 
-impl Iterator for CANDumpLogIterator {
+impl<R: BufRead> Iterator for CANDumpLogIterator<R> {
     type Item = CANDumpLogEntry;
     fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            let line = self.lines.next();
-            match line {
-                Some(Ok(line)) => match line.parse::<CANDumpLogEntry>() {
-                    Ok(entry) => return Some(entry),
-                    Err(_) => continue,
-                },
-                Some(Err(_)) => continue,
-                None => return None,
-            }
-        }
+        next_entry(&mut self.reader, &mut self.buffer, &mut self.offset)
+    }
+}
+
+impl<R: BufRead> CANDumpLogIterator<R> {
+    /// Pairs every entry with the time in seconds passed since the previous entry with the same
+    /// CAN-ID. The time is `None` for the first entry of every CAN-ID.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use cantools::logging::CANDumpLog;
+    /// let candump = CANDumpLog::open("log_file").unwrap();
+    /// for (entry, delta) in candump.into_iter().with_deltas() {
+    ///     println!("{:?} {:?}", entry, delta);
+    /// }
+    /// ```
+    pub fn with_deltas(self) -> impl Iterator<Item = (CANDumpLogEntry, Option<f64>)> {
+        let mut previous = HashMap::new();
+        self.map(move |entry| {
+            let delta = previous
+                .insert(entry.can_id, entry.timestamp)
+                .map(|timestamp| entry.timestamp - timestamp);
+            (entry, delta)
+        })
     }
 }
 
@@ -431,8 +1184,402 @@ impl IntoIterator for CANDumpLog {
     type Item = CANDumpLogEntry;
     type IntoIter = CANDumpLogIterator;
     fn into_iter(self) -> Self::IntoIter {
-        CANDumpLogIterator {
-            lines: io::BufReader::new(self.into_inner()).lines(),
+        CANDumpLogIterator::new(BufReader::new(self.into_inner()), 0)
+    }
+}
+
+/// Iterator over the results of parsing every line of a [CANDumpLog], see
+/// [CANDumpLogIterator::with_errors].
+pub struct CANDumpLogResultIterator<R = BufReader<File>> {
+    reader: R,
+    buffer: Vec<u8>,
+    offset: u64,
+}
+
+impl<R: BufRead> CANDumpLogResultIterator<R> {
+    /// Returns the byte offset of the next line to read, i.e., the position right after the
+    /// last line read. Pass it to [CANDumpLog::open_at] to resume the iteration at this position.
+    pub fn byte_offset(&self) -> u64 {
+        self.offset
+    }
+}
+
+impl<R: BufRead> Iterator for CANDumpLogResultIterator<R> {
+    type Item = Result<CANDumpLogEntry, CANDumpLogEntryParseError>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = next_line(&mut self.reader, &mut self.buffer, &mut self.offset)?;
+        Some(
+            line.map_err(|_| CANDumpLogEntryParseError::InvalidUtf8)
+                .and_then(str::parse),
+        )
+    }
+}
+
+/// Type modeling the lines of a [CANDumpLog] that are recognized, see [CANDumpLog::into_events].
+#[derive(Debug, PartialEq)]
+pub enum LogEvent {
+    /// A line holding a frame.
+    Frame(CANDumpLogEntry),
+    /// A comment line starting with `#`. Holds the text following the `#` without surrounding
+    /// whitespace.
+    Comment(String),
+    /// A restart marker starting with `===`, e.g., written when a capture is restarted. Timestamps
+    /// of the frames before and after the marker are not comparable.
+    Restart,
+}
+
+impl LogEvent {
+    /// Parses one line of a logfile. Returns `None` for empty lines and lines that are neither a
+    /// frame, a comment, nor a restart marker.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::logging::LogEvent;
+    ///
+    /// assert_eq!(
+    ///     LogEvent::parse("# bus under load"),
+    ///     Some(LogEvent::Comment(String::from("bus under load")))
+    /// );
+    /// assert_eq!(LogEvent::parse("=== restart ==="), Some(LogEvent::Restart));
+    /// assert!(matches!(
+    ///     LogEvent::parse("(1.0) can0 1FF#01"),
+    ///     Some(LogEvent::Frame(_))
+    /// ));
+    /// assert_eq!(LogEvent::parse("  vcan0  TX - -  1FF"), None);
+    /// ```
+    pub fn parse(line: &str) -> Option<LogEvent> {
+        let line = line.trim();
+        if let Some(comment) = line.strip_prefix('#') {
+            Some(LogEvent::Comment(String::from(comment.trim())))
+        } else if line.starts_with("===") {
+            Some(LogEvent::Restart)
+        } else {
+            line.parse().ok().map(LogEvent::Frame)
+        }
+    }
+}
+
+/// Accumulates entries in memory and emits them as the text of a logfile, e.g., to generate
+/// fixtures for tests. Use [CANDumpEntry] entries for the [CANDump] format and
+/// [CANDumpLogEntry] entries for the [CANDumpLog] format.
+///
+/// # Example
+/// ```
+/// use cantools::logging::{CANDumpBuilder, CANDumpLogEntry};
+///
+/// let text = CANDumpBuilder::new()
+///     .entry(CANDumpLogEntry::new(1.5, "vcan0", 0x42, vec![0x12], None).unwrap())
+///     .entry(CANDumpLogEntry::new(2.5, "vcan0", 0x43, vec![0x34], None).unwrap())
+///     .build();
+/// assert_eq!(text, "(1.5) vcan0 00000042#12\n(2.5) vcan0 00000043#34\n");
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct CANDumpBuilder<E> {
+    entries: Vec<E>,
+}
+
+impl<E> Default for CANDumpBuilder<E> {
+    fn default() -> Self {
+        CANDumpBuilder {
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl<E: fmt::Display> CANDumpBuilder<E> {
+    /// Constructs an empty builder.
+    pub fn new() -> Self {
+        CANDumpBuilder::default()
+    }
+
+    /// Appends `entry` to the builder.
+    pub fn entry(mut self, entry: E) -> Self {
+        self.entries.push(entry);
+        self
+    }
+
+    /// Appends `entry` to the builder without consuming it, e.g., inside of a loop.
+    pub fn push(&mut self, entry: E) {
+        self.entries.push(entry);
+    }
+
+    /// Returns the text of the logfile, one line per entry.
+    pub fn build(&self) -> String {
+        self.entries
+            .iter()
+            .map(|entry| format!("{}\n", entry))
+            .collect()
+    }
+
+    /// Writes the text of the logfile to `path`, replacing the file if it exists.
+    pub fn write_to<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        std::fs::write(path, self.build())
+    }
+}
+
+/// An entry waiting in the heap of [merge_logs] together with the index of the log it stems
+/// from. Entries are ordered by timestamp, ties are broken by the index of the log.
+struct PendingEntry {
+    entry: CANDumpLogEntry,
+    source: usize,
+}
+
+impl PartialEq for PendingEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for PendingEntry {}
+
+impl PartialOrd for PendingEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PendingEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.entry
+            .timestamp
+            .total_cmp(&other.entry.timestamp)
+            .then(self.source.cmp(&other.source))
+    }
+}
+
+/// Merges multiple logs, each sorted by timestamp, into one iterator yielding the entries in
+/// nondecreasing timestamp order. Entries with equal timestamps are yielded in the order of the
+/// logs in `files`.
+///
+/// # Example
+/// ```no_run
+/// use cantools::logging::{merge_logs, CANDumpLog};
+/// let can0 = CANDumpLog::open("can0.log").unwrap();
+/// let can1 = CANDumpLog::open("can1.log").unwrap();
+/// for entry in merge_logs(vec![can0, can1]) {
+///     println!("{:?}", entry);
+/// }
+/// ```
+pub fn merge_logs(files: Vec<CANDumpLog>) -> impl Iterator<Item = CANDumpLogEntry> {
+    let mut iterators = files
+        .into_iter()
+        .map(|file| file.into_iter())
+        .collect::<Vec<_>>();
+
+    let mut heap = BinaryHeap::new();
+    for (source, iterator) in iterators.iter_mut().enumerate() {
+        if let Some(entry) = iterator.next() {
+            heap.push(Reverse(PendingEntry { entry, source }));
+        }
+    }
+
+    std::iter::from_fn(move || {
+        let Reverse(PendingEntry { entry, source }) = heap.pop()?;
+        if let Some(next) = iterators[source].next() {
+            heap.push(Reverse(PendingEntry {
+                entry: next,
+                source,
+            }));
+        }
+        Some(entry)
+    })
+}
+
+/// Sorts `entries` by timestamp. The sort is stable, i.e., entries with equal timestamps keep
+/// their order. Sort by `(can_id, timestamp)` to group the entries by CAN-ID first.
+///
+/// # Example
+/// ```
+/// use cantools::logging::{sort_by_time, CANDumpLogEntry};
+///
+/// let mut entries = vec![
+///     CANDumpLogEntry::new(2.0, "can0", 0x42, vec![], None).unwrap(),
+///     CANDumpLogEntry::new(1.0, "can0", 0x43, vec![], None).unwrap(),
+/// ];
+/// sort_by_time(&mut entries);
+/// assert_eq!(entries[0].timestamp(), 1.0);
+///
+/// // group by CAN-ID, then order by time
+/// entries.sort_by(|a, b| {
+///     (a.can_id(), a.timestamp())
+///         .partial_cmp(&(b.can_id(), b.timestamp()))
+///         .unwrap()
+/// });
+/// assert_eq!(entries[0].timestamp(), 2.0);
+/// ```
+pub fn sort_by_time(entries: &mut [CANDumpLogEntry]) {
+    entries.sort_by(|a, b| a.timestamp.total_cmp(&b.timestamp));
+}
+
+/// Decodes `signal` from every entry of `log` whose CAN-ID equals `id` and yields the pairs of
+/// timestamp and decoded value, e.g., to plot the signal over time. Both standard and extended
+/// identifiers match `id`. Entries the signal cannot be decoded from are skipped.
+///
+/// # Example
+/// ```no_run
+/// use cantools::logging::{signal_series, CANDumpLog};
+/// use cantools::signals::Unsigned;
+/// use cantools::utils::Endian;
+///
+/// let log = CANDumpLog::open("log_file").unwrap();
+/// let speed = Unsigned::new(0, 16, 0.01, 0.0, Endian::Little).unwrap();
+/// for (timestamp, value) in signal_series(log, 0x42, &speed) {
+///     println!("{} {}", timestamp, value);
+/// }
+/// ```
+pub fn signal_series(
+    log: CANDumpLog,
+    id: u32,
+    signal: &Unsigned,
+) -> impl Iterator<Item = (f64, f64)> + '_ {
+    log.into_iter()
+        .filter(move |entry| entry.can_id.raw() == id)
+        .filter_map(move |entry| {
+            signal
+                .try_decode(&entry)
+                .ok()
+                .map(|value| (entry.timestamp, value))
+        })
+}
+
+/// Groups time-sorted `entries` into consecutive windows of `window_secs` seconds and yields the
+/// start time of every window together with its entries, e.g., to compute frame rates. The first
+/// window starts at the timestamp of the first entry. Windows without entries are not yielded.
+///
+/// The entries are grouped in a single pass; use [sort_by_time] to sort them beforehand.
+///
+/// # Panics
+/// Panics if `window_secs` is not a positive, finite number.
+///
+/// # Example
+/// ```
+/// use cantools::logging::{windows_by_time, CANDumpLogEntry};
+///
+/// let entries = vec![
+///     CANDumpLogEntry::new(1.0, "can0", 0x42, vec![], None).unwrap(),
+///     CANDumpLogEntry::new(1.5, "can0", 0x42, vec![], None).unwrap(),
+///     CANDumpLogEntry::new(3.5, "can0", 0x42, vec![], None).unwrap(),
+/// ];
+/// let counts = windows_by_time(entries, 1.0)
+///     .map(|(start, entries)| (start, entries.len()))
+///     .collect::<Vec<_>>();
+/// assert_eq!(counts, vec![(1.0, 2), (3.0, 1)]);
+/// ```
+pub fn windows_by_time<I>(
+    entries: I,
+    window_secs: f64,
+) -> impl Iterator<Item = (f64, Vec<CANDumpLogEntry>)>
+where
+    I: IntoIterator<Item = CANDumpLogEntry>,
+{
+    assert!(
+        window_secs.is_finite() && window_secs > 0.0,
+        "window must be positive"
+    );
+
+    let mut entries = entries.into_iter().peekable();
+    let mut origin = None;
+    std::iter::from_fn(move || {
+        let first = entries.next()?;
+        let origin = *origin.get_or_insert(first.timestamp);
+
+        // computing the start from the index keeps rounding errors from accumulating
+        let index = ((first.timestamp - origin) / window_secs).floor();
+        let start = origin + index * window_secs;
+        let end = start + window_secs;
+
+        let mut window = vec![first];
+        while let Some(entry) = entries.next_if(|entry| entry.timestamp < end) {
+            window.push(entry);
+        }
+        Some((start, window))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CANDumpEntry, CANDumpEntryConstructionError, CANDumpEntryParseError, CANDumpEntryRef,
+        CANDumpLog, CANDumpLogEntry, CANDumpLogEntryRef,
+    };
+    use crate::decode::TryDecode;
+    use crate::signals::Unsigned;
+    use crate::utils::Endian;
+    use std::io::{pipe, BufReader, Write};
+
+    #[test]
+    fn test_can_dump_log_iterator_pipe() {
+        let (reader, mut writer) = pipe().unwrap();
+        let mut iterator = CANDumpLog::from_reader(BufReader::new(reader));
+
+        // every entry is available as soon as its line is written
+        writer.write_all(b"(1.0) vcan0 00000042#12\n").unwrap();
+        assert_eq!(
+            iterator.next(),
+            Some(CANDumpLogEntry::new(1.0, "vcan0", 0x42, vec![0x12], None).unwrap())
+        );
+
+        writer.write_all(b"(2.0) vcan0 00000043#1234\n").unwrap();
+        assert_eq!(
+            iterator.next(),
+            Some(CANDumpLogEntry::new(2.0, "vcan0", 0x43, vec![0x12, 0x34], None).unwrap())
+        );
+
+        drop(writer);
+        assert_eq!(iterator.next(), None);
+    }
+
+    #[test]
+    fn test_can_dump_entry_ref_001() {
+        let lines = [
+            "can0 00000042 [0]",
+            "can0 1FF [1] 01",
+            "vcan0 00001337 [8] 01 02 03 04 05 06 07 08",
+        ];
+        let signal = Unsigned::new(4, 8, 1.0, 0.0, Endian::Little).unwrap();
+
+        for line in lines {
+            let borrowed = CANDumpEntryRef::parse(line).unwrap();
+            let owned = line.parse::<CANDumpEntry>().unwrap();
+
+            let value: Result<f64, _> = signal.try_decode(&borrowed);
+            assert_eq!(value, signal.try_decode(&owned));
+            assert_eq!(borrowed.into_owned(), owned);
+        }
+    }
+
+    #[test]
+    fn test_can_dump_entry_ref_002() {
+        assert_eq!(
+            CANDumpEntryRef::parse("can0 1FF [2] 01"),
+            Err(CANDumpEntryParseError::DlcDataMismatch)
+        );
+
+        let line = format!("can0 1FF [65]{}", " 01".repeat(65));
+        assert_eq!(
+            CANDumpEntryRef::parse(&line),
+            Err(CANDumpEntryParseError::ConstructionError(
+                CANDumpEntryConstructionError::DataTooLong
+            ))
+        );
+    }
+
+    #[test]
+    fn test_can_dump_log_entry_ref_001() {
+        let lines = [
+            "(1647037105.079609) vcan0 00000042#12",
+            "(1647037105.079609) vcan0 00000042##A12",
+            "(1.5) vcan0 00001337#0102030405060708",
+        ];
+        let signal = Unsigned::new(2, 6, 1.0, 0.0, Endian::Big).unwrap();
+
+        for line in lines {
+            let borrowed = CANDumpLogEntryRef::parse(line).unwrap();
+            let owned = line.parse::<CANDumpLogEntry>().unwrap();
+
+            let value: Result<f64, _> = signal.try_decode(&borrowed);
+            assert_eq!(value, signal.try_decode(&owned));
+            assert_eq!(borrowed.into_owned(), owned);
         }
     }
 }
@@ -0,0 +1,631 @@
+//! Module providing a reader for CAN-bus frames stored in ASAM MDF 4.x files.
+//!
+//! Frames are expected in channel groups following the bus logging convention of the ASAM MDF
+//! standard, i.e., a group containing a `CAN_DataFrame` channel whose members are named
+//! `CAN_DataFrame.ID`, `CAN_DataFrame.IDE`, `CAN_DataFrame.DataLength`, and
+//! `CAN_DataFrame.DataBytes`, together with a time master channel.
+//!
+//! This first version supports uncompressed files whose records are stored in a single `##DT`
+//! block per data group. Compressed (`##DZ`) and list (`##DL`, `##HL`) data blocks as well as
+//! big-endian channel values are rejected.
+
+use crate::data::CANRead;
+use crate::frame::CanId;
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+/// Size of the header shared by every block: the block id, reserved bytes, the block length, and
+/// the number of links.
+const BLOCK_HEADER_LENGTH: usize = 24;
+
+/// Offset of the header block, directly following the identification block.
+const HEADER_BLOCK_OFFSET: u64 = 64;
+
+/// Type representing possible errors when reading an [Mdf4] file.
+#[derive(Debug, PartialEq)]
+pub enum Mdf4Error {
+    /// Reading the file failed.
+    Io(io::ErrorKind),
+    /// The file does not start with the MDF identification block.
+    InvalidIdentification,
+    /// The file is no MDF file of version 4.
+    UnsupportedVersion,
+    /// A block is truncated, lies outside of the file, has an unexpected block id or invalid
+    /// fields, or a linked list of blocks forms a cycle.
+    InvalidBlock,
+    /// The records are stored in a compressed or list data block.
+    UnsupportedDataBlock,
+    /// A channel uses a data type or conversion the reader does not support.
+    UnsupportedChannel,
+    /// A `CAN_DataFrame` group lacks the time master, ID, or DataBytes channel.
+    MissingChannel,
+}
+
+impl fmt::Display for Mdf4Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Mdf4Error::Io(kind) => write!(f, "cannot read file: {}", kind),
+            Mdf4Error::InvalidIdentification => write!(f, "no MDF identification block"),
+            Mdf4Error::UnsupportedVersion => write!(f, "unsupported MDF version"),
+            Mdf4Error::InvalidBlock => write!(f, "invalid block"),
+            Mdf4Error::UnsupportedDataBlock => write!(f, "unsupported data block"),
+            Mdf4Error::UnsupportedChannel => write!(f, "unsupported channel"),
+            Mdf4Error::MissingChannel => write!(f, "missing CAN_DataFrame channel"),
+        }
+    }
+}
+
+impl Error for Mdf4Error {}
+
+/// A CAN-bus frame read from an [Mdf4] file.
+#[derive(Debug, PartialEq)]
+pub struct Mdf4Entry {
+    timestamp: f64,
+    can_id: CanId,
+    data: Vec<u8>,
+}
+
+impl Mdf4Entry {
+    /// Returns the timestamp of the frame in seconds, relative to the start of the measurement.
+    pub fn timestamp(&self) -> f64 {
+        self.timestamp
+    }
+
+    /// Returns the CAN-ID of the frame.
+    pub fn can_id(&self) -> CanId {
+        self.can_id
+    }
+}
+
+impl CANRead for Mdf4Entry {
+    fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    fn dlc(&self) -> usize {
+        self.data.len()
+    }
+}
+
+/// CAN-bus frames read from an ASAM MDF 4.x file, ordered by timestamp.
+///
+/// # Example
+/// ```no_run
+/// use cantools::logging::Mdf4;
+/// let file = Mdf4::open("recording.mf4").unwrap();
+/// for entry in file {
+///     println!("{} {}", entry.timestamp(), entry.can_id());
+/// }
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct Mdf4 {
+    entries: Vec<Mdf4Entry>,
+}
+
+impl Mdf4 {
+    /// Reads the frames of the MDF file located at `path`.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Mdf4, Mdf4Error> {
+        let bytes = std::fs::read(path).map_err(|err| Mdf4Error::Io(err.kind()))?;
+        Mdf4::from_bytes(&bytes)
+    }
+
+    /// Reads the frames of an MDF file held in memory.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Mdf4, Mdf4Error> {
+        if bytes.get(0..8) != Some(b"MDF     ".as_slice()) {
+            return Err(Mdf4Error::InvalidIdentification);
+        }
+
+        let version = read_u16(bytes, 28)?;
+        if !(400..500).contains(&version) {
+            return Err(Mdf4Error::UnsupportedVersion);
+        }
+
+        let header = Block::read(bytes, HEADER_BLOCK_OFFSET, b"HD")?;
+        let mut entries = Vec::new();
+        for data_group in read_list(bytes, header.link(0), b"DG")? {
+            read_data_group(bytes, &data_group, &mut entries)?;
+        }
+
+        // groups are stored one after another, the timestamps of the frames interleave
+        entries.sort_by(|a, b| a.timestamp.total_cmp(&b.timestamp));
+        Ok(Mdf4 { entries })
+    }
+
+    /// Returns the number of frames.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the file contains no frames.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl IntoIterator for Mdf4 {
+    type Item = Mdf4Entry;
+    type IntoIter = std::vec::IntoIter<Mdf4Entry>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+fn read_bytes(bytes: &[u8], offset: usize, length: usize) -> Result<&[u8], Mdf4Error> {
+    offset
+        .checked_add(length)
+        .and_then(|end| bytes.get(offset..end))
+        .ok_or(Mdf4Error::InvalidBlock)
+}
+
+fn read_u16(bytes: &[u8], offset: usize) -> Result<u16, Mdf4Error> {
+    let mut buffer = [0u8; 2];
+    buffer.copy_from_slice(read_bytes(bytes, offset, 2)?);
+    Ok(u16::from_le_bytes(buffer))
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> Result<u32, Mdf4Error> {
+    let mut buffer = [0u8; 4];
+    buffer.copy_from_slice(read_bytes(bytes, offset, 4)?);
+    Ok(u32::from_le_bytes(buffer))
+}
+
+fn read_u64(bytes: &[u8], offset: usize) -> Result<u64, Mdf4Error> {
+    let mut buffer = [0u8; 8];
+    buffer.copy_from_slice(read_bytes(bytes, offset, 8)?);
+    Ok(u64::from_le_bytes(buffer))
+}
+
+fn read_f64(bytes: &[u8], offset: usize) -> Result<f64, Mdf4Error> {
+    Ok(f64::from_bits(read_u64(bytes, offset)?))
+}
+
+/// A block of the file split into its links and its data section.
+struct Block<'a> {
+    id: [u8; 2],
+    links: &'a [u8],
+    data: &'a [u8],
+}
+
+impl<'a> Block<'a> {
+    /// Reads the block at `offset`, which must have the block id `##<id>`.
+    fn read(bytes: &'a [u8], offset: u64, id: &[u8; 2]) -> Result<Self, Mdf4Error> {
+        let block = Block::read_any(bytes, offset)?;
+        if &block.id != id {
+            return Err(Mdf4Error::InvalidBlock);
+        }
+        Ok(block)
+    }
+
+    /// Reads the block at `offset` regardless of its block id.
+    fn read_any(bytes: &'a [u8], offset: u64) -> Result<Self, Mdf4Error> {
+        let offset = usize::try_from(offset).map_err(|_| Mdf4Error::InvalidBlock)?;
+        let header = read_bytes(bytes, offset, BLOCK_HEADER_LENGTH)?;
+        if &header[0..2] != b"##" {
+            return Err(Mdf4Error::InvalidBlock);
+        }
+
+        let length = usize::try_from(read_u64(header, 8)?).map_err(|_| Mdf4Error::InvalidBlock)?;
+        let link_count =
+            usize::try_from(read_u64(header, 16)?).map_err(|_| Mdf4Error::InvalidBlock)?;
+        let block = read_bytes(bytes, offset, length)?;
+        let links_end = link_count
+            .checked_mul(8)
+            .and_then(|links| links.checked_add(BLOCK_HEADER_LENGTH))
+            .filter(|end| *end <= length)
+            .ok_or(Mdf4Error::InvalidBlock)?;
+
+        Ok(Block {
+            id: [header[2], header[3]],
+            links: &block[BLOCK_HEADER_LENGTH..links_end],
+            data: &block[links_end..],
+        })
+    }
+
+    /// Returns the link at `index`, or `0`, i.e., no link, if the block has fewer links.
+    fn link(&self, index: usize) -> u64 {
+        read_u64(self.links, 8 * index).unwrap_or(0)
+    }
+}
+
+/// Reads the linked list of `##<id>` blocks starting at `offset`, each block linking the next one
+/// through its first link. A list linking back to one of its blocks is rejected, since following
+/// it would never end.
+fn read_list<'a>(
+    bytes: &'a [u8],
+    mut offset: u64,
+    id: &[u8; 2],
+) -> Result<Vec<Block<'a>>, Mdf4Error> {
+    let mut visited = HashSet::new();
+    let mut blocks = Vec::new();
+    while offset != 0 {
+        if !visited.insert(offset) {
+            return Err(Mdf4Error::InvalidBlock);
+        }
+        let block = Block::read(bytes, offset, id)?;
+        offset = block.link(0);
+        blocks.push(block);
+    }
+    Ok(blocks)
+}
+
+/// Reads the text of the `##TX` block at `offset`. An offset of `0` yields an empty text.
+fn read_text(bytes: &[u8], offset: u64) -> Result<String, Mdf4Error> {
+    if offset == 0 {
+        return Ok(String::new());
+    }
+
+    let block = Block::read(bytes, offset, b"TX")?;
+    let end = block
+        .data
+        .iter()
+        .position(|byte| *byte == 0)
+        .unwrap_or(block.data.len());
+    Ok(String::from_utf8_lossy(&block.data[..end]).into_owned())
+}
+
+/// The location, data type, and linear conversion of a channel value inside of a record.
+struct Channel {
+    name: String,
+    kind: u8,
+    sync: u8,
+    data_type: u8,
+    bit_offset: usize,
+    byte_offset: usize,
+    bit_count: usize,
+    composition: u64,
+    conversion: (f64, f64),
+}
+
+impl Channel {
+    fn read(bytes: &[u8], block: &Block) -> Result<Self, Mdf4Error> {
+        let data = block.data;
+        let conversion = match block.link(4) {
+            0 => (0.0, 1.0),
+            offset => read_conversion(bytes, offset)?,
+        };
+
+        // the bit offset addresses a bit of the first byte, larger values are malformed
+        let bit_offset = *data.get(3).ok_or(Mdf4Error::InvalidBlock)? as usize;
+        if bit_offset > 7 {
+            return Err(Mdf4Error::InvalidBlock);
+        }
+
+        Ok(Channel {
+            name: read_text(bytes, block.link(2))?,
+            kind: *data.first().ok_or(Mdf4Error::InvalidBlock)?,
+            sync: *data.get(1).ok_or(Mdf4Error::InvalidBlock)?,
+            data_type: *data.get(2).ok_or(Mdf4Error::InvalidBlock)?,
+            bit_offset,
+            byte_offset: read_u32(data, 4)? as usize,
+            bit_count: read_u32(data, 8)? as usize,
+            composition: block.link(1),
+            conversion,
+        })
+    }
+
+    /// Returns the bytes of a byte array channel.
+    fn bytes<'a>(&self, record: &'a [u8]) -> Result<&'a [u8], Mdf4Error> {
+        if self.data_type != 10 {
+            return Err(Mdf4Error::UnsupportedChannel);
+        }
+        read_bytes(record, self.byte_offset, self.bit_count / 8)
+    }
+
+    /// Returns the raw bits of an integer channel.
+    fn bits(&self, record: &[u8]) -> Result<u64, Mdf4Error> {
+        if self.bit_count == 0 || self.bit_count > 64 {
+            return Err(Mdf4Error::UnsupportedChannel);
+        }
+
+        // at most 7 + 64 bits, i.e., 9 bytes, as Channel::read rejects larger bit offsets
+        let length = (self.bit_offset + self.bit_count).div_ceil(8);
+        let mut buffer = [0u8; 16];
+        buffer[..length].copy_from_slice(read_bytes(record, self.byte_offset, length)?);
+        let word = u128::from_le_bytes(buffer) >> self.bit_offset;
+        Ok((word as u64) & (u64::MAX >> (64 - self.bit_count)))
+    }
+
+    /// Returns the physical value of a numeric channel.
+    fn value(&self, record: &[u8]) -> Result<f64, Mdf4Error> {
+        let bits = self.bits(record)?;
+        let raw = match (self.data_type, self.bit_count) {
+            (0, _) => bits as f64,
+            (2, count) => ((bits << (64 - count)) as i64 >> (64 - count)) as f64,
+            (4, 32) => f32::from_bits(bits as u32) as f64,
+            (4, 64) => f64::from_bits(bits),
+            _ => return Err(Mdf4Error::UnsupportedChannel),
+        };
+
+        let (offset, factor) = self.conversion;
+        Ok(offset + factor * raw)
+    }
+}
+
+/// Reads the `##CC` block at `offset` as `(offset, factor)` of a linear conversion.
+fn read_conversion(bytes: &[u8], offset: u64) -> Result<(f64, f64), Mdf4Error> {
+    let block = Block::read(bytes, offset, b"CC")?;
+    match block.data.first() {
+        // identity
+        Some(0) => Ok((0.0, 1.0)),
+        // linear: cc_val follows type, precision, flags, ref count, val count, and range
+        Some(1) => Ok((read_f64(block.data, 24)?, read_f64(block.data, 32)?)),
+        _ => Err(Mdf4Error::UnsupportedChannel),
+    }
+}
+
+/// Reads the linked list of `##CN` blocks starting at `offset`.
+fn read_channels(bytes: &[u8], offset: u64) -> Result<Vec<Channel>, Mdf4Error> {
+    read_list(bytes, offset, b"CN")?
+        .iter()
+        .map(|block| Channel::read(bytes, block))
+        .collect()
+}
+
+/// The channels of a channel group following the CAN bus logging convention.
+struct FrameChannels {
+    timestamp: Channel,
+    id: Channel,
+    ide: Option<Channel>,
+    data_length: Option<Channel>,
+    data_bytes: Channel,
+}
+
+impl FrameChannels {
+    /// Picks the frame channels from `channels`. Returns `None` if the group stores no frames.
+    fn find(bytes: &[u8], channels: Vec<Channel>) -> Result<Option<Self>, Mdf4Error> {
+        let mut timestamp = None;
+        let mut frame = None;
+        for channel in channels {
+            // master channel (2) synchronized by time (1)
+            if channel.kind == 2 && channel.sync == 1 {
+                timestamp = Some(channel);
+            } else if channel.name == "CAN_DataFrame" && channel.composition != 0 {
+                frame = Some(channel);
+            }
+        }
+
+        let frame = match frame {
+            Some(frame) => frame,
+            None => return Ok(None),
+        };
+
+        let (mut id, mut ide, mut data_length, mut data_bytes) = (None, None, None, None);
+        for member in read_channels(bytes, frame.composition)? {
+            match member.name.rsplit('.').next() {
+                Some("ID") => id = Some(member),
+                Some("IDE") => ide = Some(member),
+                Some("DataLength") => data_length = Some(member),
+                Some("DataBytes") => data_bytes = Some(member),
+                _ => {}
+            }
+        }
+
+        match (timestamp, id, data_bytes) {
+            (Some(timestamp), Some(id), Some(data_bytes)) => Ok(Some(FrameChannels {
+                timestamp,
+                id,
+                ide,
+                data_length,
+                data_bytes,
+            })),
+            _ => Err(Mdf4Error::MissingChannel),
+        }
+    }
+
+    fn entry(&self, record: &[u8]) -> Result<Mdf4Entry, Mdf4Error> {
+        let raw_id = self.id.bits(record)?;
+        let extended = match &self.ide {
+            Some(ide) => ide.bits(record)? != 0,
+            // without an IDE channel, bit 31 of the ID flags extended identifiers
+            None => raw_id & 0x8000_0000 != 0,
+        };
+
+        let id = (raw_id & 0x1FFF_FFFF) as u32;
+        let can_id = if extended || id > 0x7FF {
+            CanId::Extended(id)
+        } else {
+            CanId::Standard(id as u16)
+        };
+
+        let data = self.data_bytes.bytes(record)?;
+        let length = match &self.data_length {
+            Some(data_length) => (data_length.bits(record)? as usize).min(data.len()),
+            None => data.len(),
+        };
+
+        Ok(Mdf4Entry {
+            timestamp: self.timestamp.value(record)?,
+            can_id,
+            data: data[..length].to_vec(),
+        })
+    }
+}
+
+/// A channel group of a data group together with the layout of its records.
+struct ChannelGroup {
+    record_id: u64,
+    variable_length: bool,
+    record_length: usize,
+    frame: Option<FrameChannels>,
+}
+
+/// Reads the frames stored in `data_group` into `entries`.
+fn read_data_group(
+    bytes: &[u8],
+    data_group: &Block,
+    entries: &mut Vec<Mdf4Entry>,
+) -> Result<(), Mdf4Error> {
+    let record_id_size = *data_group.data.first().ok_or(Mdf4Error::InvalidBlock)? as usize;
+    if ![0, 1, 2, 4, 8].contains(&record_id_size) {
+        return Err(Mdf4Error::InvalidBlock);
+    }
+
+    let mut groups = Vec::new();
+    for block in read_list(bytes, data_group.link(1), b"CG")? {
+        let channels = read_channels(bytes, block.link(1))?;
+        let data_bytes = read_u32(block.data, 24)? as usize;
+        let invalidation_bytes = read_u32(block.data, 28)? as usize;
+        groups.push(ChannelGroup {
+            record_id: read_u64(block.data, 0)?,
+            variable_length: read_u16(block.data, 16)? & 0x01 != 0,
+            record_length: data_bytes + invalidation_bytes,
+            frame: FrameChannels::find(bytes, channels)?,
+        });
+    }
+
+    // without record ids, the records of a single channel group follow one another, and a fixed
+    // record length of 0 would never advance through them
+    if record_id_size == 0 {
+        if groups.len() > 1 {
+            return Err(Mdf4Error::InvalidBlock);
+        }
+        if groups
+            .first()
+            .is_some_and(|group| !group.variable_length && group.record_length == 0)
+        {
+            return Err(Mdf4Error::InvalidBlock);
+        }
+    }
+
+    if groups.iter().all(|group| group.frame.is_none()) {
+        return Ok(());
+    }
+
+    let records = match data_group.link(2) {
+        0 => return Ok(()),
+        offset => {
+            let block = Block::read_any(bytes, offset)?;
+            match &block.id {
+                b"DT" => block.data,
+                b"DZ" | b"DL" | b"HL" => return Err(Mdf4Error::UnsupportedDataBlock),
+                _ => return Err(Mdf4Error::InvalidBlock),
+            }
+        }
+    };
+
+    let mut position = 0;
+    while position < records.len() {
+        // without record ids, the data group contains exactly one channel group
+        let group = match record_id_size {
+            0 => &groups[0],
+            size => {
+                let mut buffer = [0u8; 8];
+                buffer[..size].copy_from_slice(read_bytes(records, position, size)?);
+                position += size;
+                let record_id = u64::from_le_bytes(buffer);
+                groups
+                    .iter()
+                    .find(|group| group.record_id == record_id)
+                    .ok_or(Mdf4Error::InvalidBlock)?
+            }
+        };
+
+        let length = if group.variable_length {
+            let length = read_u32(records, position)? as usize;
+            position += 4;
+            length
+        } else {
+            group.record_length
+        };
+
+        let record = read_bytes(records, position, length)?;
+        position += length;
+
+        if let Some(frame) = &group.frame {
+            entries.push(frame.entry(record)?);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Mdf4, Mdf4Error};
+
+    /// Appends a block with the block id `##<id>`, `links`, and `data_length` zero bytes of data.
+    fn push_block(bytes: &mut Vec<u8>, id: &[u8; 2], links: &[u64], data_length: usize) {
+        let length = 24 + 8 * links.len() + data_length;
+        bytes.extend_from_slice(b"##");
+        bytes.extend_from_slice(id);
+        bytes.extend_from_slice(&[0u8; 4]);
+        bytes.extend_from_slice(&(length as u64).to_le_bytes());
+        bytes.extend_from_slice(&(links.len() as u64).to_le_bytes());
+        for link in links {
+            bytes.extend_from_slice(&link.to_le_bytes());
+        }
+        bytes.resize(bytes.len() + data_length, 0);
+    }
+
+    /// Returns a file whose header block at offset 64 links the data group at offset 96.
+    fn file(dg_links: &[u64]) -> Vec<u8> {
+        let mut bytes = vec![0u8; 64];
+        bytes[0..8].copy_from_slice(b"MDF     ");
+        bytes[28..30].copy_from_slice(&410u16.to_le_bytes());
+        push_block(&mut bytes, b"HD", &[96], 0);
+        push_block(&mut bytes, b"DG", dg_links, 8);
+        bytes
+    }
+
+    #[test]
+    fn test_mdf4_001() {
+        assert_eq!(
+            Mdf4::from_bytes(b"MDF4"),
+            Err(Mdf4Error::InvalidIdentification)
+        );
+
+        let mut bytes = [0u8; 64];
+        bytes[0..8].copy_from_slice(b"MDF     ");
+        bytes[28..30].copy_from_slice(&330u16.to_le_bytes());
+        assert_eq!(Mdf4::from_bytes(&bytes), Err(Mdf4Error::UnsupportedVersion));
+
+        bytes[28..30].copy_from_slice(&410u16.to_le_bytes());
+        assert_eq!(Mdf4::from_bytes(&bytes), Err(Mdf4Error::InvalidBlock));
+    }
+
+    #[test]
+    fn test_mdf4_002() {
+        assert_eq!(
+            Mdf4::from_bytes(&file(&[0, 0, 0, 0])),
+            Ok(Mdf4 { entries: vec![] })
+        );
+
+        // a data group linking itself as the next one
+        assert_eq!(
+            Mdf4::from_bytes(&file(&[96, 0, 0, 0])),
+            Err(Mdf4Error::InvalidBlock)
+        );
+
+        // a channel group at offset 160 linking itself as the next one
+        let mut bytes = file(&[0, 160, 0, 0]);
+        push_block(&mut bytes, b"CG", &[160, 0], 32);
+        assert_eq!(Mdf4::from_bytes(&bytes), Err(Mdf4Error::InvalidBlock));
+
+        // a channel at offset 232 of the channel group linking itself as the next one
+        let mut bytes = file(&[0, 160, 0, 0]);
+        push_block(&mut bytes, b"CG", &[0, 232], 32);
+        push_block(&mut bytes, b"CN", &[232, 0, 0, 0, 0], 16);
+        assert_eq!(Mdf4::from_bytes(&bytes), Err(Mdf4Error::InvalidBlock));
+    }
+
+    #[test]
+    fn test_mdf4_003() {
+        // two channel groups at offsets 160 and 232 without record ids
+        let mut bytes = file(&[0, 160, 0, 0]);
+        push_block(&mut bytes, b"CG", &[232, 0], 32);
+        push_block(&mut bytes, b"CG", &[0, 0], 32);
+        assert_eq!(Mdf4::from_bytes(&bytes), Err(Mdf4Error::InvalidBlock));
+
+        // a single channel group without record ids and with a fixed record length of 0
+        let mut bytes = file(&[0, 160, 0, 0]);
+        push_block(&mut bytes, b"CG", &[0, 0], 32);
+        assert_eq!(Mdf4::from_bytes(&bytes), Err(Mdf4Error::InvalidBlock));
+
+        // records of variable length advance by their length field
+        bytes[160 + 40 + 16] = 0x01;
+        assert_eq!(Mdf4::from_bytes(&bytes), Ok(Mdf4 { entries: vec![] }));
+    }
+}
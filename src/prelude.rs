@@ -1,6 +1,20 @@
 //! Umbrella module containing useful traits and functionality.
+//!
+//! # Example
+//! ```
+//! use cantools::prelude::*;
+//!
+//! let sig = Unsigned::new(0, 8, 1.0, 0.0, Endian::Little).unwrap();
+//! let mut data = [0u8; 2];
+//!
+//! sig.encode(&mut data, 42.0);
+//! assert_eq!(data.dlc(), 2);
+//! assert_eq!(sig.decode(&data), 42.0);
+//! assert_eq!(u8::mask(4, 0), 0x0F);
+//! ```
 
-pub use crate::data::{CANRead};
-pub use crate::mask::{Mask};
-pub use crate::endian::{Endian};
-pub use crate::signals::{*};
\ No newline at end of file
+pub use crate::data::{CANRead, CANWrite};
+pub use crate::decode::{Decode, DefaultDecode, TryDecode};
+pub use crate::encode::{Encode, TryEncode};
+pub use crate::signals::*;
+pub use crate::utils::{Endian, Mask};
@@ -5,6 +5,28 @@
 //! Both [Unsigned] and [Signed] treat the sequence of bytes as integers, and as the names suggest:
 //! [Unsigned] as unsigned and [Signed] as signed integers.
 //!
+//! [Bytes] models a sequence of bits of arbitrary length that is not interpreted as a number but
+//! decodes into a vector of bytes, e.g., for VINs or other long identifiers. [Text] decodes a
+//! range of bytes into a string.
+//!
+//! Additionally, the module provides two helper types used to generate valid frames: [Counter]
+//! models a rolling counter that increments on every encode and [Checksum] models a checksum byte
+//! that is computed over the remaining bytes.
+//!
+//! [SignalKind] holds either an [Unsigned] or a [Signed] signal and can be parsed from a compact,
+//! DBC-like specification such as `"0|8@1+ (1,0)"`.
+//!
+//! For decoding large amounts of CAN-bus data, [Unsigned] and [Signed] signals can be compiled into a
+//! [CompiledSignal] that computes the byte range, shift, and mask once instead of on every decode.
+//!
+//! [Decoder] decodes many [Unsigned] signals reusing one scratch buffer.
+//!
+//! [self_check] verifies that a signal decodes every raw value it encodes, e.g., to validate
+//! signals of an imported database.
+//!
+//! Finally, [Multiplexed] wraps a signal that is only present if a multiplexor signal equals a
+//! given multiplex value.
+//!
 //! More signals are planed for upcoming releases of this crate including signals for bit sequences
 //! representing integers, floats, and doubles.
 //!
@@ -21,21 +43,55 @@
 //! ```
 
 use crate::data::{CANRead, CANWrite};
-use crate::decode::{Decode, DecodeError, DefaultDecode, TryDecode};
+use crate::decode::{
+    Decode, DecodeError, DecodedValue, DefaultDecode, EnumDecodeError, Physical, Raw, TryDecode,
+};
 use crate::encode::{Encode, EncodeError, TryEncode};
-use crate::utils::{Endian, Mask};
+use crate::utils::{
+    big_endian_bits, bit_positions, insert_bits, little_endian_bits, little_endian_bits_in, Endian,
+    Mask,
+};
+use crate::values::ValueTable;
 use std::cmp::min;
+use std::error::Error;
+use std::fmt;
 use std::ops::Div;
+use std::str::FromStr;
+
+/// The maximal number of bits of signals interpreted as a number, i.e., [Unsigned] and [Signed],
+/// limited by the 64-bit integers they decode into. [Bytes] and [Text] are not limited.
+pub const MAX_SCALAR_BITS: u16 = 64;
 
 /// A type modeling possible construction errors.
 #[derive(Debug, PartialEq)]
 pub enum LengthError {
     /// The length, i.e., the number of bits is set to zero.
     LengthZero,
-    /// The length, i.e., the number of bits is set to a value greater than 64.
+    /// The length, i.e., the number of bits is set to a value greater than [MAX_SCALAR_BITS].
     LengthGreater64,
+    /// The `factor` or `offset` yield a minimal or maximal value that is infinite or NaN.
+    NonFiniteRange,
+    /// The sign bit of a [Signed] signal lies outside of its `length` bits.
+    SignBitOutOfRange,
 }
 
+impl fmt::Display for LengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LengthError::LengthZero => write!(f, "signal length is zero"),
+            LengthError::LengthGreater64 => {
+                write!(f, "signal length is greater than {}", MAX_SCALAR_BITS)
+            }
+            LengthError::NonFiniteRange => {
+                write!(f, "factor and offset yield a non-finite value range")
+            }
+            LengthError::SignBitOutOfRange => write!(f, "sign bit lies outside of the signal"),
+        }
+    }
+}
+
+impl Error for LengthError {}
+
 /// A trait providing a convenient way to calculate the minimal producible value.
 pub trait Min {
     /// The type of return value of a call to [min](Min::min).
@@ -54,6 +110,72 @@ pub trait Max {
     fn max(&self) -> Self::Item;
 }
 
+/// Checks that scaling the raw values `raw_min` and `raw_max` by `factor` and `offset` yields a
+/// finite range. Encoding compares values against the range, which never fails for NaN bounds.
+fn check_range(raw_min: f64, raw_max: f64, factor: f64, offset: f64) -> Result<(), LengthError> {
+    let min = raw_min * factor + offset;
+    let max = raw_max * factor + offset;
+    if min.is_finite() && max.is_finite() {
+        Ok(())
+    } else {
+        Err(LengthError::NonFiniteRange)
+    }
+}
+
+/// Multiplies `value` with `out_scale` and returns the product as an integer. Returns
+/// [DecodeError::InexactScale] if the product is not an integer up to floating-point noise, since
+/// rounding it would scale every raw value by a slightly wrong factor.
+fn scale_exact(value: f64, out_scale: u32) -> Result<i128, DecodeError> {
+    let scaled = value * out_scale as f64;
+    let rounded = scaled.round();
+    if (scaled - rounded).abs() <= 1e-9 * rounded.abs().max(1.0) {
+        Ok(rounded as i128)
+    } else {
+        Err(DecodeError::InexactScale)
+    }
+}
+
+/// Returns the number of bits CAN-bus data must contain to hold `length` bits starting at `start`
+/// given the bit numbering imposed by `endian`.
+fn required_bits(start: u16, length: u16, endian: &Endian) -> usize {
+    let start = start as usize;
+    match endian {
+        Endian::Little => start + length as usize,
+        Endian::Big => (7 - start % 8) + 8 * start.div(8) + length as usize,
+    }
+}
+
+/// Checks whether `length` bits starting at `start` fit into `dlc` bytes of data given the bit
+/// numbering imposed by `endian`.
+fn check_bounds(start: u16, length: u16, endian: &Endian, dlc: usize) -> Result<(), DecodeError> {
+    let available = 8 * dlc;
+    if start as usize >= available {
+        return Err(DecodeError::StartOutOfRange { start, dlc });
+    }
+
+    let needed = required_bits(start, length, endian);
+    if needed > available {
+        return Err(DecodeError::LengthExceedsBuffer {
+            needed: u16::try_from(needed).unwrap_or(u16::MAX),
+            available: u16::try_from(available).unwrap_or(u16::MAX),
+        });
+    }
+
+    Ok(())
+}
+
+/// Returns the number of decimal places needed to print `value` exactly, e.g., `2` for `0.01`.
+/// Values needing more than 9 decimal places are limited to 9.
+fn decimal_places(value: f64) -> usize {
+    let value = value.abs();
+    (0..9)
+        .find(|&places| {
+            let scaled = value * 10f64.powi(places as i32);
+            (scaled - scaled.round()).abs() <= 1e-9 * scaled.max(1.0)
+        })
+        .unwrap_or(9)
+}
+
 /// A type modeling one bit.
 #[derive(Debug, Default, PartialEq)]
 pub struct Bit {
@@ -71,26 +193,31 @@ impl Bit {
     pub fn new(start: u16) -> Bit {
         Bit { start }
     }
+
+    /// Returns the absolute position of the bit, i.e., `vec![start]`. See
+    /// [Unsigned::bit_positions].
+    pub fn bit_positions(&self) -> Vec<u16> {
+        vec![self.start]
+    }
 }
 
 impl TryDecode<bool> for Bit {
-    type Error = ();
+    type Error = DecodeError;
 
     fn try_decode<D: CANRead>(&self, data: &D) -> Result<bool, Self::Error> {
-        if self.start >= (8 * data.dlc()) as u16 {
-            Err(())
-        } else {
-            let start_byte = self.start.div(8);
-            let bit_in_start_byte = self.start % 8;
-
-            let mut byte = data.data()[start_byte as usize];
-            byte >>= bit_in_start_byte;
-            byte &= 0x01;
-            if byte != 0 {
-                Ok(true)
-            } else {
-                Ok(false)
-            }
+        if self.start as usize >= 8 * data.dlc() {
+            return Err(DecodeError::StartOutOfRange {
+                start: self.start,
+                dlc: data.dlc(),
+            });
+        }
+
+        let start_byte = self.start.div(8);
+        let bit_in_start_byte = self.start % 8;
+
+        match data.data().get(start_byte as usize) {
+            Some(byte) => Ok((byte >> bit_in_start_byte) & 0x01 != 0),
+            None => Err(DecodeError::NotEnoughData),
         }
     }
 }
@@ -98,33 +225,52 @@ impl TryDecode<bool> for Bit {
 impl DefaultDecode<bool> for Bit {}
 impl Decode<bool> for Bit {}
 
+/// Decodes the bit as `1.0` if set and `0.0` otherwise such that bits can be processed together
+/// with [Unsigned] and [Signed] signals.
+impl TryDecode<f64> for Bit {
+    type Error = DecodeError;
+
+    fn try_decode<D: CANRead>(&self, data: &D) -> Result<f64, Self::Error> {
+        let value: bool = self.try_decode(data)?;
+        Ok(if value { 1.0 } else { 0.0 })
+    }
+}
+
+impl DefaultDecode<f64> for Bit {}
+impl Decode<f64> for Bit {}
+
 impl TryEncode<bool> for Bit {
     type Error = EncodeError;
 
     fn try_encode<D: CANWrite>(&self, data: &mut D, value: bool) -> Result<(), Self::Error> {
-        let start_bit_in_byte = self.start % 8;
         let start_byte = self.start.div(8);
 
         if start_byte as usize >= data.dlc() {
             return Err(EncodeError::NotEnoughData);
         }
 
-        match value {
-            true => {
-                let mask_byte = u8::mask(1, start_bit_in_byte);
-                data.mut_data()[start_byte as usize] |= mask_byte;
-            }
-            false => {
-                let mask_byte = !u8::mask(1, start_bit_in_byte);
-                data.mut_data()[start_byte as usize] &= mask_byte;
-            }
-        }
-        Ok(())
+        data.set_bit(self.start, value)
     }
 }
 
 impl Encode<bool> for Bit {}
 
+impl Min for Bit {
+    type Item = f64;
+
+    fn min(&self) -> Self::Item {
+        0.0
+    }
+}
+
+impl Max for Bit {
+    type Item = f64;
+
+    fn max(&self) -> Self::Item {
+        1.0
+    }
+}
+
 /// A type modeling multiple bits.
 ///
 /// The `length` number of bits represent an unsigned integer which is multiplied with the `factor`,
@@ -141,6 +287,10 @@ pub struct Unsigned {
     factor: f64,
     offset: f64,
     endian: Endian,
+    name: Option<String>,
+    unit: Option<String>,
+    value_table: Option<ValueTable>,
+    range: Option<(f64, f64)>,
 }
 
 impl Unsigned {
@@ -160,381 +310,664 @@ impl Unsigned {
         endian: Endian,
     ) -> Result<Unsigned, LengthError> {
         if length == 0 {
-            Err(LengthError::LengthZero)
-        } else if length > 64 {
-            Err(LengthError::LengthGreater64)
-        } else {
-            let var = Unsigned {
-                start,
-                length,
-                factor,
-                offset,
-                endian,
-            };
-            Ok(var)
+            return Err(LengthError::LengthZero);
+        } else if length > MAX_SCALAR_BITS {
+            return Err(LengthError::LengthGreater64);
         }
-    }
-}
 
-impl Default for Unsigned {
-    fn default() -> Self {
-        Unsigned {
-            start: 0,
-            length: 1,
-            factor: 1.0,
-            offset: 0.0,
-            endian: Endian::Little,
-        }
+        check_range(0.0, u64::mask(length, 0) as f64, factor, offset)?;
+
+        Ok(Unsigned {
+            start,
+            length,
+            factor,
+            offset,
+            endian,
+            name: None,
+            unit: None,
+            value_table: None,
+            range: None,
+        })
     }
-}
-
-impl Min for Unsigned {
-    type Item = f64;
 
-    fn min(&self) -> Self::Item {
-        self.offset
+    /// Returns the smallest raw value of the signal, i.e., the value before applying `factor`
+    /// and `offset`, which is always `0`.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::signals::Unsigned;
+    /// use cantools::utils::Endian;
+    /// let sig = Unsigned::new(0, 8, 0.5, 10.0, Endian::Little).unwrap();
+    /// assert_eq!(sig.raw_min(), 0);
+    /// ```
+    pub fn raw_min(&self) -> i64 {
+        0
     }
-}
 
-impl Max for Unsigned {
-    type Item = f64;
+    /// Returns the largest raw value of the signal, i.e., `2^length - 1`. For 64-bit signals, the
+    /// value saturates at [i64::MAX].
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::signals::Unsigned;
+    /// use cantools::utils::Endian;
+    /// let sig = Unsigned::new(0, 8, 0.5, 10.0, Endian::Little).unwrap();
+    /// assert_eq!(sig.raw_max(), 255);
+    /// ```
+    pub fn raw_max(&self) -> i64 {
+        let max = u64::MAX >> (64 - self.length);
+        i64::try_from(max).unwrap_or(i64::MAX)
+    }
 
-    fn max(&self) -> Self::Item {
-        let mut base = u64::mask(self.length, 0) as f64;
-        base *= self.factor;
-        base += self.offset;
-        base
+    /// Checks whether the signal can be decoded from, or encoded into, CAN-bus data consisting of
+    /// `dlc` bytes. For big-endian signals, this catches start bits from which the remaining
+    /// `length` bits run past the end of the data.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::signals::Unsigned;
+    /// use cantools::utils::Endian;
+    /// let sig = Unsigned::new(7, 16, 1.0, 0.0, Endian::Big).unwrap();
+    /// assert!(sig.validate(2).is_ok());
+    /// assert!(sig.validate(1).is_err());
+    /// ```
+    pub fn validate(&self, dlc: usize) -> Result<(), DecodeError> {
+        check_bounds(self.start, self.length, &self.endian, dlc)
     }
-}
 
-impl TryDecode<f64> for Unsigned {
-    type Error = DecodeError;
+    /// Extracts the bit sequence of the signal from `data` as an integer, i.e., without applying
+    /// `factor` and `offset`.
+    fn decode_bits<D: CANRead>(&self, data: &D) -> Result<u64, DecodeError> {
+        self.decode_bits_from(self.start, data)
+    }
 
-    fn try_decode<D: CANRead>(&self, data: &D) -> Result<f64, Self::Error> {
+    /// Extracts the bit sequence of the signal as if it started at bit `start`.
+    fn decode_bits_from<D: CANRead>(&self, start: u16, data: &D) -> Result<u64, DecodeError> {
         match &self.endian {
             Endian::Little => {
-                if self.start + self.length > (8 * data.dlc() as u16) {
-                    return Err(DecodeError::NotEnoughData);
-                }
-
-                let start_byte = self.start.div(8);
-                let bit_in_start_byte = self.start % 8;
-                let end_byte = (self.start + self.length - 1).div(8);
-
-                let mut slice = [0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8];
-                let s = start_byte..=end_byte;
-
-                for (i, byte_index) in s.into_iter().enumerate().filter(|(i, _)| *i < 8) {
-                    match data.data().get(byte_index as usize) {
-                        None => {
-                            slice[i] = 0;
-                        }
-                        Some(value) => {
-                            slice[i] = *value;
-                        }
-                    }
-                }
-
-                let mut converted = u64::from_le_bytes(slice);
-                converted >>= bit_in_start_byte;
-                converted &= u64::mask(self.length, 0);
-
-                let mut result = converted as f64;
-                result *= &self.factor;
-                result += &self.offset;
-                Ok(result)
+                check_bounds(start, self.length, &self.endian, data.dlc())?;
+                Ok(little_endian_bits(start, self.length, data.data()))
             }
             Endian::Big => {
-                let shift = (7 - self.start % 8) + 8 * self.start.div(8);
-                let shift = (8 * data.dlc()) as isize - (shift as isize) - (self.length as isize);
-                if shift < 0 {
-                    return Err(DecodeError::NotEnoughData);
-                }
-
-                let start_byte = self.start.div(8);
-                let end_byte = (7 - self.start % 8) + 8 * self.start.div(8);
-                let end_byte = (end_byte + self.length - 1).div(8);
-
-                let mut slice = [0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8];
-                let s = start_byte..=end_byte;
-
-                let min_data = min(8, s.len());
-                for (i, byte_index) in s.into_iter().enumerate().filter(|(i, _)| *i < min_data) {
-                    match data.data().get(byte_index as usize) {
-                        None => {
-                            slice[min_data - i - 1] = 0;
-                        }
-                        Some(value) => {
-                            slice[min_data - i - 1] = *value;
-                        }
-                    }
-                }
-
-                let mut converted = u64::from_le_bytes(slice);
-                converted >>= 7 - (self.start % 8);
-                converted &= u64::mask(self.length, 0);
-                let mut result = converted as f64;
-                result *= &self.factor;
-                result += &self.offset;
-                Ok(result)
+                check_bounds(start, self.length, &self.endian, data.dlc())?;
+                Ok(big_endian_bits(start, self.length, data.data()))
             }
         }
     }
-}
-
-impl DefaultDecode<f64> for Unsigned {}
-impl Decode<f64> for Unsigned {}
-
-impl TryEncode<f64> for Unsigned {
-    type Error = EncodeError;
 
-    fn try_encode<D: CANWrite>(&self, data: &mut D, value: f64) -> Result<(), Self::Error> {
-        if value < self.min() {
-            return Err(EncodeError::MinError);
-        }
+    /// Compiles the signal into a [CompiledSignal] decoding the same values.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::signals::Unsigned;
+    /// use cantools::decode::TryDecode;
+    /// use cantools::utils::Endian;
+    ///
+    /// let sig = Unsigned::new(4, 8, 2.0, 1337.0, Endian::Little).unwrap();
+    /// let compiled = sig.compile();
+    ///
+    /// let data = [0b0101_0000u8, 0b0000_0011];
+    /// let mut value = 0.0;
+    /// compiled.decode_into(&data, &mut value).unwrap();
+    /// assert_eq!(Ok(value), sig.try_decode(&data));
+    /// ```
+    pub fn compile(&self) -> CompiledSignal {
+        CompiledSignal::new(
+            self.start,
+            self.length,
+            &self.endian,
+            self.factor,
+            self.offset,
+            false,
+        )
+    }
 
-        if value > self.max() {
-            return Err(EncodeError::MaxError);
-        }
+    /// Returns a [UnsignedBuilder] initialized with the values of [Unsigned::default].
+    pub fn builder() -> UnsignedBuilder {
+        UnsignedBuilder::default()
+    }
 
-        match self.endian {
-            Endian::Little => {
-                if self.start + self.length > (8 * data.dlc() as u16) {
-                    return Err(EncodeError::NotEnoughData);
-                }
+    /// Returns the start bit.
+    pub fn start(&self) -> u16 {
+        self.start
+    }
 
-                // compute integer value to be set
-                let value = value - self.offset;
-                let value = value / self.factor;
-                let value = value.trunc() as u64;
-                let mut value = value & u64::mask(self.length, 0);
+    /// Returns the absolute positions of the bits of the signal ordered from the least to the most
+    /// significant bit, which is the order the encoder writes them in. Position `8 * i + j` is bit
+    /// `j` of byte `i`. Other than for little-endian signals, the positions of big-endian signals
+    /// jump between bytes following the Motorola bit numbering.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::signals::Unsigned;
+    /// use cantools::utils::Endian;
+    ///
+    /// let sig = Unsigned::new(6, 4, 1.0, 0.0, Endian::Little).unwrap();
+    /// assert_eq!(sig.bit_positions(), vec![6, 7, 8, 9]);
+    ///
+    /// let sig = Unsigned::new(1, 4, 1.0, 0.0, Endian::Big).unwrap();
+    /// assert_eq!(sig.bit_positions(), vec![14, 15, 0, 1]);
+    /// ```
+    pub fn bit_positions(&self) -> Vec<u16> {
+        bit_positions(self.start, self.length, &self.endian).collect()
+    }
 
-                // set data by setting the corresponding data bits
-                for i in 0..self.length {
-                    let bit = Bit::new(self.start + i);
+    /// Returns the number of bits.
+    pub fn length(&self) -> u16 {
+        self.length
+    }
 
-                    if value & 1 == 0 {
-                        bit.try_encode(data, false).unwrap();
-                    } else {
-                        bit.try_encode(data, true).unwrap();
-                    }
+    /// Returns the factor the raw value is multiplied with.
+    pub fn factor(&self) -> f64 {
+        self.factor
+    }
 
-                    value >>= 1;
-                }
-            }
-            Endian::Big => {
-                let shift = (7 - self.start % 8) + 8 * self.start.div(8);
-                let shift = (8 * data.dlc()) as isize - (shift as isize) - (self.length as isize);
-                if shift < 0 {
-                    return Err(EncodeError::NotEnoughData);
-                }
+    /// Returns the offset added to the scaled raw value.
+    pub fn offset(&self) -> f64 {
+        self.offset
+    }
 
-                // compute integer value to be set
-                let value = value - self.offset;
-                let value = value / self.factor;
-                let mut value = value.trunc() as u64;
+    /// Replaces the factor the raw value is multiplied with, e.g., when calibrating a signal at
+    /// runtime. [Min] and [Max] follow the new factor. The signal is left unchanged if the new
+    /// factor yields a non-finite range, see [new](Self::new).
+    pub fn set_factor(&mut self, factor: f64) -> Result<(), LengthError> {
+        check_range(0.0, u64::mask(self.length, 0) as f64, factor, self.offset)?;
+        self.factor = factor;
+        Ok(())
+    }
 
-                value &= u64::mask(self.length, 0);
+    /// Replaces the offset added to the scaled raw value. [Min] and [Max] follow the new offset.
+    /// The signal is left unchanged if the new offset yields a non-finite range.
+    pub fn set_offset(&mut self, offset: f64) -> Result<(), LengthError> {
+        check_range(0.0, u64::mask(self.length, 0) as f64, self.factor, offset)?;
+        self.offset = offset;
+        Ok(())
+    }
 
-                // set data by setting the corresponding data bits
-                let mut start = self.start;
-                for i in 0..self.length {
-                    let bit = Bit::new(start);
+    /// Returns the byte-order.
+    pub fn endian(&self) -> &Endian {
+        &self.endian
+    }
 
-                    if (value >> (self.length - 1 - i)) & 1 == 0 {
-                        bit.try_encode(data, false).unwrap();
-                    } else {
-                        bit.try_encode(data, true).unwrap();
-                    }
+    /// Returns the name of the signal, if any.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
 
-                    // update start to be the next bit to set
-                    start = if start % 8 == 0 {
-                        (start.div(8) + 1) * 8 + 7
-                    } else {
-                        start - 1
-                    };
-                }
-            }
-        }
+    /// Returns the unit of the physical value, if any.
+    pub fn unit(&self) -> Option<&str> {
+        self.unit.as_deref()
+    }
 
-        Ok(())
+    /// Returns the value table assigning labels to raw values, if any.
+    pub fn value_table(&self) -> Option<&ValueTable> {
+        self.value_table.as_ref()
     }
-}
 
-impl Encode<f64> for Unsigned {}
+    /// Returns the documented physical range `(min, max)`, if any.
+    pub fn range(&self) -> Option<(f64, f64)> {
+        self.range
+    }
 
-/// A type modeling multiple bits.
-///
-/// The `length` number of bits represent a signed integer which is multiplied with the `factor`,
-/// and summed up with the `offset`. The `endian` controls the exact sequence of selected bits.
-///
-/// The following formula summarizes the relation between the bit sequence, `factor`, and `offset`:
-/// ```latex
-/// result = bits_signed * factor + offset
-/// ```
-#[derive(Debug, PartialEq)]
-pub struct Signed {
-    start: u16,
-    length: u16,
-    factor: f64,
-    offset: f64,
-    endian: Endian,
-}
+    /// Decodes the label assigned to the raw value by the value table. `None` is returned if the
+    /// signal has no value table or the raw value has no label. [DecodeError::Overflow] is returned
+    /// if the raw value exceeds `i64::MAX`, which only 64-bit signals can produce.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::signals::Unsigned;
+    /// use cantools::values::ValueTable;
+    ///
+    /// let mut table = ValueTable::new();
+    /// table.insert_range(0..=9, "Low");
+    /// table.insert_range(10..=19, "Mid");
+    /// let sig = Unsigned::builder().length(6).value_table(table).build().unwrap();
+    ///
+    /// assert_eq!(sig.decode_named(&[12u8]), Ok(Some("Mid")));
+    /// assert_eq!(sig.decode_named(&[20u8]), Ok(None));
+    /// ```
+    pub fn decode_named<D: CANRead>(&self, data: &D) -> Result<Option<&str>, DecodeError> {
+        let raw = i64::try_from(self.decode_bits(data)?).map_err(|_| DecodeError::Overflow)?;
+        Ok(self.value_table.as_ref().and_then(|table| table.label(raw)))
+    }
 
-impl Signed {
-    /// Constructs a new Signed signal.
+    /// Encodes the raw value the value table assigns `label` to, e.g., `"On"` instead of `1`.
+    /// Returns [EncodeError::UnknownLabel] if the signal has no value table or `label` is not part
+    /// of it.
     ///
     /// # Example
     /// ```
-    /// use cantools::signals::Signed;
-    /// use cantools::utils::Endian;
-    /// let sig = Signed::new(0, 8, 42.0, 1337.0, Endian::Little).unwrap();
+    /// use cantools::signals::Unsigned;
+    /// use cantools::values::ValueTable;
+    ///
+    /// let mut table = ValueTable::new();
+    /// table.insert(0, "Off");
+    /// table.insert(1, "On");
+    /// let sig = Unsigned::builder().start(2).length(2).value_table(table).build().unwrap();
+    ///
+    /// let mut data = [0u8];
+    /// sig.try_encode_named(&mut data, "On").unwrap();
+    /// assert_eq!(data, [0b0000_0100]);
     /// ```
-    pub fn new(
-        start: u16,
-        length: u16,
-        factor: f64,
-        offset: f64,
-        endian: Endian,
-    ) -> Result<Signed, LengthError> {
-        if length == 0 {
-            Err(LengthError::LengthZero)
-        } else if length > 64 {
-            Err(LengthError::LengthGreater64)
-        } else {
-            let var = Signed {
-                start,
-                length,
-                factor,
-                offset,
-                endian,
-            };
-            Ok(var)
+    pub fn try_encode_named<D: CANWrite>(
+        &self,
+        data: &mut D,
+        label: &str,
+    ) -> Result<(), EncodeError> {
+        let raw = self
+            .value_table
+            .as_ref()
+            .and_then(|table| table.value(label))
+            .ok_or(EncodeError::UnknownLabel)?;
+
+        if raw < self.raw_min() {
+            return Err(EncodeError::MinError);
         }
-    }
-}
 
-impl Default for Signed {
-    fn default() -> Self {
-        Signed {
-            start: 0,
-            length: 1,
-            factor: 1.0,
-            offset: 0.0,
-            endian: Endian::Little,
+        if raw > self.raw_max() {
+            return Err(EncodeError::MaxError);
         }
-    }
-}
 
-impl Min for Signed {
-    type Item = f64;
+        insert_bits(data, self.start, self.length, &self.endian, raw as u64)
+    }
 
-    fn min(&self) -> Self::Item {
-        let mut base = i64::mask(64 - self.length + 1, self.length - 1) as f64;
-        base *= self.factor;
-        base += self.offset;
-        base
+    /// Decodes the raw value, the physical value, and the label assigned to the raw value by the
+    /// value table at once. Returns [DecodeError::Overflow] if the raw value exceeds `i64::MAX`.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::signals::Unsigned;
+    /// use cantools::values::ValueTable;
+    ///
+    /// let mut table = ValueTable::new();
+    /// table.insert(1, "On");
+    /// let sig = Unsigned::builder().length(2).offset(10.0).value_table(table).build().unwrap();
+    ///
+    /// let value = sig.decode_full(&[0x01u8]).unwrap();
+    /// assert_eq!(value.raw, 1);
+    /// assert_eq!(value.physical, 11.0);
+    /// assert_eq!(value.label.as_deref(), Some("On"));
+    /// ```
+    pub fn decode_full<D: CANRead>(&self, data: &D) -> Result<DecodedValue, DecodeError> {
+        let raw = i64::try_from(self.decode_bits(data)?).map_err(|_| DecodeError::Overflow)?;
+        let physical = raw as f64 * self.factor + self.offset;
+        let label = self
+            .value_table
+            .as_ref()
+            .and_then(|table| table.label(raw))
+            .map(String::from);
+        Ok(DecodedValue {
+            raw,
+            physical,
+            label,
+        })
     }
-}
 
-impl Max for Signed {
-    type Item = f64;
+    /// Decodes the raw value, i.e., without applying `factor` and `offset`, and converts it into
+    /// the user-provided type `E`, e.g., an enum modeling the states of the signal. Returns
+    /// [EnumDecodeError::UnknownValue] if the conversion fails and [DecodeError::Overflow] if the raw
+    /// value exceeds `i64::MAX`.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::decode::EnumDecodeError;
+    /// use cantools::signals::Unsigned;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Gear {
+    ///     Park,
+    ///     Drive,
+    /// }
+    ///
+    /// impl TryFrom<i64> for Gear {
+    ///     type Error = ();
+    ///
+    ///     fn try_from(value: i64) -> Result<Self, Self::Error> {
+    ///         match value {
+    ///             0 => Ok(Gear::Park),
+    ///             1 => Ok(Gear::Drive),
+    ///             _ => Err(()),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let sig = Unsigned::builder().length(4).build().unwrap();
+    /// assert_eq!(sig.try_decode_enum(&[0x01u8]), Ok(Gear::Drive));
+    /// assert_eq!(
+    ///     sig.try_decode_enum::<Gear, _>(&[0x02u8]),
+    ///     Err(EnumDecodeError::UnknownValue(2))
+    /// );
+    /// ```
+    pub fn try_decode_enum<E, D>(&self, data: &D) -> Result<E, EnumDecodeError>
+    where
+        E: TryFrom<i64>,
+        D: CANRead,
+    {
+        let raw = i64::try_from(self.decode_bits(data)?).map_err(|_| DecodeError::Overflow)?;
+        E::try_from(raw).map_err(|_| EnumDecodeError::UnknownValue(raw))
+    }
 
-    fn max(&self) -> Self::Item {
-        let mut base = u64::mask(self.length - 1, 0) as f64;
-        base *= self.factor;
-        base += self.offset;
-        base
+    /// Decodes the physical value and formats it with the precision of the signal followed by the
+    /// unit, if any, e.g., for reports. The number of decimal places is the one needed to print
+    /// `factor` and `offset` exactly, e.g., `2` for a factor of `0.01`.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::signals::Unsigned;
+    /// let sig = Unsigned::builder().length(8).factor(0.1).unit("km/h").build().unwrap();
+    /// assert_eq!(sig.decode_display(&[123u8]), Ok(String::from("12.3 km/h")));
+    /// ```
+    pub fn decode_display<D: CANRead>(&self, data: &D) -> Result<String, DecodeError> {
+        let value: f64 = self.try_decode(data)?;
+        let places = decimal_places(self.factor).max(decimal_places(self.offset));
+        Ok(match &self.unit {
+            Some(unit) => format!("{:.*} {}", places, value, unit),
+            None => format!("{:.*}", places, value),
+        })
+    }
+
+    /// Decodes the physical value as an integer in units of `1 / out_scale` without using
+    /// floating-point arithmetic on the raw value. For example, a value of `12.3` decoded with
+    /// `out_scale = 1000` returns `12300`.
+    ///
+    /// The `factor` and `offset` are multiplied with `out_scale`, which must yield integers such
+    /// that the result is exact. Returns [DecodeError::InexactScale] otherwise, e.g., for a factor
+    /// of `0.25` and an `out_scale` of `10`, and [DecodeError::Overflow] if the scaled value does
+    /// not fit into an `i64`.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::signals::Unsigned;
+    /// use cantools::utils::Endian;
+    /// let sig = Unsigned::new(0, 8, 0.1, 0.0, Endian::Little).unwrap();
+    /// assert_eq!(sig.try_decode_scaled(&[123u8], 1000), Ok(12300));
+    /// ```
+    pub fn try_decode_scaled<D: CANRead>(
+        &self,
+        data: &D,
+        out_scale: u32,
+    ) -> Result<i64, DecodeError> {
+        let raw = self.decode_bits(data)? as i128;
+        let factor = scale_exact(self.factor, out_scale)?;
+        let offset = scale_exact(self.offset, out_scale)?;
+        raw.checked_mul(factor)
+            .and_then(|value| value.checked_add(offset))
+            .and_then(|value| i64::try_from(value).ok())
+            .ok_or(DecodeError::Overflow)
+    }
+
+    /// Decodes the signal like [try_decode](TryDecode::try_decode) but returns
+    /// [DecodeError::Overflow] instead of an infinite or NaN value. [Unsigned::new] already rejects
+    /// signals whose range is not finite, so this guards pipelines against non-finite values
+    /// regardless of how the signal was defined.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::signals::Unsigned;
+    /// use cantools::utils::Endian;
+    /// let sig = Unsigned::new(0, 8, 0.5, 10.0, Endian::Little).unwrap();
+    /// assert_eq!(sig.try_decode_checked(&[4u8]), Ok(12.0));
+    /// ```
+    pub fn try_decode_checked<D: CANRead>(&self, data: &D) -> Result<f64, DecodeError> {
+        let value: f64 = self.try_decode(data)?;
+        if value.is_finite() {
+            Ok(value)
+        } else {
+            Err(DecodeError::Overflow)
+        }
+    }
+
+    /// Decodes the signal like [try_decode](TryDecode::try_decode) but clamps the result to
+    /// [min](Min::min) and [max](Max::max). Raw values outside of the documented
+    /// [range](UnsignedBuilder::range), e.g., of a misbehaving sensor, decode to the nearest bound
+    /// instead of an out-of-range value.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::signals::Unsigned;
+    /// let sig = Unsigned::builder().length(8).range(0.0, 100.0).build().unwrap();
+    /// assert_eq!(sig.try_decode_clamped(&[42u8]), Ok(42.0));
+    /// assert_eq!(sig.try_decode_clamped(&[120u8]), Ok(100.0));
+    /// ```
+    pub fn try_decode_clamped<D: CANRead>(&self, data: &D) -> Result<f64, DecodeError> {
+        let value: f64 = self.try_decode(data)?;
+        Ok(value.max(self.min()).min(self.max()))
+    }
+
+    /// Decodes the signal relative to a window of `data` starting at byte `byte_offset`, i.e.,
+    /// as if the start bit of the signal were shifted by `8 * byte_offset`. This avoids
+    /// constructing sub-slices, e.g., when decoding the payload of a container frame.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::signals::Unsigned;
+    /// use cantools::utils::Endian;
+    /// let sig = Unsigned::new(0, 8, 1.0, 0.0, Endian::Little).unwrap();
+    /// let data = [0x01u8, 0x02, 0x03];
+    /// assert_eq!(sig.try_decode_at(&data, 0), Ok(1.0));
+    /// assert_eq!(sig.try_decode_at(&data, 2), Ok(3.0));
+    /// ```
+    pub fn try_decode_at<D: CANRead>(
+        &self,
+        data: &D,
+        byte_offset: usize,
+    ) -> Result<f64, DecodeError> {
+        let start = byte_offset
+            .checked_mul(8)
+            .and_then(|shift| shift.checked_add(self.start as usize))
+            .and_then(|start| u16::try_from(start).ok())
+            .ok_or(DecodeError::NotEnoughData)?;
+        let raw = self.decode_bits_from(start, data)? as f64;
+        Ok(raw * self.factor + self.offset)
+    }
+
+    /// Decodes the physical value, i.e., the raw value multiplied with the factor and summed up
+    /// with the offset, like [try_decode](TryDecode::try_decode) does.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::decode::{Physical, Raw};
+    /// use cantools::signals::Unsigned;
+    /// use cantools::utils::Endian;
+    /// let sig = Unsigned::new(0, 8, 0.5, 10.0, Endian::Little).unwrap();
+    /// assert_eq!(sig.decode_physical(&[4u8]), Ok(Physical(12.0)));
+    /// assert_eq!(sig.decode_raw(&[4u8]), Ok(Raw(4)));
+    /// ```
+    pub fn decode_physical<D: CANRead>(&self, data: &D) -> Result<Physical, DecodeError> {
+        self.try_decode(data).map(Physical)
+    }
+
+    /// Decodes the raw value, i.e., the bit sequence of the signal without applying `factor` and
+    /// `offset`.
+    pub fn decode_raw<D: CANRead>(&self, data: &D) -> Result<Raw, DecodeError> {
+        self.decode_bits(data).map(Raw)
     }
 }
 
-impl TryDecode<f64> for Signed {
-    type Error = DecodeError;
+impl Default for Unsigned {
+    fn default() -> Self {
+        Unsigned {
+            start: 0,
+            length: 1,
+            factor: 1.0,
+            offset: 0.0,
+            endian: Endian::Little,
+            name: None,
+            unit: None,
+            value_table: None,
+            range: None,
+        }
+    }
+}
 
-    fn try_decode<D: CANRead>(&self, data: &D) -> Result<f64, Self::Error> {
-        match &self.endian {
-            Endian::Little => {
-                if self.start + self.length > (8 * data.dlc() as u16) {
-                    return Err(DecodeError::NotEnoughData);
-                }
+/// A builder for [Unsigned] signals.
+///
+/// Every setter consumes and returns the builder such that calls can be chained. Values that are
+/// not set explicitly default to the ones of [Unsigned::default].
+///
+/// # Example
+/// ```
+/// use cantools::signals::{Unsigned, UnsignedBuilder};
+/// use cantools::utils::Endian;
+/// let sig = UnsignedBuilder::new()
+///     .start(0)
+///     .length(8)
+///     .factor(42.0)
+///     .offset(1337.0)
+///     .endian(Endian::Little)
+///     .name("speed")
+///     .unit("km/h")
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct UnsignedBuilder {
+    start: u16,
+    length: u16,
+    factor: f64,
+    offset: f64,
+    endian: Endian,
+    name: Option<String>,
+    unit: Option<String>,
+    value_table: Option<ValueTable>,
+    range: Option<(f64, f64)>,
+}
 
-                let start_byte = self.start.div(8);
-                let bit_in_start_byte = self.start % 8;
-                let end_byte = (self.start + self.length - 1).div(8);
-
-                let mut slice = [0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8];
-                let s = start_byte..=end_byte;
-                for (i, byte_index) in s.into_iter().enumerate().filter(|(i, _)| *i < 8) {
-                    match data.data().get(byte_index as usize) {
-                        None => {
-                            slice[i] = 0;
-                        }
-                        Some(value) => {
-                            slice[i] = *value;
-                        }
-                    }
-                }
+impl UnsignedBuilder {
+    /// Constructs a new builder. Equivalent to [Unsigned::builder].
+    pub fn new() -> UnsignedBuilder {
+        UnsignedBuilder::default()
+    }
 
-                let mut converted = i64::from_le_bytes(slice);
-                converted >>= bit_in_start_byte;
-                converted &= i64::mask(self.length, 0);
+    /// Sets the start bit.
+    pub fn start(mut self, start: u16) -> Self {
+        self.start = start;
+        self
+    }
 
-                if converted & i64::mask(1, self.length - 1) != 0 {
-                    converted += !i64::mask(self.length, 0);
-                }
+    /// Sets the number of bits.
+    pub fn length(mut self, length: u16) -> Self {
+        self.length = length;
+        self
+    }
 
-                let mut result = converted as f64;
-                result *= &self.factor;
-                result += &self.offset;
-                Ok(result)
-            }
-            Endian::Big => {
-                let shift = (7 - self.start % 8) + 8 * self.start.div(8);
-                let shift = (8 * data.dlc()) as isize - (shift as isize) - (self.length as isize);
-                if shift < 0 {
-                    return Err(DecodeError::NotEnoughData);
-                }
+    /// Sets the factor the integer value is multiplied with.
+    pub fn factor(mut self, factor: f64) -> Self {
+        self.factor = factor;
+        self
+    }
 
-                let start_byte = self.start.div(8);
-                let end_byte = (7 - self.start % 8) + 8 * self.start.div(8);
-                let end_byte = (end_byte + self.length - 1).div(8);
-
-                let mut slice = [0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8];
-                let s = start_byte..=end_byte;
-
-                let min_data = min(8, s.len());
-                for (i, byte_index) in s.into_iter().enumerate().filter(|(i, _)| *i < min_data) {
-                    match data.data().get(byte_index as usize) {
-                        None => {
-                            slice[min_data - i - 1] = 0;
-                        }
-                        Some(value) => {
-                            slice[min_data - i - 1] = *value;
-                        }
-                    }
-                }
+    /// Sets the offset added to the scaled integer value.
+    pub fn offset(mut self, offset: f64) -> Self {
+        self.offset = offset;
+        self
+    }
 
-                let mut converted = i64::from_le_bytes(slice);
-                converted >>= 7 - self.start % 8;
-                converted &= i64::mask(self.length, 0);
+    /// Sets the byte-order.
+    pub fn endian(mut self, endian: Endian) -> Self {
+        self.endian = endian;
+        self
+    }
 
-                if converted & i64::mask(1, self.length - 1) != 0 {
-                    converted += !i64::mask(self.length, 0);
-                }
+    /// Sets the name of the signal.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(String::from(name));
+        self
+    }
 
-                let mut result = converted as f64;
-                result *= &self.factor;
-                result += &self.offset;
-                Ok(result)
-            }
+    /// Sets the unit of the physical value.
+    pub fn unit(mut self, unit: &str) -> Self {
+        self.unit = Some(String::from(unit));
+        self
+    }
+
+    /// Sets the value table assigning labels to raw values.
+    pub fn value_table(mut self, value_table: ValueTable) -> Self {
+        self.value_table = Some(value_table);
+        self
+    }
+
+    /// Sets the documented physical range, e.g., the `[min|max]` of a DBC signal. The range
+    /// narrows [min](Min::min) and [max](Max::max) of the signal.
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        self.range = Some((min, max));
+        self
+    }
+
+    /// Constructs the [Unsigned] signal. Fails under the same conditions as [Unsigned::new].
+    pub fn build(self) -> Result<Unsigned, LengthError> {
+        let mut sig = Unsigned::new(
+            self.start,
+            self.length,
+            self.factor,
+            self.offset,
+            self.endian,
+        )?;
+        sig.name = self.name;
+        sig.unit = self.unit;
+        sig.value_table = self.value_table;
+        sig.range = self.range;
+        Ok(sig)
+    }
+}
+
+impl Default for UnsignedBuilder {
+    fn default() -> Self {
+        UnsignedBuilder {
+            start: 0,
+            length: 1,
+            factor: 1.0,
+            offset: 0.0,
+            endian: Endian::Little,
+            name: None,
+            unit: None,
+            value_table: None,
+            range: None,
         }
     }
 }
 
-impl DefaultDecode<f64> for Signed {}
-impl Decode<f64> for Signed {}
+impl Min for Unsigned {
+    type Item = f64;
 
-impl TryEncode<f64> for Signed {
+    fn min(&self) -> Self::Item {
+        let base = self.offset;
+        match self.range {
+            Some((min, _)) => base.max(min),
+            None => base,
+        }
+    }
+}
+
+impl Max for Unsigned {
+    type Item = f64;
+
+    fn max(&self) -> Self::Item {
+        let mut base = u64::mask(self.length, 0) as f64;
+        base *= self.factor;
+        base += self.offset;
+        match self.range {
+            Some((_, max)) => base.min(max),
+            None => base,
+        }
+    }
+}
+
+impl TryDecode<f64> for Unsigned {
+    type Error = DecodeError;
+
+    fn try_decode<D: CANRead>(&self, data: &D) -> Result<f64, Self::Error> {
+        let mut result = self.decode_bits(data)? as f64;
+        result *= &self.factor;
+        result += &self.offset;
+        Ok(result)
+    }
+}
+
+impl DefaultDecode<f64> for Unsigned {}
+impl Decode<f64> for Unsigned {}
+
+impl TryEncode<f64> for Unsigned {
     type Error = EncodeError;
 
     fn try_encode<D: CANWrite>(&self, data: &mut D, value: f64) -> Result<(), Self::Error> {
@@ -546,84 +979,1803 @@ impl TryEncode<f64> for Signed {
             return Err(EncodeError::MaxError);
         }
 
-        match self.endian {
-            Endian::Little => {
-                if self.start + self.length > (8 * data.dlc() as u16) {
-                    return Err(EncodeError::NotEnoughData);
-                }
-
-                // compute integer value to be set
-                let value = value - self.offset;
-                let value = value / self.factor;
-                let mut value = value.trunc() as i64;
-
-                if value < 0 {
-                    value -= !i64::mask(self.length, 0);
-                };
+        // compute integer value to be set
+        let value = value - self.offset;
+        let value = value / self.factor;
+        let value = value.trunc() as u64;
+        let value = value & u64::mask(self.length, 0);
 
-                value &= i64::mask(self.length, 0);
+        insert_bits(data, self.start, self.length, &self.endian, value)
+    }
+}
 
-                // set data by setting the corresponding data bits
-                for i in 0..self.length {
-                    let bit = Bit::new(self.start + i);
+impl Encode<f64> for Unsigned {}
 
-                    if value & 1 == 0 {
-                        bit.try_encode(data, false).unwrap();
-                    } else {
-                        bit.try_encode(data, true).unwrap();
-                    }
+/// A type modeling multiple bits.
+///
+/// The `length` number of bits represent a signed integer which is multiplied with the `factor`,
+/// and summed up with the `offset`. The `endian` controls the exact sequence of selected bits.
+/// By default, the most significant bit is the sign bit. Values stored in a wider field can move
+/// it to a lower bit with [SignedBuilder::sign_bit].
+///
+/// The following formula summarizes the relation between the bit sequence, `factor`, and `offset`:
+/// ```latex
+/// result = bits_signed * factor + offset
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct Signed {
+    start: u16,
+    length: u16,
+    factor: f64,
+    offset: f64,
+    endian: Endian,
+    name: Option<String>,
+    unit: Option<String>,
+    value_table: Option<ValueTable>,
+    range: Option<(f64, f64)>,
+    sign_bit: Option<u16>,
+}
 
-                    value >>= 1;
-                }
-            }
-            Endian::Big => {
-                let shift = (7 - self.start % 8) + 8 * self.start.div(8);
-                let shift = (8 * data.dlc()) as isize - (shift as isize) - (self.length as isize);
-                if shift < 0 {
-                    return Err(EncodeError::NotEnoughData);
-                }
+impl Signed {
+    /// Constructs a new Signed signal.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::signals::Signed;
+    /// use cantools::utils::Endian;
+    /// let sig = Signed::new(0, 8, 42.0, 1337.0, Endian::Little).unwrap();
+    /// ```
+    pub fn new(
+        start: u16,
+        length: u16,
+        factor: f64,
+        offset: f64,
+        endian: Endian,
+    ) -> Result<Signed, LengthError> {
+        if length == 0 {
+            return Err(LengthError::LengthZero);
+        } else if length > MAX_SCALAR_BITS {
+            return Err(LengthError::LengthGreater64);
+        }
 
-                // compute integer value to be set
-                let value = value - self.offset;
-                let value = value / self.factor;
-                let mut value = value.trunc() as i64;
+        let half = 2f64.powi(length as i32 - 1);
+        check_range(-half, half - 1.0, factor, offset)?;
+
+        Ok(Signed {
+            start,
+            length,
+            factor,
+            offset,
+            endian,
+            name: None,
+            unit: None,
+            value_table: None,
+            range: None,
+            sign_bit: None,
+        })
+    }
 
-                if value < 0 {
-                    value -= !i64::mask(self.length, 0);
-                };
+    /// Returns the smallest raw value of the signal, i.e., the value before applying `factor`
+    /// and `offset`, which is `-2^(length - 1)` unless a different [sign_bit](Signed::sign_bit) is
+    /// set.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::signals::Signed;
+    /// use cantools::utils::Endian;
+    /// let sig = Signed::new(0, 8, 0.5, 10.0, Endian::Little).unwrap();
+    /// assert_eq!(sig.raw_min(), -128);
+    /// ```
+    pub fn raw_min(&self) -> i64 {
+        i64::MIN >> (63 - self.sign_bit())
+    }
 
-                value &= i64::mask(self.length, 0);
+    /// Returns the largest raw value of the signal, i.e., `2^(length - 1) - 1` unless a different
+    /// [sign_bit](Signed::sign_bit) is set.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::signals::Signed;
+    /// use cantools::utils::Endian;
+    /// let sig = Signed::new(0, 8, 0.5, 10.0, Endian::Little).unwrap();
+    /// assert_eq!(sig.raw_max(), 127);
+    /// ```
+    pub fn raw_max(&self) -> i64 {
+        i64::MAX >> (63 - self.sign_bit())
+    }
 
-                // set data by setting the corresponding data bits
-                let mut start = self.start;
-                for i in 0..self.length {
-                    let bit = Bit::new(start);
+    /// Checks whether the signal can be decoded from, or encoded into, CAN-bus data consisting of
+    /// `dlc` bytes. For big-endian signals, this catches start bits from which the remaining
+    /// `length` bits run past the end of the data.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::signals::Signed;
+    /// use cantools::utils::Endian;
+    /// let sig = Signed::new(7, 16, 1.0, 0.0, Endian::Big).unwrap();
+    /// assert!(sig.validate(2).is_ok());
+    /// assert!(sig.validate(1).is_err());
+    /// ```
+    pub fn validate(&self, dlc: usize) -> Result<(), DecodeError> {
+        check_bounds(self.start, self.length, &self.endian, dlc)
+    }
 
-                    if (value >> (self.length - 1 - i)) & 1 == 0 {
-                        bit.try_encode(data, false).unwrap();
-                    } else {
-                        bit.try_encode(data, true).unwrap();
-                    }
+    /// Extracts the bit sequence of the signal from `data` as an integer, i.e., without applying
+    /// `factor` and `offset`.
+    fn decode_bits<D: CANRead>(&self, data: &D) -> Result<i64, DecodeError> {
+        self.decode_bits_from(self.start, data)
+    }
 
-                    // update start to be the next bit to set
-                    start = if start % 8 == 0 {
-                        (start.div(8) + 1) * 8 + 7
-                    } else {
-                        start - 1
-                    };
-                }
+    /// Extracts the bit sequence of the signal as if it started at bit `start`.
+    fn decode_bits_from<D: CANRead>(&self, start: u16, data: &D) -> Result<i64, DecodeError> {
+        match &self.endian {
+            Endian::Little => {
+                check_bounds(start, self.length, &self.endian, data.dlc())?;
+                let bits = little_endian_bits(start, self.length, data.data());
+                Ok(self.sign_extend(bits))
+            }
+            Endian::Big => {
+                check_bounds(start, self.length, &self.endian, data.dlc())?;
+                let bits = big_endian_bits(start, self.length, data.data());
+                Ok(self.sign_extend(bits))
             }
         }
+    }
 
-        Ok(())
+    /// Converts the integer `value` into the two's complement bits up to the sign bit. The bits
+    /// above the sign bit remain zero.
+    fn to_bits(&self, value: i64) -> u64 {
+        let width = self.sign_bit() + 1;
+        let mut value = value;
+        if value < 0 {
+            value -= !i64::mask(width, 0);
+        };
+
+        (value & i64::mask(width, 0)) as u64
     }
-}
 
-impl Encode<f64> for Signed {}
+    /// Interprets the bits up to the sign bit as two's complement integer, ignoring the bits above.
+    fn sign_extend(&self, bits: u64) -> i64 {
+        let width = self.sign_bit() + 1;
+        let mut converted = bits as i64 & i64::mask(width, 0);
 
-// #[derive(Debug,PartialEq)]
-// pub struct Float32 {
-//     start: u16,
+        if converted & i64::mask(1, width - 1) != 0 {
+            converted += !i64::mask(width, 0);
+        }
+
+        converted
+    }
+
+    /// Compiles the signal into a [CompiledSignal] decoding the same values.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::signals::Signed;
+    /// use cantools::decode::TryDecode;
+    /// use cantools::utils::Endian;
+    ///
+    /// let sig = Signed::new(4, 8, 2.0, 1337.0, Endian::Little).unwrap();
+    /// let compiled = sig.compile();
+    ///
+    /// let data = [0b0101_0000u8, 0b0000_0011];
+    /// let mut value = 0.0;
+    /// compiled.decode_into(&data, &mut value).unwrap();
+    /// assert_eq!(Ok(value), sig.try_decode(&data));
+    /// ```
+    pub fn compile(&self) -> CompiledSignal {
+        let mut compiled = CompiledSignal::new(
+            self.start,
+            self.length,
+            &self.endian,
+            self.factor,
+            self.offset,
+            true,
+        );
+        // the bits above the sign bit are not part of the value
+        compiled.mask = u64::mask(self.sign_bit() + 1, 0);
+        compiled
+    }
+
+    /// Returns a [SignedBuilder] initialized with the values of [Signed::default].
+    pub fn builder() -> SignedBuilder {
+        SignedBuilder::default()
+    }
+
+    /// Returns the start bit.
+    pub fn start(&self) -> u16 {
+        self.start
+    }
+
+    /// Returns the absolute positions of the bits of the signal ordered from the least to the most
+    /// significant bit. See [Unsigned::bit_positions].
+    pub fn bit_positions(&self) -> Vec<u16> {
+        bit_positions(self.start, self.length, &self.endian).collect()
+    }
+
+    /// Returns the number of bits.
+    pub fn length(&self) -> u16 {
+        self.length
+    }
+
+    /// Returns the bit treated as sign bit, counted from the least significant bit of the signal.
+    /// Defaults to `length - 1`, i.e., the most significant bit (see [SignedBuilder::sign_bit]).
+    pub fn sign_bit(&self) -> u16 {
+        self.sign_bit.unwrap_or(self.length - 1)
+    }
+
+    /// Returns the factor the raw value is multiplied with.
+    pub fn factor(&self) -> f64 {
+        self.factor
+    }
+
+    /// Returns the offset added to the scaled raw value.
+    pub fn offset(&self) -> f64 {
+        self.offset
+    }
+
+    /// Replaces the factor the raw value is multiplied with, e.g., when calibrating a signal at
+    /// runtime. [Min] and [Max] follow the new factor. The signal is left unchanged if the new
+    /// factor yields a non-finite range, see [new](Self::new).
+    pub fn set_factor(&mut self, factor: f64) -> Result<(), LengthError> {
+        let half = 2f64.powi(self.length as i32 - 1);
+        check_range(-half, half - 1.0, factor, self.offset)?;
+        self.factor = factor;
+        Ok(())
+    }
+
+    /// Replaces the offset added to the scaled raw value. [Min] and [Max] follow the new offset.
+    /// The signal is left unchanged if the new offset yields a non-finite range.
+    pub fn set_offset(&mut self, offset: f64) -> Result<(), LengthError> {
+        let half = 2f64.powi(self.length as i32 - 1);
+        check_range(-half, half - 1.0, self.factor, offset)?;
+        self.offset = offset;
+        Ok(())
+    }
+
+    /// Returns the byte-order.
+    pub fn endian(&self) -> &Endian {
+        &self.endian
+    }
+
+    /// Returns the name of the signal, if any.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the unit of the physical value, if any.
+    pub fn unit(&self) -> Option<&str> {
+        self.unit.as_deref()
+    }
+
+    /// Returns the value table assigning labels to raw values, if any.
+    pub fn value_table(&self) -> Option<&ValueTable> {
+        self.value_table.as_ref()
+    }
+
+    /// Returns the documented physical range `(min, max)`, if any.
+    pub fn range(&self) -> Option<(f64, f64)> {
+        self.range
+    }
+
+    /// Decodes the label assigned to the raw value by the value table. `None` is returned if the
+    /// signal has no value table or the raw value has no label.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::signals::Signed;
+    /// use cantools::values::ValueTable;
+    ///
+    /// let mut table = ValueTable::new();
+    /// table.insert_range(0..=9, "Low");
+    /// table.insert_range(10..=19, "Mid");
+    /// let sig = Signed::builder().length(6).value_table(table).build().unwrap();
+    ///
+    /// assert_eq!(sig.decode_named(&[12u8]), Ok(Some("Mid")));
+    /// assert_eq!(sig.decode_named(&[20u8]), Ok(None));
+    /// ```
+    pub fn decode_named<D: CANRead>(&self, data: &D) -> Result<Option<&str>, DecodeError> {
+        let raw = self.decode_bits(data)?;
+        Ok(self.value_table.as_ref().and_then(|table| table.label(raw)))
+    }
+
+    /// Encodes the raw value the value table assigns `label` to, e.g., `"On"` instead of `1`.
+    /// Returns [EncodeError::UnknownLabel] if the signal has no value table or `label` is not part
+    /// of it.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::signals::Signed;
+    /// use cantools::values::ValueTable;
+    ///
+    /// let mut table = ValueTable::new();
+    /// table.insert(-1, "Off");
+    /// table.insert(1, "On");
+    /// let sig = Signed::builder().start(2).length(2).value_table(table).build().unwrap();
+    ///
+    /// let mut data = [0u8];
+    /// sig.try_encode_named(&mut data, "On").unwrap();
+    /// assert_eq!(data, [0b0000_0100]);
+    ///
+    /// sig.try_encode_named(&mut data, "Off").unwrap();
+    /// assert_eq!(data, [0b0000_1100]);
+    /// ```
+    pub fn try_encode_named<D: CANWrite>(
+        &self,
+        data: &mut D,
+        label: &str,
+    ) -> Result<(), EncodeError> {
+        let raw = self
+            .value_table
+            .as_ref()
+            .and_then(|table| table.value(label))
+            .ok_or(EncodeError::UnknownLabel)?;
+
+        if raw < self.raw_min() {
+            return Err(EncodeError::MinError);
+        }
+
+        if raw > self.raw_max() {
+            return Err(EncodeError::MaxError);
+        }
+
+        insert_bits(
+            data,
+            self.start,
+            self.length,
+            &self.endian,
+            self.to_bits(raw),
+        )
+    }
+
+    /// Decodes the raw value, the physical value, and the label assigned to the raw value by the
+    /// value table at once.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::signals::Signed;
+    /// use cantools::values::ValueTable;
+    ///
+    /// let mut table = ValueTable::new();
+    /// table.insert(1, "On");
+    /// let sig = Signed::builder().length(2).offset(10.0).value_table(table).build().unwrap();
+    ///
+    /// let value = sig.decode_full(&[0x01u8]).unwrap();
+    /// assert_eq!(value.raw, 1);
+    /// assert_eq!(value.physical, 11.0);
+    /// assert_eq!(value.label.as_deref(), Some("On"));
+    /// ```
+    pub fn decode_full<D: CANRead>(&self, data: &D) -> Result<DecodedValue, DecodeError> {
+        let raw = self.decode_bits(data)?;
+        let physical = raw as f64 * self.factor + self.offset;
+        let label = self
+            .value_table
+            .as_ref()
+            .and_then(|table| table.label(raw))
+            .map(String::from);
+        Ok(DecodedValue {
+            raw,
+            physical,
+            label,
+        })
+    }
+
+    /// Decodes the raw value, i.e., without applying `factor` and `offset`, and converts it into
+    /// the user-provided type `E`, e.g., an enum modeling the states of the signal. Returns
+    /// [EnumDecodeError::UnknownValue] if the conversion fails.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::decode::EnumDecodeError;
+    /// use cantools::signals::Signed;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Gear {
+    ///     Park,
+    ///     Drive,
+    /// }
+    ///
+    /// impl TryFrom<i64> for Gear {
+    ///     type Error = ();
+    ///
+    ///     fn try_from(value: i64) -> Result<Self, Self::Error> {
+    ///         match value {
+    ///             0 => Ok(Gear::Park),
+    ///             1 => Ok(Gear::Drive),
+    ///             _ => Err(()),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let sig = Signed::builder().length(4).build().unwrap();
+    /// assert_eq!(sig.try_decode_enum(&[0x01u8]), Ok(Gear::Drive));
+    /// assert_eq!(
+    ///     sig.try_decode_enum::<Gear, _>(&[0x02u8]),
+    ///     Err(EnumDecodeError::UnknownValue(2))
+    /// );
+    /// ```
+    pub fn try_decode_enum<E, D>(&self, data: &D) -> Result<E, EnumDecodeError>
+    where
+        E: TryFrom<i64>,
+        D: CANRead,
+    {
+        let raw = self.decode_bits(data)?;
+        E::try_from(raw).map_err(|_| EnumDecodeError::UnknownValue(raw))
+    }
+
+    /// Decodes the physical value and formats it with the precision of the signal followed by the
+    /// unit, if any, e.g., for reports. The number of decimal places is the one needed to print
+    /// `factor` and `offset` exactly, e.g., `2` for a factor of `0.01`.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::signals::Signed;
+    /// let sig = Signed::builder().length(8).factor(0.1).unit("km/h").build().unwrap();
+    /// assert_eq!(sig.decode_display(&[123u8]), Ok(String::from("12.3 km/h")));
+    /// ```
+    pub fn decode_display<D: CANRead>(&self, data: &D) -> Result<String, DecodeError> {
+        let value: f64 = self.try_decode(data)?;
+        let places = decimal_places(self.factor).max(decimal_places(self.offset));
+        Ok(match &self.unit {
+            Some(unit) => format!("{:.*} {}", places, value, unit),
+            None => format!("{:.*}", places, value),
+        })
+    }
+
+    /// Decodes the physical value as an integer in units of `1 / out_scale` without using
+    /// floating-point arithmetic on the raw value. For example, a value of `12.3` decoded with
+    /// `out_scale = 1000` returns `12300`.
+    ///
+    /// The `factor` and `offset` are multiplied with `out_scale`, which must yield integers such
+    /// that the result is exact. Returns [DecodeError::InexactScale] otherwise, e.g., for a factor
+    /// of `0.25` and an `out_scale` of `10`, and [DecodeError::Overflow] if the scaled value does
+    /// not fit into an `i64`.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::signals::Signed;
+    /// use cantools::utils::Endian;
+    /// let sig = Signed::new(0, 8, 0.1, 0.0, Endian::Little).unwrap();
+    /// assert_eq!(sig.try_decode_scaled(&[123u8], 1000), Ok(12300));
+    /// ```
+    pub fn try_decode_scaled<D: CANRead>(
+        &self,
+        data: &D,
+        out_scale: u32,
+    ) -> Result<i64, DecodeError> {
+        let raw = self.decode_bits(data)? as i128;
+        let factor = scale_exact(self.factor, out_scale)?;
+        let offset = scale_exact(self.offset, out_scale)?;
+        raw.checked_mul(factor)
+            .and_then(|value| value.checked_add(offset))
+            .and_then(|value| i64::try_from(value).ok())
+            .ok_or(DecodeError::Overflow)
+    }
+
+    /// Decodes the signal like [try_decode](TryDecode::try_decode) but returns
+    /// [DecodeError::Overflow] instead of an infinite or NaN value. [Signed::new] already rejects
+    /// signals whose range is not finite, so this guards pipelines against non-finite values
+    /// regardless of how the signal was defined.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::signals::Signed;
+    /// use cantools::utils::Endian;
+    /// let sig = Signed::new(0, 8, 0.5, 10.0, Endian::Little).unwrap();
+    /// assert_eq!(sig.try_decode_checked(&[4u8]), Ok(12.0));
+    /// ```
+    pub fn try_decode_checked<D: CANRead>(&self, data: &D) -> Result<f64, DecodeError> {
+        let value: f64 = self.try_decode(data)?;
+        if value.is_finite() {
+            Ok(value)
+        } else {
+            Err(DecodeError::Overflow)
+        }
+    }
+
+    /// Decodes the signal like [try_decode](TryDecode::try_decode) but clamps the result to
+    /// [min](Min::min) and [max](Max::max). Raw values outside of the documented
+    /// [range](SignedBuilder::range), e.g., of a misbehaving sensor, decode to the nearest bound
+    /// instead of an out-of-range value.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::signals::Signed;
+    /// let sig = Signed::builder().length(8).range(0.0, 100.0).build().unwrap();
+    /// assert_eq!(sig.try_decode_clamped(&[42u8]), Ok(42.0));
+    /// assert_eq!(sig.try_decode_clamped(&[120u8]), Ok(100.0));
+    /// ```
+    pub fn try_decode_clamped<D: CANRead>(&self, data: &D) -> Result<f64, DecodeError> {
+        let value: f64 = self.try_decode(data)?;
+        Ok(value.max(self.min()).min(self.max()))
+    }
+
+    /// Decodes the signal relative to a window of `data` starting at byte `byte_offset`, i.e.,
+    /// as if the start bit of the signal were shifted by `8 * byte_offset`. This avoids
+    /// constructing sub-slices, e.g., when decoding the payload of a container frame.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::signals::Signed;
+    /// use cantools::utils::Endian;
+    /// let sig = Signed::new(0, 8, 1.0, 0.0, Endian::Little).unwrap();
+    /// let data = [0x01u8, 0x02, 0x03];
+    /// assert_eq!(sig.try_decode_at(&data, 0), Ok(1.0));
+    /// assert_eq!(sig.try_decode_at(&data, 2), Ok(3.0));
+    /// ```
+    pub fn try_decode_at<D: CANRead>(
+        &self,
+        data: &D,
+        byte_offset: usize,
+    ) -> Result<f64, DecodeError> {
+        let start = byte_offset
+            .checked_mul(8)
+            .and_then(|shift| shift.checked_add(self.start as usize))
+            .and_then(|start| u16::try_from(start).ok())
+            .ok_or(DecodeError::NotEnoughData)?;
+        let raw = self.decode_bits_from(start, data)? as f64;
+        Ok(raw * self.factor + self.offset)
+    }
+
+    /// Decodes the physical value, i.e., the raw value multiplied with the factor and summed up
+    /// with the offset, like [try_decode](TryDecode::try_decode) does.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::decode::{Physical, Raw};
+    /// use cantools::signals::Signed;
+    /// use cantools::utils::Endian;
+    /// let sig = Signed::new(0, 8, 0.5, 10.0, Endian::Little).unwrap();
+    /// assert_eq!(sig.decode_physical(&[0xFCu8]), Ok(Physical(8.0)));
+    /// assert_eq!(sig.decode_raw(&[0xFCu8]), Ok(Raw(0xFC)));
+    /// ```
+    pub fn decode_physical<D: CANRead>(&self, data: &D) -> Result<Physical, DecodeError> {
+        self.try_decode(data).map(Physical)
+    }
+
+    /// Decodes the raw value, i.e., the bit sequence of the signal without applying `factor` and
+    /// `offset`. The raw value is the two's complement of the signed integer limited to `length`
+    /// bits, e.g., `-4` of an 8-bit signal is returned as `Raw(0xFC)`.
+    pub fn decode_raw<D: CANRead>(&self, data: &D) -> Result<Raw, DecodeError> {
+        let bits = self.decode_bits(data)? as u64;
+        Ok(Raw(bits & u64::mask(self.length, 0)))
+    }
+}
+
+impl Default for Signed {
+    fn default() -> Self {
+        Signed {
+            start: 0,
+            length: 1,
+            factor: 1.0,
+            offset: 0.0,
+            endian: Endian::Little,
+            name: None,
+            unit: None,
+            value_table: None,
+            range: None,
+            sign_bit: None,
+        }
+    }
+}
+
+/// A builder for [Signed] signals.
+///
+/// Every setter consumes and returns the builder such that calls can be chained. Values that are
+/// not set explicitly default to the ones of [Signed::default].
+///
+/// # Example
+/// ```
+/// use cantools::signals::{Signed, SignedBuilder};
+/// use cantools::utils::Endian;
+/// let sig = SignedBuilder::new()
+///     .start(0)
+///     .length(8)
+///     .factor(42.0)
+///     .offset(1337.0)
+///     .endian(Endian::Little)
+///     .name("speed")
+///     .unit("km/h")
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct SignedBuilder {
+    start: u16,
+    length: u16,
+    factor: f64,
+    offset: f64,
+    endian: Endian,
+    name: Option<String>,
+    unit: Option<String>,
+    value_table: Option<ValueTable>,
+    range: Option<(f64, f64)>,
+    sign_bit: Option<u16>,
+}
+
+impl SignedBuilder {
+    /// Constructs a new builder. Equivalent to [Signed::builder].
+    pub fn new() -> SignedBuilder {
+        SignedBuilder::default()
+    }
+
+    /// Sets the start bit.
+    pub fn start(mut self, start: u16) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// Sets the number of bits.
+    pub fn length(mut self, length: u16) -> Self {
+        self.length = length;
+        self
+    }
+
+    /// Sets the factor the integer value is multiplied with.
+    pub fn factor(mut self, factor: f64) -> Self {
+        self.factor = factor;
+        self
+    }
+
+    /// Sets the offset added to the scaled integer value.
+    pub fn offset(mut self, offset: f64) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the byte-order.
+    pub fn endian(mut self, endian: Endian) -> Self {
+        self.endian = endian;
+        self
+    }
+
+    /// Sets the name of the signal.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(String::from(name));
+        self
+    }
+
+    /// Sets the unit of the physical value.
+    pub fn unit(mut self, unit: &str) -> Self {
+        self.unit = Some(String::from(unit));
+        self
+    }
+
+    /// Sets the value table assigning labels to raw values.
+    pub fn value_table(mut self, value_table: ValueTable) -> Self {
+        self.value_table = Some(value_table);
+        self
+    }
+
+    /// Sets the documented physical range, e.g., the `[min|max]` of a DBC signal. The range
+    /// narrows [min](Min::min) and [max](Max::max) of the signal.
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        self.range = Some((min, max));
+        self
+    }
+
+    /// Sets the bit treated as sign bit, counted from the least significant bit of the signal.
+    /// The bits above the sign bit are ignored when decoding and set to zero when encoding.
+    pub fn sign_bit(mut self, sign_bit: u16) -> Self {
+        self.sign_bit = Some(sign_bit);
+        self
+    }
+
+    /// Constructs the [Signed] signal. Fails under the same conditions as [Signed::new] and if
+    /// the sign bit does not lie inside of the `length` bits.
+    pub fn build(self) -> Result<Signed, LengthError> {
+        let mut sig = Signed::new(
+            self.start,
+            self.length,
+            self.factor,
+            self.offset,
+            self.endian,
+        )?;
+        sig.name = self.name;
+        sig.unit = self.unit;
+        sig.value_table = self.value_table;
+        sig.range = self.range;
+
+        if let Some(sign_bit) = self.sign_bit {
+            if sign_bit >= sig.length {
+                return Err(LengthError::SignBitOutOfRange);
+            }
+            sig.sign_bit = Some(sign_bit);
+        }
+
+        Ok(sig)
+    }
+}
+
+impl Default for SignedBuilder {
+    fn default() -> Self {
+        SignedBuilder {
+            start: 0,
+            length: 1,
+            factor: 1.0,
+            offset: 0.0,
+            endian: Endian::Little,
+            name: None,
+            unit: None,
+            value_table: None,
+            range: None,
+            sign_bit: None,
+        }
+    }
+}
+
+/// The two's-complement minimum of `n = sign_bit + 1` bits is `-2^(n - 1)`, i.e., only the sign bit
+/// set and sign-extended. [raw_min](Signed::raw_min) computes it by arithmetically shifting
+/// [i64::MIN], whose only set bit is the sign bit of 64 bits, right by `64 - n`. This holds for
+/// every `n` from `1`, yielding `-1`, to `64`, yielding [i64::MIN].
+impl Min for Signed {
+    type Item = f64;
+
+    fn min(&self) -> Self::Item {
+        let mut base = self.raw_min() as f64;
+        base *= self.factor;
+        base += self.offset;
+        match self.range {
+            Some((min, _)) => base.max(min),
+            None => base,
+        }
+    }
+}
+
+/// The two's-complement maximum of `n = sign_bit + 1` bits is `2^(n - 1) - 1`, i.e., every bit but
+/// the sign bit set. [raw_max](Signed::raw_max) shifts [i64::MAX] right by `64 - n`, yielding `0`
+/// for `n = 1` and [i64::MAX] for `n = 64`.
+impl Max for Signed {
+    type Item = f64;
+
+    fn max(&self) -> Self::Item {
+        let mut base = self.raw_max() as f64;
+        base *= self.factor;
+        base += self.offset;
+        match self.range {
+            Some((_, max)) => base.min(max),
+            None => base,
+        }
+    }
+}
+
+impl TryDecode<f64> for Signed {
+    type Error = DecodeError;
+
+    fn try_decode<D: CANRead>(&self, data: &D) -> Result<f64, Self::Error> {
+        let mut result = self.decode_bits(data)? as f64;
+        result *= &self.factor;
+        result += &self.offset;
+        Ok(result)
+    }
+}
+
+impl DefaultDecode<f64> for Signed {}
+impl Decode<f64> for Signed {}
+
+impl TryEncode<f64> for Signed {
+    type Error = EncodeError;
+
+    fn try_encode<D: CANWrite>(&self, data: &mut D, value: f64) -> Result<(), Self::Error> {
+        if value < self.min() {
+            return Err(EncodeError::MinError);
+        }
+
+        if value > self.max() {
+            return Err(EncodeError::MaxError);
+        }
+
+        // compute integer value to be set
+        let value = value - self.offset;
+        let value = value / self.factor;
+        let value = value.trunc() as i64;
+
+        insert_bits(
+            data,
+            self.start,
+            self.length,
+            &self.endian,
+            self.to_bits(value),
+        )
+    }
+}
+
+impl Encode<f64> for Signed {}
+
+/// A type modeling a sequence of bits that is not interpreted as a number, e.g., a VIN or a
+/// 128-bit identifier.
+///
+/// Contrary to [Unsigned] and [Signed], the `length` is not limited to 64 bits. The bits decode
+/// into `length / 8` bytes, rounded up. The `endian` controls the exact sequence of selected bits as
+/// well as the order of the decoded bytes: [Endian::Little] yields the least significant byte
+/// first, [Endian::Big] the most significant byte first. If `length` is no multiple of `8`, the most
+/// significant byte holds the remaining bits. Byte-aligned sequences decode into the data bytes in
+/// their original order in both cases.
+///
+/// # Example
+/// ```
+/// use cantools::decode::TryDecode;
+/// use cantools::encode::TryEncode;
+/// use cantools::signals::Bytes;
+/// use cantools::utils::Endian;
+///
+/// let sig = Bytes::new(8, 24, Endian::Little).unwrap();
+/// let mut data = [0u8; 4];
+///
+/// assert_eq!(sig.try_encode(&mut data, &b"abc"[..]), Ok(()));
+/// assert_eq!(data, [0x00, b'a', b'b', b'c']);
+/// assert_eq!(sig.try_decode(&data), Ok(b"abc".to_vec()));
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct Bytes {
+    start: u16,
+    length: u16,
+    endian: Endian,
+}
+
+impl Bytes {
+    /// Constructs a new Bytes signal consisting of `length` bits.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::signals::Bytes;
+    /// use cantools::utils::Endian;
+    /// let sig = Bytes::new(0, 136, Endian::Little).unwrap();
+    /// ```
+    pub fn new(start: u16, length: u16, endian: Endian) -> Result<Bytes, LengthError> {
+        if length == 0 {
+            return Err(LengthError::LengthZero);
+        }
+
+        Ok(Bytes {
+            start,
+            length,
+            endian,
+        })
+    }
+
+    /// Returns the start bit of the signal.
+    pub fn start(&self) -> u16 {
+        self.start
+    }
+
+    /// Returns the number of bits of the signal.
+    pub fn length(&self) -> u16 {
+        self.length
+    }
+
+    /// Returns the byte-order of the signal.
+    pub fn endian(&self) -> &Endian {
+        &self.endian
+    }
+
+    /// Returns the number of bytes the signal decodes into.
+    pub fn byte_length(&self) -> usize {
+        (self.length as usize).div_ceil(8)
+    }
+
+    /// Returns the position in the data of the bit with significance `i`, i.e., `i = 0` selects the
+    /// least significant bit.
+    fn data_bit(&self, i: usize) -> usize {
+        let start = self.start as usize;
+        match self.endian {
+            Endian::Little => start + i,
+            Endian::Big => {
+                // position when counting from the most significant bit of the first byte
+                let position = (7 - start % 8) + 8 * start.div(8) + self.length as usize - 1 - i;
+                8 * position.div(8) + 7 - position % 8
+            }
+        }
+    }
+
+    /// Returns the index of the decoded byte holding the bit with significance `i`.
+    fn byte_index(&self, i: usize) -> usize {
+        match self.endian {
+            Endian::Little => i.div(8),
+            Endian::Big => self.byte_length() - 1 - i.div(8),
+        }
+    }
+}
+
+impl TryDecode<Vec<u8>> for Bytes {
+    type Error = DecodeError;
+
+    fn try_decode<D: CANRead>(&self, data: &D) -> Result<Vec<u8>, Self::Error> {
+        check_bounds(self.start, self.length, &self.endian, data.dlc())?;
+
+        let mut bytes = vec![0u8; self.byte_length()];
+        for i in 0..self.length as usize {
+            let bit = self.data_bit(i);
+            let byte = data
+                .data()
+                .get(bit.div(8))
+                .ok_or(DecodeError::NotEnoughData)?;
+
+            if (byte >> (bit % 8)) & 0x01 != 0 {
+                bytes[self.byte_index(i)] |= 1 << (i % 8);
+            }
+        }
+
+        Ok(bytes)
+    }
+}
+
+impl DefaultDecode<Vec<u8>> for Bytes {}
+impl Decode<Vec<u8>> for Bytes {}
+
+/// Encodes exactly [byte_length](Bytes::byte_length) bytes ordered as returned by the decoding. A
+/// shorter value fails with [EncodeError::NotEnoughData], a longer value or one with bits set
+/// beyond `length` fails with [EncodeError::MaxError].
+impl TryEncode<&[u8]> for Bytes {
+    type Error = EncodeError;
+
+    fn try_encode<D: CANWrite>(&self, data: &mut D, value: &[u8]) -> Result<(), Self::Error> {
+        if value.len() < self.byte_length() {
+            return Err(EncodeError::NotEnoughData);
+        }
+
+        if value.len() > self.byte_length() {
+            return Err(EncodeError::MaxError);
+        }
+
+        // bits of the most significant byte that lie beyond the length of the signal
+        let unused = 8 * self.byte_length() - self.length as usize;
+        let last = self.byte_index(self.length as usize - 1);
+        if unused > 0 && value[last] & u8::mask(unused as u16, (8 - unused) as u16) != 0 {
+            return Err(EncodeError::MaxError);
+        }
+
+        if required_bits(self.start, self.length, &self.endian) > 8 * data.dlc() {
+            return Err(EncodeError::NotEnoughData);
+        }
+
+        for i in 0..self.length as usize {
+            let bit = (value[self.byte_index(i)] >> (i % 8)) & 0x01 != 0;
+            data.set_bit(self.data_bit(i) as u16, bit)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Encode<&[u8]> for Bytes {}
+
+/// A type modeling a text field, e.g., an ASCII string embedded in a diagnostic message.
+///
+/// Contrary to the other signals, `start` and `length` count bytes instead of bits. The bytes
+/// decode as UTF-8 where invalid sequences are replaced by `U+FFFD`. By default, trailing `0x00`
+/// bytes and spaces are trimmed from the decoded string (see [trim](Text::trim)). Encoding writes
+/// the string padded with the [padding](Text::padding) byte, `0x00` by default, or truncated to the
+/// field.
+///
+/// # Example
+/// ```
+/// use cantools::decode::TryDecode;
+/// use cantools::encode::TryEncode;
+/// use cantools::signals::Text;
+///
+/// let sig = Text::new(1, 4).unwrap();
+/// let mut data = [0xFFu8; 5];
+///
+/// assert_eq!(sig.try_encode(&mut data, "ok"), Ok(()));
+/// assert_eq!(data, [0xFF, b'o', b'k', 0x00, 0x00]);
+/// assert_eq!(sig.try_decode(&data), Ok(String::from("ok")));
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct Text {
+    start: u16,
+    length: u16,
+    trim: bool,
+    padding: u8,
+}
+
+impl Text {
+    /// Constructs a new Text signal consisting of `length` bytes starting at byte `start`.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::signals::Text;
+    /// let sig = Text::new(0, 5).unwrap();
+    /// ```
+    pub fn new(start: u16, length: u16) -> Result<Text, LengthError> {
+        if length == 0 {
+            return Err(LengthError::LengthZero);
+        }
+
+        Ok(Text {
+            start,
+            length,
+            trim: true,
+            padding: 0x00,
+        })
+    }
+
+    /// Sets whether trailing `0x00` bytes and spaces are trimmed from the decoded string.
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Sets the byte used to pad strings shorter than the field.
+    pub fn padding(mut self, padding: u8) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Returns the start byte of the signal.
+    pub fn start(&self) -> u16 {
+        self.start
+    }
+
+    /// Returns the number of bytes of the signal.
+    pub fn length(&self) -> u16 {
+        self.length
+    }
+
+    /// Returns the range of the data bytes holding the signal.
+    fn byte_range(&self) -> std::ops::Range<usize> {
+        let start = self.start as usize;
+        start..start + self.length as usize
+    }
+}
+
+impl TryDecode<String> for Text {
+    type Error = DecodeError;
+
+    fn try_decode<D: CANRead>(&self, data: &D) -> Result<String, Self::Error> {
+        let bytes = match data.data().get(self.byte_range()) {
+            Some(bytes) => bytes,
+            None => return Err(DecodeError::NotEnoughData),
+        };
+
+        let text = String::from_utf8_lossy(bytes);
+        if self.trim {
+            Ok(String::from(text.trim_end_matches(['\0', ' '])))
+        } else {
+            Ok(text.into_owned())
+        }
+    }
+}
+
+impl DefaultDecode<String> for Text {}
+impl Decode<String> for Text {}
+
+/// Strings longer than the field are truncated at the last character that fits completely.
+impl TryEncode<&str> for Text {
+    type Error = EncodeError;
+
+    fn try_encode<D: CANWrite>(&self, data: &mut D, value: &str) -> Result<(), Self::Error> {
+        let range = self.byte_range();
+        if range.end > data.dlc() {
+            return Err(EncodeError::NotEnoughData);
+        }
+
+        let mut end = value.len().min(range.len());
+        while !value.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        let mut bytes = vec![self.padding; range.len()];
+        bytes[..end].copy_from_slice(&value.as_bytes()[..end]);
+
+        for (index, byte) in range.zip(bytes) {
+            for j in 0..8 {
+                // bits beyond u16::MAX cannot be addressed
+                let bit = u16::try_from(8 * index + j).map_err(|_| EncodeError::NotEnoughData)?;
+                data.set_bit(bit, (byte >> j) & 0x01 != 0)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Encode<&str> for Text {}
+
+/// A type holding either an [Unsigned] or a [Signed] signal.
+///
+/// [SignalKind] can be parsed from a DBC-like specification of the form
+/// `start|length@byteorder sign (factor,offset)`, where `byteorder` is `1` for little-endian and `0`
+/// for big-endian, and `sign` is `+` for unsigned and `-` for signed signals. For big-endian
+/// signals, `start` denotes the most significant bit just like in DBC files.
+///
+/// # Example
+/// ```
+/// use cantools::signals::{SignalKind, Unsigned, Signed};
+/// use cantools::utils::Endian;
+///
+/// let sig: SignalKind = "0|8@1+ (1,0)".parse().unwrap();
+/// assert_eq!(sig, SignalKind::Unsigned(Unsigned::new(0, 8, 1.0, 0.0, Endian::Little).unwrap()));
+///
+/// let sig: SignalKind = "7|12@0- (0.5,-10)".parse().unwrap();
+/// assert_eq!(sig, SignalKind::Signed(Signed::new(7, 12, 0.5, -10.0, Endian::Big).unwrap()));
+/// ```
+#[derive(Debug, PartialEq)]
+pub enum SignalKind {
+    /// An unsigned signal.
+    Unsigned(Unsigned),
+    /// A signed signal.
+    Signed(Signed),
+}
+
+/// Type representing possible errors when parsing a [SignalKind].
+#[derive(Debug, PartialEq)]
+pub enum SignalKindParseError {
+    MissingStartData,
+    MissingLengthData,
+    MissingByteOrderData,
+    MissingSignData,
+    MissingFactorOffsetData,
+    ParseStartError,
+    ParseLengthError,
+    ParseByteOrderError,
+    ParseSignError,
+    ParseFactorError,
+    ParseOffsetError,
+    ConstructionError(LengthError),
+}
+
+impl fmt::Display for SignalKindParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SignalKindParseError::MissingStartData => write!(f, "missing start bit"),
+            SignalKindParseError::MissingLengthData => write!(f, "missing length"),
+            SignalKindParseError::MissingByteOrderData => write!(f, "missing byte-order"),
+            SignalKindParseError::MissingSignData => write!(f, "missing sign"),
+            SignalKindParseError::MissingFactorOffsetData => write!(f, "missing factor and offset"),
+            SignalKindParseError::ParseStartError => write!(f, "invalid start bit"),
+            SignalKindParseError::ParseLengthError => write!(f, "invalid length"),
+            SignalKindParseError::ParseByteOrderError => write!(f, "invalid byte-order"),
+            SignalKindParseError::ParseSignError => write!(f, "invalid sign"),
+            SignalKindParseError::ParseFactorError => write!(f, "invalid factor"),
+            SignalKindParseError::ParseOffsetError => write!(f, "invalid offset"),
+            SignalKindParseError::ConstructionError(err) => write!(f, "invalid signal: {}", err),
+        }
+    }
+}
+
+impl Error for SignalKindParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SignalKindParseError::ConstructionError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for SignalKind {
+    type Err = SignalKindParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut splits = s.split_whitespace();
+
+        let layout = match splits.next() {
+            Some(layout) => layout,
+            None => return Err(SignalKindParseError::MissingStartData),
+        };
+
+        let (start, rest) = match layout.split_once('|') {
+            Some(value) => value,
+            None => return Err(SignalKindParseError::MissingLengthData),
+        };
+
+        let start = match start.parse::<u16>() {
+            Ok(start) => start,
+            Err(_) => return Err(SignalKindParseError::ParseStartError),
+        };
+
+        let (length, rest) = match rest.split_once('@') {
+            Some(value) => value,
+            None => return Err(SignalKindParseError::MissingByteOrderData),
+        };
+
+        let length = match length.parse::<u16>() {
+            Ok(length) => length,
+            Err(_) => return Err(SignalKindParseError::ParseLengthError),
+        };
+
+        let mut chars = rest.chars();
+        let endian = match chars.next() {
+            Some('1') => Endian::Little,
+            Some('0') => Endian::Big,
+            Some(_) => return Err(SignalKindParseError::ParseByteOrderError),
+            None => return Err(SignalKindParseError::MissingByteOrderData),
+        };
+
+        let signed = match (chars.next(), chars.next()) {
+            (Some('+'), None) => false,
+            (Some('-'), None) => true,
+            (None, _) => return Err(SignalKindParseError::MissingSignData),
+            _ => return Err(SignalKindParseError::ParseSignError),
+        };
+
+        let scaling = match splits.next() {
+            Some(scaling) => scaling,
+            None => return Err(SignalKindParseError::MissingFactorOffsetData),
+        };
+
+        let (factor, offset) = match scaling
+            .strip_prefix('(')
+            .and_then(|scaling| scaling.strip_suffix(')'))
+            .and_then(|scaling| scaling.split_once(','))
+        {
+            Some(value) => value,
+            None => return Err(SignalKindParseError::MissingFactorOffsetData),
+        };
+
+        let factor = match factor.parse::<f64>() {
+            Ok(factor) => factor,
+            Err(_) => return Err(SignalKindParseError::ParseFactorError),
+        };
+
+        let offset = match offset.parse::<f64>() {
+            Ok(offset) => offset,
+            Err(_) => return Err(SignalKindParseError::ParseOffsetError),
+        };
+
+        let result = if signed {
+            Signed::new(start, length, factor, offset, endian).map(SignalKind::Signed)
+        } else {
+            Unsigned::new(start, length, factor, offset, endian).map(SignalKind::Unsigned)
+        };
+
+        match result {
+            Ok(sig) => Ok(sig),
+            Err(err) => Err(SignalKindParseError::ConstructionError(err)),
+        }
+    }
+}
+
+impl TryDecode<f64> for SignalKind {
+    type Error = DecodeError;
+
+    fn try_decode<D: CANRead>(&self, data: &D) -> Result<f64, Self::Error> {
+        match self {
+            SignalKind::Unsigned(sig) => sig.try_decode(data),
+            SignalKind::Signed(sig) => sig.try_decode(data),
+        }
+    }
+}
+
+impl DefaultDecode<f64> for SignalKind {}
+impl Decode<f64> for SignalKind {}
+
+impl TryEncode<f64> for SignalKind {
+    type Error = EncodeError;
+
+    fn try_encode<D: CANWrite>(&self, data: &mut D, value: f64) -> Result<(), Self::Error> {
+        match self {
+            SignalKind::Unsigned(sig) => sig.try_encode(data, value),
+            SignalKind::Signed(sig) => sig.try_encode(data, value),
+        }
+    }
+}
+
+impl Encode<f64> for SignalKind {}
+
+/// Returns the minimal number of bytes CAN-bus data must contain to hold all `signals`. Returns
+/// `0` if `signals` is empty.
+///
+/// # Example
+/// ```
+/// use cantools::signals::{required_dlc, SignalKind};
+///
+/// let signals = ["0|8@1+ (1,0)", "13|8@1- (1,0)", "7|12@0+ (1,0)"]
+///     .into_iter()
+///     .map(|spec| spec.parse::<SignalKind>().unwrap())
+///     .collect::<Vec<_>>();
+/// assert_eq!(required_dlc(&signals), 3);
+/// ```
+pub fn required_dlc(signals: &[SignalKind]) -> usize {
+    signals
+        .iter()
+        .map(|signal| match signal {
+            SignalKind::Unsigned(sig) => required_bits(sig.start, sig.length, &sig.endian),
+            SignalKind::Signed(sig) => required_bits(sig.start, sig.length, &sig.endian),
+        })
+        .max()
+        .map_or(0, |bits| bits.div_ceil(8))
+}
+
+/// A type modeling a precompiled [Unsigned] or [Signed] signal.
+///
+/// The byte range, shift, and mask required to extract the bits of the signal are computed once
+/// during compilation. Decoding therefore only copies the relevant bytes and applies the
+/// precomputed values. The decoded values are identical to the ones of the original signal.
+#[derive(Debug, PartialEq)]
+pub struct CompiledSignal {
+    start: u16,
+    length: u16,
+    start_byte: usize,
+    byte_count: usize,
+    dlc: usize,
+    big_endian: bool,
+    signed: bool,
+    shift: u32,
+    mask: u64,
+    factor: f64,
+    offset: f64,
+}
+
+impl CompiledSignal {
+    fn new(
+        start: u16,
+        length: u16,
+        endian: &Endian,
+        factor: f64,
+        offset: f64,
+        signed: bool,
+    ) -> CompiledSignal {
+        let start_byte = start.div(8) as usize;
+        let (end_bit, shift) = match endian {
+            Endian::Little => (start as usize + length as usize - 1, start as usize % 8),
+            Endian::Big => {
+                let end_bit = (7 - start % 8) as usize + 8 * start_byte + length as usize - 1;
+                (end_bit, 7 - end_bit % 8)
+            }
+        };
+        let end_byte = end_bit.div(8);
+        let byte_count = end_byte - start_byte + 1;
+
+        CompiledSignal {
+            start,
+            length,
+            start_byte,
+            byte_count,
+            dlc: end_byte + 1,
+            big_endian: *endian == Endian::Big,
+            signed,
+            shift: shift as u32,
+            mask: u64::mask(length, 0),
+            factor,
+            offset,
+        }
+    }
+
+    /// Decodes the signal from `data` and stores the value in `out`. `out` is left untouched if
+    /// the decoding fails.
+    pub fn decode_into(&self, data: &[u8], out: &mut f64) -> Result<(), DecodeError> {
+        if data.len() < self.dlc {
+            let endian = if self.big_endian {
+                Endian::Big
+            } else {
+                Endian::Little
+            };
+            check_bounds(self.start, self.length, &endian, data.len())?;
+            return Err(DecodeError::NotEnoughData);
+        }
+
+        let bytes = &data[self.start_byte..self.start_byte + self.byte_count];
+        let bits = if self.big_endian {
+            let mut word = 0u128;
+            for byte in bytes {
+                word = (word << 8) | *byte as u128;
+            }
+            (word >> self.shift) as u64
+        } else {
+            let mut slice = [0u8; 16];
+            slice[..self.byte_count].copy_from_slice(bytes);
+            (u128::from_le_bytes(slice) >> self.shift) as u64
+        };
+        let bits = bits & self.mask;
+
+        let converted = if self.signed && bits & !(self.mask >> 1) != 0 {
+            (bits | !self.mask) as i64 as f64
+        } else if self.signed {
+            bits as i64 as f64
+        } else {
+            bits as f64
+        };
+
+        *out = converted * self.factor + self.offset;
+        Ok(())
+    }
+}
+
+impl TryDecode<f64> for CompiledSignal {
+    type Error = DecodeError;
+
+    fn try_decode<D: CANRead>(&self, data: &D) -> Result<f64, Self::Error> {
+        let mut value = 0.0;
+        let end = min(data.dlc(), data.data().len());
+        self.decode_into(&data.data()[..end], &mut value)?;
+        Ok(value)
+    }
+}
+
+impl DefaultDecode<f64> for CompiledSignal {}
+impl Decode<f64> for CompiledSignal {}
+
+/// A decoder reusing one scratch buffer for the bytes covered by the decoded signals, e.g., when
+/// decoding many [Unsigned] signals from a stream of frames in a tight loop. The results equal the
+/// ones of [try_decode](TryDecode::try_decode).
+///
+/// # Example
+/// ```
+/// use cantools::decode::TryDecode;
+/// use cantools::signals::{Decoder, Unsigned};
+/// use cantools::utils::Endian;
+///
+/// let speed = Unsigned::new(0, 16, 0.5, 0.0, Endian::Little).unwrap();
+/// let rpm = Unsigned::new(16, 16, 1.0, 0.0, Endian::Little).unwrap();
+/// let mut decoder = Decoder::new();
+///
+/// for data in [[0x10u8, 0x00, 0xE8, 0x03], [0x20, 0x00, 0xD0, 0x07]] {
+///     assert_eq!(decoder.decode(&speed, &data), speed.try_decode(&data));
+///     assert_eq!(decoder.decode(&rpm, &data), rpm.try_decode(&data));
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct Decoder {
+    scratch: [u8; 16],
+}
+
+impl Decoder {
+    /// Constructs a new decoder.
+    pub fn new() -> Decoder {
+        Decoder::default()
+    }
+
+    /// Decodes `sig` from `data` using the scratch buffer of the decoder.
+    pub fn decode<D: CANRead>(&mut self, sig: &Unsigned, data: &D) -> Result<f64, DecodeError> {
+        check_bounds(sig.start, sig.length, &sig.endian, data.dlc())?;
+
+        let raw = match sig.endian {
+            Endian::Little => {
+                little_endian_bits_in(&mut self.scratch, sig.start, sig.length, data.data())
+            }
+            Endian::Big => big_endian_bits(sig.start, sig.length, data.data()),
+        };
+
+        Ok(raw as f64 * sig.factor + sig.offset)
+    }
+}
+
+/// A trait modeling signals whose values are stored as a fixed number of bits, i.e., as raw
+/// values, see [self_check].
+pub trait RawCodec {
+    /// Returns the number of bits of the raw values. Every value below `2^raw_bits()` is a valid
+    /// raw value.
+    fn raw_bits(&self) -> u16;
+
+    /// Encodes the raw value `raw` into `data`.
+    fn try_encode_raw<D: CANWrite>(&self, data: &mut D, raw: u64) -> Result<(), EncodeError>;
+
+    /// Decodes the raw value from `data`.
+    fn try_decode_raw<D: CANRead>(&self, data: &D) -> Result<u64, DecodeError>;
+}
+
+impl RawCodec for Unsigned {
+    fn raw_bits(&self) -> u16 {
+        self.length
+    }
+
+    fn try_encode_raw<D: CANWrite>(&self, data: &mut D, raw: u64) -> Result<(), EncodeError> {
+        insert_bits(data, self.start, self.length, &self.endian, raw)
+    }
+
+    fn try_decode_raw<D: CANRead>(&self, data: &D) -> Result<u64, DecodeError> {
+        self.decode_bits(data)
+    }
+}
+
+/// The raw values are the two's complement bits up to the sign bit. The bits above the sign bit
+/// are written as zero and ignored when decoding.
+impl RawCodec for Signed {
+    fn raw_bits(&self) -> u16 {
+        self.sign_bit() + 1
+    }
+
+    fn try_encode_raw<D: CANWrite>(&self, data: &mut D, raw: u64) -> Result<(), EncodeError> {
+        let raw = raw & u64::mask(self.raw_bits(), 0);
+        insert_bits(data, self.start, self.length, &self.endian, raw)
+    }
+
+    fn try_decode_raw<D: CANRead>(&self, data: &D) -> Result<u64, DecodeError> {
+        let bits = self.decode_bits(data)? as u64;
+        Ok(bits & u64::mask(self.raw_bits(), 0))
+    }
+}
+
+/// Checks that `signal` decodes every raw value it encodes into `dlc` bytes of data, e.g., to
+/// validate the signals of an imported database at startup. Every value is encoded into zeroed
+/// data as well as into data with every bit set, such that bits written to the wrong position are
+/// detected. Returns `false` on the first mismatch or if a value cannot be encoded or decoded.
+///
+/// Signals of up to 16 bits are checked exhaustively. For longer signals, the extremes, every value
+/// with exactly one bit set, and every value with exactly one bit cleared are checked.
+///
+/// # Example
+/// ```
+/// use cantools::signals::{self_check, Signed, Unsigned};
+/// use cantools::utils::Endian;
+///
+/// assert!(self_check(&Unsigned::new(7, 12, 1.0, 0.0, Endian::Big).unwrap(), 8));
+/// assert!(self_check(&Signed::new(20, 40, 1.0, 0.0, Endian::Little).unwrap(), 8));
+///
+/// // the signal does not fit into 1 byte
+/// assert!(!self_check(&Unsigned::new(4, 8, 1.0, 0.0, Endian::Little).unwrap(), 1));
+/// ```
+pub fn self_check<S: RawCodec>(signal: &S, dlc: usize) -> bool {
+    let bits = signal.raw_bits();
+    if bits == 0 || bits > MAX_SCALAR_BITS {
+        return false;
+    }
+
+    let max = u64::mask(bits, 0);
+    let round_trips = |raw: u64| {
+        [0x00u8, 0xFF].iter().all(|&fill| {
+            let mut data = vec![fill; dlc];
+            signal.try_encode_raw(&mut data, raw).is_ok() && signal.try_decode_raw(&data) == Ok(raw)
+        })
+    };
+
+    if bits <= 16 {
+        (0..=max).all(round_trips)
+    } else {
+        let single_bits = (0..bits).map(|bit| 1u64 << bit);
+        [0, max]
+            .into_iter()
+            .chain(single_bits.clone())
+            .chain(single_bits.map(|value| max & !value))
+            .all(round_trips)
+    }
+}
+
+/// A type modeling a rolling counter.
+///
+/// The `length` number of bits represent an unsigned integer. Every call to
+/// [try_encode_next](Counter::try_encode_next) writes the current value and increments it modulo
+/// `2^length`.
+///
+/// # Example
+/// ```
+/// use cantools::signals::Counter;
+/// use cantools::utils::Endian;
+///
+/// let mut counter = Counter::new(0, 2, Endian::Little).unwrap();
+/// let mut data = [0u8];
+/// for expected in [0, 1, 2, 3, 0] {
+///     assert_eq!(counter.try_encode_next(&mut data), Ok(expected));
+///     assert_eq!(data, [expected as u8]);
+/// }
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct Counter {
+    signal: Unsigned,
+    value: u64,
+}
+
+impl Counter {
+    /// Constructs a new Counter starting at zero.
+    pub fn new(start: u16, length: u16, endian: Endian) -> Result<Counter, LengthError> {
+        let signal = Unsigned::new(start, length, 1.0, 0.0, endian)?;
+        Ok(Counter { signal, value: 0 })
+    }
+
+    /// Returns the value written by the next call to [try_encode_next](Counter::try_encode_next).
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    /// Writes the current value into `data` and increments the counter. Returns the written value.
+    ///
+    /// The counter is only incremented if the value was written successfully.
+    pub fn try_encode_next<D: CANWrite>(&mut self, data: &mut D) -> Result<u64, EncodeError> {
+        let value = self.value;
+        self.signal.try_encode(data, value as f64)?;
+        self.value = value.wrapping_add(1) & u64::mask(self.signal.length, 0);
+        Ok(value)
+    }
+
+    /// Writes the current value into `data` and increments the counter. Returns the written value.
+    ///
+    /// # Panics
+    /// Panics if the call to [try_encode_next](Counter::try_encode_next) fails.
+    pub fn encode_next<D: CANWrite>(&mut self, data: &mut D) -> u64 {
+        match self.try_encode_next(data) {
+            Ok(value) => value,
+            Err(_) => panic!("cannot encode data"),
+        }
+    }
+}
+
+impl TryDecode<u64> for Counter {
+    type Error = DecodeError;
+
+    fn try_decode<D: CANRead>(&self, data: &D) -> Result<u64, Self::Error> {
+        self.signal.decode_bits(data)
+    }
+}
+
+impl DefaultDecode<u64> for Counter {}
+impl Decode<u64> for Counter {}
+
+/// A type modeling a checksum occupying one byte of the CAN-bus data.
+///
+/// The checksum is computed by `algorithm` over all bytes except the checksum byte itself, in
+/// their original order.
+///
+/// # Example
+/// ```
+/// use cantools::signals::Checksum;
+///
+/// fn xor(data: &[u8]) -> u8 {
+///     data.iter().fold(0, |acc, byte| acc ^ byte)
+/// }
+///
+/// let checksum = Checksum::new(3, xor);
+/// let mut data = [0x01u8, 0x02, 0x04, 0x00];
+/// assert_eq!(checksum.try_update(&mut data), Ok(0x07));
+/// assert_eq!(data, [0x01, 0x02, 0x04, 0x07]);
+/// assert_eq!(checksum.verify(&data), Ok(true));
+/// ```
+#[derive(Debug)]
+pub struct Checksum {
+    byte: usize,
+    algorithm: fn(&[u8]) -> u8,
+}
+
+impl Checksum {
+    /// Constructs a new Checksum located at byte `byte` and computed by `algorithm`.
+    pub fn new(byte: usize, algorithm: fn(&[u8]) -> u8) -> Checksum {
+        Checksum { byte, algorithm }
+    }
+
+    /// Computes the checksum over the first `dlc` bytes of `data` except the checksum byte.
+    pub fn compute<D: CANRead>(&self, data: &D) -> Result<u8, DecodeError> {
+        if self.byte >= data.dlc() {
+            return Err(DecodeError::NotEnoughData);
+        }
+
+        let other = data
+            .data()
+            .iter()
+            .take(data.dlc())
+            .enumerate()
+            .filter(|(i, _)| *i != self.byte)
+            .map(|(_, byte)| *byte)
+            .collect::<Vec<_>>();
+        Ok((self.algorithm)(&other))
+    }
+
+    /// Recomputes the checksum and writes it into `data`. Returns the written checksum.
+    pub fn try_update<D: CANWrite>(&self, data: &mut D) -> Result<u8, EncodeError> {
+        let checksum = match self.compute(data) {
+            Ok(checksum) => checksum,
+            Err(_) => return Err(EncodeError::NotEnoughData),
+        };
+        for i in 0..8 {
+            let bit = 8 * self.byte as u16 + i;
+            data.set_bit(bit, (checksum >> i) & 1 != 0)?;
+        }
+        Ok(checksum)
+    }
+
+    /// Checks whether the checksum byte of `data` matches the computed checksum.
+    pub fn verify<D: CANRead>(&self, data: &D) -> Result<bool, DecodeError> {
+        let checksum = self.compute(data)?;
+        let byte: u8 = self.try_decode(data)?;
+        Ok(byte == checksum)
+    }
+}
+
+impl TryDecode<u8> for Checksum {
+    type Error = DecodeError;
+
+    fn try_decode<D: CANRead>(&self, data: &D) -> Result<u8, Self::Error> {
+        match data.data().get(self.byte) {
+            Some(byte) if self.byte < data.dlc() => Ok(*byte),
+            _ => Err(DecodeError::NotEnoughData),
+        }
+    }
+}
+
+impl DefaultDecode<u8> for Checksum {}
+impl Decode<u8> for Checksum {}
+
+/// A type modeling a multiplexed signal.
+///
+/// A multiplexed signal is only present if the raw value of its multiplexor signal equals the
+/// `multiplex_value`. Otherwise, the bits of the signal belong to another layout. Decoding yields
+/// `None` in this case. Encoding writes the `multiplex_value` into the multiplexor before encoding
+/// the signal itself.
+///
+/// # Example
+/// ```
+/// use cantools::signals::{Multiplexed, Unsigned};
+/// use cantools::decode::TryDecode;
+/// use cantools::utils::Endian;
+///
+/// let multiplexor = Unsigned::new(0, 8, 1.0, 0.0, Endian::Little).unwrap();
+/// let sig = Unsigned::new(8, 8, 1.0, 0.0, Endian::Little).unwrap();
+/// let multiplexed = Multiplexed::new(&multiplexor, 1, sig);
+///
+/// assert_eq!(multiplexed.try_decode(&[1u8, 42u8]), Ok(Some(42.0)));
+/// assert_eq!(multiplexed.try_decode(&[2u8, 42u8]), Ok(None));
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct Multiplexed<'a, S> {
+    multiplexor: &'a Unsigned,
+    multiplex_value: u64,
+    signal: S,
+}
+
+impl<'a, S> Multiplexed<'a, S> {
+    /// Constructs a new Multiplexed signal that is present if `multiplexor` equals
+    /// `multiplex_value`.
+    pub fn new(multiplexor: &'a Unsigned, multiplex_value: u64, signal: S) -> Multiplexed<'a, S> {
+        Multiplexed {
+            multiplexor,
+            multiplex_value,
+            signal,
+        }
+    }
+
+    /// Returns the multiplexor signal.
+    pub fn multiplexor(&self) -> &Unsigned {
+        self.multiplexor
+    }
+
+    /// Returns the raw multiplexor value selecting the signal.
+    pub fn multiplex_value(&self) -> u64 {
+        self.multiplex_value
+    }
+
+    /// Returns the wrapped signal.
+    pub fn signal(&self) -> &S {
+        &self.signal
+    }
+
+    /// Checks whether the multiplexor in `data` currently selects the signal.
+    pub fn is_active<D: CANRead>(&self, data: &D) -> Result<bool, DecodeError> {
+        Ok(self.multiplexor.decode_bits(data)? == self.multiplex_value)
+    }
+}
+
+impl<'a, T, S> TryDecode<Option<T>> for Multiplexed<'a, S>
+where
+    S: TryDecode<T, Error = DecodeError>,
+{
+    type Error = DecodeError;
+
+    fn try_decode<D: CANRead>(&self, data: &D) -> Result<Option<T>, Self::Error> {
+        if self.is_active(data)? {
+            Ok(Some(self.signal.try_decode(data)?))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl<'a, T, S> DefaultDecode<Option<T>> for Multiplexed<'a, S> where
+    S: TryDecode<T, Error = DecodeError>
+{
+}
+impl<'a, T, S> Decode<Option<T>> for Multiplexed<'a, S> where S: TryDecode<T, Error = DecodeError> {}
+
+impl<'a, T, S> TryEncode<T> for Multiplexed<'a, S>
+where
+    S: TryEncode<T, Error = EncodeError>,
+{
+    type Error = EncodeError;
+
+    fn try_encode<D: CANWrite>(&self, data: &mut D, value: T) -> Result<(), Self::Error> {
+        // the raw selector is written as is; scaling it to a physical value and back would
+        // truncate for non-integer factors and offsets
+        if self.multiplex_value > u64::mask(self.multiplexor.length, 0) {
+            return Err(EncodeError::MaxError);
+        }
+        self.multiplexor
+            .try_encode_raw(data, self.multiplex_value)?;
+        self.signal.try_encode(data, value)
+    }
+}
+
+impl<'a, T, S> Encode<T> for Multiplexed<'a, S> where S: TryEncode<T, Error = EncodeError> {}
+
+// #[derive(Debug,PartialEq)]
+// pub struct Float32 {
+//     start: u16,
 //     factor: f32,
 //     offset: f32,
 //     endian: Endian
@@ -961,457 +3113,1679 @@ impl Encode<f64> for Signed {}
 //     }
 // }
 
-#[cfg(test)]
-mod tests {
-    use crate::decode::TryDecode;
-    use crate::encode::{Encode, EncodeError, TryEncode};
-    use crate::utils::{Endian, Mask};
-    // use crate::signals::{Bit, Unsigned, Raw, DataError, Float32, Signed};
-    use crate::signals::{Bit, DecodeError, Max, Min, Signed, Unsigned};
+#[cfg(test)]
+mod tests {
+    use crate::data::{CANRead, CANWrite};
+    use crate::decode::{DecodedValue, EnumDecodeError, Physical, Raw, TryDecode};
+    use crate::encode::{Encode, EncodeError, TryEncode};
+    use crate::utils::{Endian, Mask};
+    use crate::values::ValueTable;
+    // use crate::signals::{Bit, Unsigned, Raw, DataError, Float32, Signed};
+    use crate::signals::{
+        decimal_places, required_dlc, self_check, Bit, Bytes, Checksum, Counter, DecodeError,
+        Decoder, LengthError, Max, Min, Multiplexed, RawCodec, SignalKind, SignalKindParseError,
+        Signed, SignedBuilder, Text, Unsigned, UnsignedBuilder, MAX_SCALAR_BITS,
+    };
+
+    #[test]
+    fn test_unsigned_001() {
+        let sig = Unsigned::new(1, 64, 1.0, 0.0, Endian::Little);
+        assert!(sig.is_ok())
+    }
+
+    #[test]
+    fn test_unsigned_002() {
+        let sig = Unsigned::new(1, 65, 1.0, 0.0, Endian::Little);
+        assert!(sig.is_err())
+    }
+
+    #[test]
+    fn test_unsigned_003() {
+        let sig = Unsigned::new(1, 0, 1.0, 0.0, Endian::Little);
+        assert!(sig.is_err())
+    }
+
+    #[test]
+    fn test_decode_bit_001() {
+        let bit = Bit::new(1);
+        let data = [0b1111_0010u8];
+
+        let decode = bit.try_decode(&data);
+        assert_eq!(decode, Result::Ok(true));
+    }
+
+    #[test]
+    fn test_decode_bit_002() {
+        let bit = Bit::new(0);
+        let data = [0b1111_0010u8];
+
+        let decode = bit.try_decode(&data);
+        assert_eq!(decode, Result::Ok(false));
+    }
+
+    #[test]
+    fn test_decode_bit_003() {
+        for i in 0..8 {
+            let bit = Bit::new(i);
+            let data = [u8::mask(1, i)];
+            let decode = bit.try_decode(&data);
+            assert_eq!(decode, Result::Ok(true));
+        }
+    }
+
+    #[test]
+    fn test_decode_bit_004() {
+        for i in 8..64 {
+            let bit = Bit::new(i);
+            let data = [0xFFu8];
+            let decode: Result<bool, _> = bit.try_decode(&data);
+            assert_eq!(
+                decode,
+                Err(DecodeError::StartOutOfRange { start: i, dlc: 1 })
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_bit_005() {
+        let data = [0b1111_0010u8];
+
+        let decode: Result<bool, _> = Bit::new(1).try_decode(&data);
+        assert_eq!(decode, Ok(true));
+        let decode: Result<f64, _> = Bit::new(1).try_decode(&data);
+        assert_eq!(decode, Ok(1.0));
+
+        let decode: Result<bool, _> = Bit::new(0).try_decode(&data);
+        assert_eq!(decode, Ok(false));
+        let decode: Result<f64, _> = Bit::new(0).try_decode(&data);
+        assert_eq!(decode, Ok(0.0));
+
+        let decode: Result<f64, _> = Bit::new(8).try_decode(&data);
+        assert_eq!(
+            decode,
+            Err(DecodeError::StartOutOfRange { start: 8, dlc: 1 })
+        );
+    }
+
+    #[test]
+    fn test_min_max_bit_001() {
+        let bit = Bit::new(42);
+        assert_eq!(bit.min(), 0.0);
+        assert_eq!(bit.max(), 1.0);
+    }
+
+    /* TEST ENCODE BIT */
+
+    #[test]
+    fn test_encode_bit_001() {
+        for i in 0..8 {
+            let bit = Bit::new(i);
+            let data = [u8::mask(1, i)];
+            let mut data_to_encode = [0u8];
+            let result = bit.try_encode(&mut data_to_encode, true);
+
+            assert_eq!(result, Ok(()));
+            assert_eq!(data, data_to_encode);
+        }
+    }
+
+    #[test]
+    fn test_encode_bit_002() {
+        for i in 0..8 {
+            let bit = Bit::new(i);
+            let data = [u8::mask(1, i)];
+            let mut data_to_encode = [0u8];
+            Encode::encode(&bit, &mut data_to_encode, true);
+
+            assert_eq!(data, data_to_encode);
+        }
+    }
+
+    #[test]
+    fn test_encode_bit_003() {
+        for i in 0..8 {
+            let bit = Bit::new(i);
+            let data = [!u8::mask(1, i)];
+            let mut data_to_encode = [0xFFu8];
+            let result = bit.try_encode(&mut data_to_encode, false);
+
+            assert_eq!(result, Ok(()));
+            assert_eq!(data, data_to_encode);
+        }
+    }
+
+    #[test]
+    fn test_encode_bit_004() {
+        for i in 0..8 {
+            let bit = Bit::new(i);
+            let data = [!u8::mask(1, i)];
+            let mut data_to_encode = [0xFFu8];
+            let result = bit.try_encode(&mut data_to_encode, false);
+
+            assert_eq!(result, Ok(()));
+            assert_eq!(data, data_to_encode);
+        }
+    }
+
+    #[test]
+    fn test_encode_bit_005() {
+        for i in 8..64 {
+            let bit = Bit::new(i);
+            let mut data_to_encode = [0xFFu8];
+            let result = bit.try_encode(&mut data_to_encode, false);
+
+            assert!(result.is_err());
+            assert_eq!(result, Err(EncodeError::NotEnoughData))
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_encode_bit_006() {
+        for i in 8..64 {
+            let bit = Bit::new(i);
+            let mut data_to_encode = [0xFFu8];
+            bit.encode(&mut data_to_encode, false);
+        }
+    }
+
+    #[test]
+    fn test_decode_unsigned_001() {
+        let bit = Unsigned::new(0, 8, 1.0, 0.0, Endian::Little).unwrap();
+        let data = [255u8, 0, 0, 0, 0, 0, 0, 0];
+
+        let decode = bit.try_decode(&data);
+        println!("{:?}", &decode);
+        assert_eq!(decode, Result::Ok(255f64));
+    }
+
+    #[test]
+    fn test_decode_unsigned_002() {
+        let bit = Unsigned::new(7, 8, 1.0, 0.0, Endian::Big).unwrap();
+        let data = [255u8, 0, 0, 0, 0, 0, 0, 0];
+
+        let decode = bit.try_decode(&data);
+        println!("{:?}", &decode);
+        assert_eq!(decode, Result::Ok(255f64));
+    }
+
+    #[test]
+    fn test_decode_unsigned_004() {
+        let bit = Unsigned::new(4, 8, 1.0, 0.0, Endian::Little).unwrap();
+        let data = [0b11110000, 0b00001111, 0, 0, 0, 0, 0, 0];
+
+        let decode = bit.try_decode(&data);
+        println!("{:?}", &decode);
+        assert_eq!(decode, Result::Ok(255f64));
+    }
+
+    #[test]
+    fn test_decode_unsigned_005() {
+        let bit = Unsigned::new(4, 8, 2.0, 1337.0, Endian::Little).unwrap();
+        let data = [0b11110000, 0b00001111, 0, 0, 0, 0, 0, 0];
+
+        let decode = bit.try_decode(&data);
+        println!("{:?}", &decode);
+        assert_eq!(decode, Result::Ok(255f64 * 2.0 + 1337.0));
+    }
+
+    #[test]
+    fn test_decode_unsigned_006() {
+        let sig = Unsigned::new(3, 8, 2.0, 1337.0, Endian::Big).unwrap();
+        let data = [0b0000_1111, 0b00001111, 0, 0, 0, 0, 0, 0];
+
+        let decode = sig.try_decode(&data);
+        println!("{:?}", &decode);
+        assert_eq!(decode, Result::Ok((0b1111_0000 as f64 * 2.0) + 1337.0));
+    }
+
+    #[test]
+    fn test_decode_unsigned_007() {
+        let sig = Unsigned::new(0, 9, 2.0, 1337.0, Endian::Little).unwrap();
+        let data = [0b0000_1111];
+
+        let decode = sig.try_decode(&data);
+        println!("{:?}", &decode);
+        assert_eq!(
+            decode,
+            Result::Err(DecodeError::LengthExceedsBuffer {
+                needed: 9,
+                available: 8
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_unsigned_008() {
+        let sig = Unsigned::new(6, 8, 2.0, 1337.0, Endian::Big).unwrap();
+        let data = [0b0000_1111];
+
+        let decode = sig.try_decode(&data);
+        println!("{:?}", &decode);
+        assert_eq!(
+            decode,
+            Result::Err(DecodeError::LengthExceedsBuffer {
+                needed: 9,
+                available: 8
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_unsigned_min_max_001() {
+        let sig = Unsigned::new(6, 8, 2.0, 1337.0, Endian::Little).unwrap();
+        assert_eq!(sig.min(), 1337.0);
+        assert_eq!(sig.max(), (255.0 * 2.0) + 1337.0);
+    }
+
+    #[test]
+    fn test_decode_unsigned_min_max_002() {
+        let sig = Unsigned::new(6, 8, 1.0, 0.0, Endian::Little).unwrap();
+        assert_eq!(sig.min(), 0.0);
+        assert_eq!(sig.max(), 255.0);
+    }
+
+    #[test]
+    fn test_decode_unsigned_min_max_003() {
+        let sig = Unsigned::new(6, 16, 1.0, 0.0, Endian::Little).unwrap();
+        assert_eq!(sig.min(), 0.0);
+        assert_eq!(sig.max(), 256.0 * 256.0 - 1.0);
+    }
+
+    #[test]
+    fn test_encode_unsigned_001() {
+        let unsigned = Unsigned::new(0, 8, 1.0, 0.0, Endian::Little).unwrap();
+        for i in 0..=255u8 {
+            let mut data = [0u8];
+
+            let result = unsigned.try_encode(&mut data, i as f64);
+            assert!(result.is_ok());
+            assert_eq!(data, [i])
+        }
+    }
+
+    #[test]
+    fn test_encode_unsigned_002() {
+        let unsigned = Unsigned::new(3, 8, 1.0, 0.0, Endian::Big).unwrap();
+        let mut data = [0u8, 0u8];
+
+        let result = unsigned.try_encode(&mut data, 255.0);
+        assert!(result.is_ok());
+        assert_eq!(data, [0x0Fu8, 0xF0u8]);
+    }
+
+    #[test]
+    fn test_encode_unsigned_004() {
+        let unsigned = Unsigned::new(2, 8, 1.0, 0.0, Endian::Big).unwrap();
+        let mut data = [0u8, 0u8];
+
+        let result = unsigned.try_encode(&mut data, 255.0);
+        assert!(result.is_ok());
+        assert_eq!(data, [0b0000_0111u8, 0b1111_1000u8]);
+    }
+
+    #[test]
+    fn test_encode_unsigned_005() {
+        let unsigned = Unsigned::new(3, 8, 1.0, 0.0, Endian::Big).unwrap();
+        let mut data = [0xA0u8, 0x0Bu8];
+
+        let result = unsigned.try_encode(&mut data, 255.0);
+        assert!(result.is_ok());
+        assert_eq!(data, [0xAFu8, 0xFBu8]);
+    }
+
+    #[test]
+    fn test_encode_unsigned_006() {
+        let unsigned = Unsigned::new(3, 8, 1.0, 0.0, Endian::Big).unwrap();
+        let mut data = [0xA0u8, 0x0Bu8];
+
+        let result = unsigned.try_encode(&mut data, 254_f64);
+        assert!(result.is_ok());
+        assert_eq!(data, [0xAFu8, 0xEBu8]);
+    }
+
+    /* TEST SIGNED */
+
+    #[test]
+    fn test_decode_signed_001() {
+        let sig = Signed::new(0, 8, 1.0, 0.0, Endian::Little).unwrap();
+        let data = [0b0111_1111];
+
+        let decode = sig.try_decode(&data);
+        println!("{:?}", &decode);
+        assert_eq!(decode, Result::Ok(127.0));
+    }
+
+    #[test]
+    fn test_decode_signed_002() {
+        let sig = Signed::new(0, 8, 1.0, 0.0, Endian::Little).unwrap();
+        let data = [0b1000_0000];
+
+        let decode = sig.try_decode(&data);
+        println!("{:?}", &decode);
+        assert_eq!(decode, Result::Ok(-128.0));
+    }
+
+    #[test]
+    fn test_decode_signed_003() {
+        let sig = Signed::new(3, 8, 1.0, 0.0, Endian::Big).unwrap();
+        let data = [0b0000_0111, 0b1111_0000];
+
+        let decode = sig.try_decode(&data);
+        println!("{:?}", &decode);
+        assert_eq!(decode, Result::Ok(127.0));
+    }
+
+    #[test]
+    fn test_decode_signed_004() {
+        let sig = Signed::new(3, 8, 1.0, 0.0, Endian::Big).unwrap();
+        let data = [0b0000_1000, 0b0000_1111];
+
+        let decode = sig.try_decode(&data);
+        println!("{:?}", &decode);
+        assert_eq!(decode, Result::Ok(-128.0));
+    }
+
+    #[test]
+    fn test_decode_signed_005() {
+        let sig = Signed::new(0, 8, 42.0, 1337.0, Endian::Little).unwrap();
+        let data = [0b0111_1111];
+
+        let decode = sig.try_decode(&data);
+        println!("{:?}", &decode);
+        assert_eq!(decode, Result::Ok(127.0 * 42.0 + 1337.0));
+    }
+
+    #[test]
+    fn test_decode_signed_006() {
+        let sig = Signed::new(3, 8, 42.0, 1337.0, Endian::Big).unwrap();
+        let data = [0b0000_0111, 0b1111_0000];
+
+        let decode = sig.try_decode(&data);
+        println!("{:?}", &decode);
+        assert_eq!(decode, Result::Ok(127.0 * 42.0 + 1337.0));
+    }
+
+    #[test]
+    fn test_decode_signed_007() {
+        let sig = Signed::new(1, 8, 42.0, 1337.0, Endian::Little).unwrap();
+        let data = [0b0000_0111];
+
+        let decode = sig.try_decode(&data);
+        println!("{:?}", &decode);
+        assert_eq!(
+            decode,
+            Result::Err(DecodeError::LengthExceedsBuffer {
+                needed: 9,
+                available: 8
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_signed_008() {
+        let sig = Signed::new(6, 8, 42.0, 1337.0, Endian::Big).unwrap();
+        let data = [0b0000_0111];
+
+        let decode = sig.try_decode(&data);
+        println!("{:?}", &decode);
+        assert_eq!(
+            decode,
+            Result::Err(DecodeError::LengthExceedsBuffer {
+                needed: 9,
+                available: 8
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_signed_min_max_001() {
+        let sig = Signed::new(6, 8, 2.0, 1337.0, Endian::Little).unwrap();
+        assert_eq!(sig.min(), -128.0 * 2.0 + 1337.0);
+        assert_eq!(sig.max(), 127.0 * 2.0 + 1337.0);
+    }
+
+    #[test]
+    fn test_encode_signed_001() {
+        let unsigned = Signed::new(0, 8, 1.0, 0.0, Endian::Little).unwrap();
+        for i in 0..=127u8 {
+            let mut data = [0u8];
+
+            let result = unsigned.try_encode(&mut data, i as f64);
+            assert!(result.is_ok());
+            assert_eq!(data, [i])
+        }
+    }
+
+    #[test]
+    fn test_encode_signed_002() {
+        let unsigned = Signed::new(0, 8, 1.0, 0.0, Endian::Little).unwrap();
+        let mut data = [0u8];
+
+        let result = unsigned.try_encode(&mut data, -128_f64);
+        assert!(result.is_ok());
+        assert_eq!(data, [0b1000_0000u8])
+    }
 
     #[test]
-    fn test_unsigned_001() {
-        let sig = Unsigned::new(1, 64, 1.0, 0.0, Endian::Little);
-        assert!(sig.is_ok())
+    fn test_encode_signed_003() {
+        let unsigned = Signed::new(0, 8, 1.0, 0.0, Endian::Little).unwrap();
+        let mut data = [0u8];
+
+        let result = unsigned.try_encode(&mut data, -1_f64);
+        assert!(result.is_ok());
+        assert_eq!(data, [0b1111_1111])
     }
 
     #[test]
-    fn test_unsigned_002() {
-        let sig = Unsigned::new(1, 65, 1.0, 0.0, Endian::Little);
-        assert!(sig.is_err())
+    fn test_encode_signed_004() {
+        let unsigned = Signed::new(0, 8, 1.0, 0.0, Endian::Little).unwrap();
+        let mut data = [0u8];
+
+        let result = unsigned.try_encode(&mut data, 128_f64);
+        assert!(result.is_err());
+        assert_eq!(result, Err(EncodeError::MaxError));
     }
 
     #[test]
-    fn test_unsigned_003() {
-        let sig = Unsigned::new(1, 0, 1.0, 0.0, Endian::Little);
-        assert!(sig.is_err())
+    fn test_encode_signed_005() {
+        let unsigned = Signed::new(0, 8, 1.0, 0.0, Endian::Little).unwrap();
+        let mut data = [0u8];
+
+        let result = unsigned.try_encode(&mut data, -129_f64);
+        assert!(result.is_err());
+        assert_eq!(result, Err(EncodeError::MinError));
     }
 
     #[test]
-    fn test_decode_bit_001() {
-        let bit = Bit::new(1);
-        let data = [0b1111_0010u8];
+    fn test_encode_signed_006() {
+        let unsigned = Signed::new(0, 8, 1.0, 0.0, Endian::Little).unwrap();
+        let mut data = [];
 
-        let decode = bit.try_decode(&data);
-        assert_eq!(decode, Result::Ok(true));
+        let result = unsigned.try_encode(&mut data, -128_f64);
+        assert!(result.is_err());
+        assert_eq!(result, Err(EncodeError::NotEnoughData));
     }
 
     #[test]
-    fn test_decode_bit_002() {
-        let bit = Bit::new(0);
-        let data = [0b1111_0010u8];
+    fn test_encode_signed_007() {
+        let unsigned = Signed::new(3, 8, 1.0, 0.0, Endian::Big).unwrap();
+        let mut data = [0u8, 0u8];
 
-        let decode = bit.try_decode(&data);
-        assert_eq!(decode, Result::Ok(false));
+        let result = unsigned.try_encode(&mut data, 127_f64);
+        assert!(result.is_ok());
+        assert_eq!(data, [0b0000_0111u8, 0b1111_0000u8]);
     }
 
     #[test]
-    fn test_decode_bit_003() {
-        for i in 0..8 {
-            let bit = Bit::new(i);
-            let data = [u8::mask(1, i)];
-            let decode = bit.try_decode(&data);
-            assert_eq!(decode, Result::Ok(true));
-        }
+    fn test_encode_signed_008() {
+        let signed = Signed::new(3, 8, 1.0, 0.0, Endian::Big).unwrap();
+        let mut data = [0b0000_0000u8, 0b0000_0000u8];
+
+        let result = signed.try_encode(&mut data, -128_f64);
+        assert!(result.is_ok());
+        assert_eq!(data, [0b0000_1000u8, 0b0000_0000u8]);
     }
 
-    /* TEST ENCODE BIT */
+    #[test]
+    fn test_encode_signed_009() {
+        let signed = Signed::new(3, 8, 1.0, 0.0, Endian::Big).unwrap();
+        let mut data = [0b1000_0000u8, 0b0000_0001u8];
+
+        let result = signed.try_encode(&mut data, -1_f64);
+        assert!(result.is_ok());
+        assert_eq!(data, [0b1000_1111u8, 0b1111_0001u8]);
+    }
 
     #[test]
-    fn test_encode_bit_001() {
-        for i in 0..8 {
-            let bit = Bit::new(i);
-            let data = [u8::mask(1, i)];
-            let mut data_to_encode = [0u8];
-            let result = bit.try_encode(&mut data_to_encode, true);
+    fn test_validate_big_endian_001() {
+        let unsigned = Unsigned::new(3, 12, 1.0, 0.0, Endian::Big).unwrap();
+        let signed = Signed::new(3, 12, 1.0, 0.0, Endian::Big).unwrap();
+        assert_eq!(unsigned.validate(2), Ok(()));
+        assert_eq!(signed.validate(2), Ok(()));
+    }
 
-            assert_eq!(result, Ok(()));
-            assert_eq!(data, data_to_encode);
-        }
+    #[test]
+    fn test_validate_big_endian_002() {
+        let unsigned = Unsigned::new(3, 13, 1.0, 0.0, Endian::Big).unwrap();
+        let signed = Signed::new(3, 13, 1.0, 0.0, Endian::Big).unwrap();
+        assert_eq!(
+            unsigned.validate(2),
+            Err(DecodeError::LengthExceedsBuffer {
+                needed: 17,
+                available: 16
+            })
+        );
+        assert_eq!(
+            signed.validate(2),
+            Err(DecodeError::LengthExceedsBuffer {
+                needed: 17,
+                available: 16
+            })
+        );
     }
 
     #[test]
-    fn test_encode_bit_002() {
-        for i in 0..8 {
-            let bit = Bit::new(i);
-            let data = [u8::mask(1, i)];
-            let mut data_to_encode = [0u8];
-            Encode::encode(&bit, &mut data_to_encode, true);
+    fn test_validate_little_endian_001() {
+        let sig = Unsigned::new(4, 12, 1.0, 0.0, Endian::Little).unwrap();
+        assert_eq!(sig.validate(2), Ok(()));
+        assert_eq!(
+            sig.validate(1),
+            Err(DecodeError::LengthExceedsBuffer {
+                needed: 16,
+                available: 8
+            })
+        );
+    }
 
-            assert_eq!(data, data_to_encode);
-        }
+    #[test]
+    fn test_validate_start_out_of_range_001() {
+        let unsigned = Unsigned::new(16, 4, 1.0, 0.0, Endian::Little).unwrap();
+        let signed = Signed::new(23, 4, 1.0, 0.0, Endian::Big).unwrap();
+        assert_eq!(
+            unsigned.validate(2),
+            Err(DecodeError::StartOutOfRange { start: 16, dlc: 2 })
+        );
+        assert_eq!(
+            signed.validate(2),
+            Err(DecodeError::StartOutOfRange { start: 23, dlc: 2 })
+        );
+        assert_eq!(unsigned.validate(3), Ok(()));
+        assert_eq!(signed.validate(3), Ok(()));
     }
 
     #[test]
-    fn test_encode_bit_003() {
-        for i in 0..8 {
-            let bit = Bit::new(i);
-            let data = [!u8::mask(1, i)];
-            let mut data_to_encode = [0xFFu8];
-            let result = bit.try_encode(&mut data_to_encode, false);
+    fn test_builder_unsigned_001() {
+        let sig = UnsignedBuilder::new()
+            .start(3)
+            .length(12)
+            .factor(42.0)
+            .offset(1337.0)
+            .endian(Endian::Big)
+            .build();
+        assert_eq!(sig, Unsigned::new(3, 12, 42.0, 1337.0, Endian::Big));
+    }
 
-            assert_eq!(result, Ok(()));
-            assert_eq!(data, data_to_encode);
-        }
+    #[test]
+    fn test_builder_unsigned_002() {
+        assert_eq!(Unsigned::builder().build(), Ok(Unsigned::default()));
+        assert_eq!(
+            Unsigned::builder().length(0).build(),
+            Err(LengthError::LengthZero)
+        );
     }
 
     #[test]
-    fn test_encode_bit_004() {
-        for i in 0..8 {
-            let bit = Bit::new(i);
-            let data = [!u8::mask(1, i)];
-            let mut data_to_encode = [0xFFu8];
-            let result = bit.try_encode(&mut data_to_encode, false);
+    fn test_raw_range_001() {
+        let sig = Unsigned::new(0, 8, 0.5, 10.0, Endian::Little).unwrap();
+        assert_eq!((sig.raw_min(), sig.raw_max()), (0, 255));
+        let sig = Unsigned::new(0, 1, 1.0, 0.0, Endian::Little).unwrap();
+        assert_eq!((sig.raw_min(), sig.raw_max()), (0, 1));
+        let sig = Unsigned::new(0, 64, 1.0, 0.0, Endian::Little).unwrap();
+        assert_eq!((sig.raw_min(), sig.raw_max()), (0, i64::MAX));
+    }
 
-            assert_eq!(result, Ok(()));
-            assert_eq!(data, data_to_encode);
-        }
+    #[test]
+    fn test_raw_range_002() {
+        let sig = Signed::new(0, 8, 0.5, 10.0, Endian::Little).unwrap();
+        assert_eq!((sig.raw_min(), sig.raw_max()), (-128, 127));
+        let sig = Signed::new(0, 1, 1.0, 0.0, Endian::Little).unwrap();
+        assert_eq!((sig.raw_min(), sig.raw_max()), (-1, 0));
+        let sig = Signed::new(0, 64, 1.0, 0.0, Endian::Little).unwrap();
+        assert_eq!((sig.raw_min(), sig.raw_max()), (i64::MIN, i64::MAX));
     }
 
     #[test]
-    fn test_encode_bit_005() {
-        for i in 8..64 {
-            let bit = Bit::new(i);
-            let mut data_to_encode = [0xFFu8];
-            let result = bit.try_encode(&mut data_to_encode, false);
+    fn test_non_finite_range_001() {
+        assert_eq!(
+            Unsigned::new(0, 8, f64::MAX, 0.0, Endian::Little),
+            Err(LengthError::NonFiniteRange)
+        );
+        assert_eq!(
+            Signed::new(0, 8, f64::MAX, 0.0, Endian::Little),
+            Err(LengthError::NonFiniteRange)
+        );
+        assert_eq!(
+            Unsigned::new(0, 8, 1.0, f64::NAN, Endian::Little),
+            Err(LengthError::NonFiniteRange)
+        );
+        assert_eq!(
+            Signed::builder().factor(f64::INFINITY).build(),
+            Err(LengthError::NonFiniteRange)
+        );
+        assert!(Unsigned::new(0, 1, f64::MAX, 0.0, Endian::Little).is_ok());
+        assert!(Signed::new(0, 64, 1.0, 0.0, Endian::Little).is_ok());
+    }
 
-            assert!(result.is_err());
-            assert_eq!(result, Err(EncodeError::NotEnoughData))
-        }
+    #[test]
+    fn test_accessors_001() {
+        let sig = Unsigned::new(3, 12, 42.0, 1337.0, Endian::Big).unwrap();
+        assert_eq!(sig.start(), 3);
+        assert_eq!(sig.length(), 12);
+        assert_eq!(sig.factor(), 42.0);
+        assert_eq!(sig.offset(), 1337.0);
+        assert_eq!(sig.endian(), &Endian::Big);
+
+        let sig = Signed::new(5, 7, -0.5, 10.0, Endian::Little).unwrap();
+        assert_eq!(sig.start(), 5);
+        assert_eq!(sig.length(), 7);
+        assert_eq!(sig.factor(), -0.5);
+        assert_eq!(sig.offset(), 10.0);
+        assert_eq!(sig.endian(), &Endian::Little);
     }
 
     #[test]
-    #[should_panic]
-    fn test_encode_bit_006() {
-        for i in 8..64 {
-            let bit = Bit::new(i);
-            let mut data_to_encode = [0xFFu8];
-            bit.encode(&mut data_to_encode, false);
-        }
+    fn test_decode_checked_001() {
+        assert_eq!(
+            Unsigned::new(0, 64, 1e300, 0.0, Endian::Little),
+            Err(LengthError::NonFiniteRange)
+        );
+
+        let sig = Unsigned::new(0, 64, 1e288, 0.0, Endian::Little).unwrap();
+        let data = [0xFFu8; 8];
+        assert_eq!(sig.try_decode_checked(&data), sig.try_decode(&data));
+        assert!(sig.try_decode_checked(&data).unwrap().is_finite());
+
+        let sig = Signed::new(0, 64, 1e288, 0.0, Endian::Little).unwrap();
+        let data = [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80];
+        assert_eq!(sig.try_decode_checked(&data), sig.try_decode(&data));
+        assert_eq!(
+            sig.try_decode_checked(&[0u8; 7]),
+            Err(DecodeError::LengthExceedsBuffer {
+                needed: 64,
+                available: 56
+            })
+        );
     }
 
     #[test]
-    fn test_decode_unsigned_001() {
-        let bit = Unsigned::new(0, 8, 1.0, 0.0, Endian::Little).unwrap();
-        let data = [255u8, 0, 0, 0, 0, 0, 0, 0];
+    fn test_builder_signed_001() {
+        let sig = SignedBuilder::new()
+            .start(3)
+            .length(12)
+            .factor(42.0)
+            .offset(1337.0)
+            .endian(Endian::Big)
+            .build();
+        assert_eq!(sig, Signed::new(3, 12, 42.0, 1337.0, Endian::Big));
+    }
 
-        let decode = bit.try_decode(&data);
-        println!("{:?}", &decode);
-        assert_eq!(decode, Result::Ok(255f64));
+    #[test]
+    fn test_builder_signed_002() {
+        let sig = Signed::builder()
+            .name("temperature")
+            .unit("°C")
+            .build()
+            .unwrap();
+        assert_eq!(sig.name(), Some("temperature"));
+        assert_eq!(sig.unit(), Some("°C"));
+        assert_eq!(Signed::default().name(), None);
+        assert_eq!(Signed::default().unit(), None);
     }
 
     #[test]
-    fn test_decode_unsigned_002() {
-        let bit = Unsigned::new(7, 8, 1.0, 0.0, Endian::Big).unwrap();
-        let data = [255u8, 0, 0, 0, 0, 0, 0, 0];
+    fn test_decode_full_001() {
+        let mut table = ValueTable::new();
+        table.insert(2, "Error");
+        table.insert(3, "Not available");
+        let sig = Unsigned::builder()
+            .start(4)
+            .length(2)
+            .factor(0.5)
+            .offset(1.0)
+            .value_table(table)
+            .build()
+            .unwrap();
+
+        let value = sig.decode_full(&[0b0011_0000u8]).unwrap();
+        assert_eq!(
+            value,
+            DecodedValue {
+                raw: 3,
+                physical: 2.5,
+                label: Some(String::from("Not available")),
+            }
+        );
 
-        let decode = bit.try_decode(&data);
-        println!("{:?}", &decode);
-        assert_eq!(decode, Result::Ok(255f64));
+        let value = sig.decode_full(&[0b0001_0000u8]).unwrap();
+        assert_eq!(value.raw, 1);
+        assert_eq!(value.physical, 1.5);
+        assert_eq!(value.label, None);
     }
 
     #[test]
-    fn test_decode_unsigned_004() {
-        let bit = Unsigned::new(4, 8, 1.0, 0.0, Endian::Little).unwrap();
-        let data = [0b11110000, 0b00001111, 0, 0, 0, 0, 0, 0];
+    fn test_decode_full_002() {
+        let mut table = ValueTable::new();
+        table.insert(-1, "Invalid");
+        let sig = Signed::builder()
+            .length(8)
+            .factor(2.0)
+            .value_table(table)
+            .build()
+            .unwrap();
+
+        let value = sig.decode_full(&[0xFFu8]).unwrap();
+        assert_eq!(value.raw, -1);
+        assert_eq!(value.physical, -2.0);
+        assert_eq!(value.label.as_deref(), Some("Invalid"));
+        assert_eq!(
+            sig.decode_full(&[0u8; 0]),
+            Err(DecodeError::StartOutOfRange { start: 0, dlc: 0 })
+        );
+    }
 
-        let decode = bit.try_decode(&data);
-        println!("{:?}", &decode);
-        assert_eq!(decode, Result::Ok(255f64));
+    #[test]
+    fn test_decode_named_001() {
+        let mut table = ValueTable::new();
+        table.insert_range(0..=9, "Low");
+        table.insert_range(10..=19, "Mid");
+        let sig = Unsigned::builder()
+            .length(8)
+            .value_table(table)
+            .build()
+            .unwrap();
+
+        assert_eq!(sig.decode_named(&[5u8]), Ok(Some("Low")));
+        assert_eq!(sig.decode_named(&[15u8]), Ok(Some("Mid")));
+        assert_eq!(sig.decode_named(&[25u8]), Ok(None));
+        assert_eq!(Unsigned::default().decode_named(&[0u8]), Ok(None));
     }
 
     #[test]
-    fn test_decode_unsigned_005() {
-        let bit = Unsigned::new(4, 8, 2.0, 1337.0, Endian::Little).unwrap();
-        let data = [0b11110000, 0b00001111, 0, 0, 0, 0, 0, 0];
+    fn test_decode_named_003() {
+        let mut table = ValueTable::new();
+        table.insert(-1, "Negative");
+        let sig = Unsigned::builder()
+            .length(64)
+            .value_table(table)
+            .build()
+            .unwrap();
+
+        // raw values above i64::MAX must not wrap around to negative labels
+        assert_eq!(sig.decode_named(&[0xFFu8; 8]), Err(DecodeError::Overflow));
+        assert_eq!(sig.decode_full(&[0xFFu8; 8]), Err(DecodeError::Overflow));
+
+        let mut data = [0xFFu8; 8];
+        data[7] = 0x7F;
+        assert_eq!(sig.decode_full(&data).unwrap().raw, i64::MAX);
+    }
 
-        let decode = bit.try_decode(&data);
-        println!("{:?}", &decode);
-        assert_eq!(decode, Result::Ok(255f64 * 2.0 + 1337.0));
+    #[test]
+    fn test_decode_named_002() {
+        let mut table = ValueTable::new();
+        table.insert_range(-128..=-1, "Negative");
+        table.insert_range(0..=127, "Positive");
+        let sig = Signed::builder()
+            .length(8)
+            .value_table(table)
+            .build()
+            .unwrap();
+
+        assert_eq!(sig.decode_named(&[0x80u8]), Ok(Some("Negative")));
+        assert_eq!(sig.decode_named(&[0x7Fu8]), Ok(Some("Positive")));
+        assert_eq!(
+            sig.decode_named(&[0u8; 0]),
+            Err(DecodeError::StartOutOfRange { start: 0, dlc: 0 })
+        );
     }
 
     #[test]
-    fn test_decode_unsigned_006() {
-        let sig = Unsigned::new(3, 8, 2.0, 1337.0, Endian::Big).unwrap();
-        let data = [0b0000_1111, 0b00001111, 0, 0, 0, 0, 0, 0];
+    fn test_counter_001() {
+        let mut counter = Counter::new(4, 3, Endian::Little).unwrap();
+        let mut data = [0x0Fu8];
 
-        let decode = sig.try_decode(&data);
-        println!("{:?}", &decode);
-        assert_eq!(decode, Result::Ok((0b1111_0000 as f64 * 2.0) + 1337.0));
+        for i in 0..8 {
+            assert_eq!(counter.try_encode_next(&mut data), Ok(i));
+            assert_eq!(counter.try_decode(&data), Ok(i));
+            assert_eq!(data, [0x0F | (i as u8) << 4]);
+        }
+
+        // the counter wraps around after 2^3 values
+        assert_eq!(counter.value(), 0);
+        assert_eq!(counter.try_encode_next(&mut data), Ok(0));
+        assert_eq!(data, [0x0F]);
     }
 
     #[test]
-    fn test_decode_unsigned_007() {
-        let sig = Unsigned::new(0, 9, 2.0, 1337.0, Endian::Little).unwrap();
-        let data = [0b0000_1111];
+    fn test_counter_002() {
+        let mut counter = Counter::new(8, 8, Endian::Little).unwrap();
+        let mut data = [0u8];
 
-        let decode = sig.try_decode(&data);
-        println!("{:?}", &decode);
-        assert_eq!(decode, Result::Err(DecodeError::NotEnoughData));
+        assert_eq!(
+            counter.try_encode_next(&mut data),
+            Err(EncodeError::NotEnoughData)
+        );
+        assert_eq!(counter.value(), 0);
     }
 
     #[test]
-    fn test_decode_unsigned_008() {
-        let sig = Unsigned::new(6, 8, 2.0, 1337.0, Endian::Big).unwrap();
-        let data = [0b0000_1111];
+    fn test_checksum_001() {
+        fn xor(data: &[u8]) -> u8 {
+            data.iter().fold(0, |acc, byte| acc ^ byte)
+        }
 
-        let decode = sig.try_decode(&data);
-        println!("{:?}", &decode);
-        assert_eq!(decode, Result::Err(DecodeError::NotEnoughData));
+        let checksum = Checksum::new(0, xor);
+        let mut data = [0xFFu8, 0x12, 0x34, 0x56, 0x78];
+
+        assert_eq!(
+            checksum.try_update(&mut data),
+            Ok(0x12 ^ 0x34 ^ 0x56 ^ 0x78)
+        );
+        assert_eq!(data[0], 0x08);
+        assert_eq!(checksum.try_decode(&data), Ok(0x08));
+        assert_eq!(checksum.verify(&data), Ok(true));
+
+        data[1] = 0x13;
+        assert_eq!(checksum.verify(&data), Ok(false));
     }
 
     #[test]
-    fn test_decode_unsigned_min_max_001() {
-        let sig = Unsigned::new(6, 8, 2.0, 1337.0, Endian::Little).unwrap();
-        assert_eq!(sig.min(), 1337.0);
-        assert_eq!(sig.max(), (255.0 * 2.0) + 1337.0);
+    fn test_checksum_002() {
+        fn sum(data: &[u8]) -> u8 {
+            data.iter().fold(0u8, |acc, byte| acc.wrapping_add(*byte))
+        }
+
+        let checksum = Checksum::new(2, sum);
+        let mut data = [0x80u8, 0x81];
+        assert_eq!(
+            checksum.try_update(&mut data),
+            Err(EncodeError::NotEnoughData)
+        );
+        assert_eq!(checksum.verify(&data), Err(DecodeError::NotEnoughData));
+
+        let mut data = [0x80u8, 0x81, 0x00];
+        assert_eq!(checksum.try_update(&mut data), Ok(0x01));
     }
 
     #[test]
-    fn test_decode_unsigned_min_max_002() {
-        let sig = Unsigned::new(6, 8, 1.0, 0.0, Endian::Little).unwrap();
-        assert_eq!(sig.min(), 0.0);
-        assert_eq!(sig.max(), 255.0);
+    fn test_checksum_003() {
+        struct Frame {
+            bytes: Vec<u8>,
+            dlc: usize,
+        }
+
+        impl CANRead for Frame {
+            fn data(&self) -> &[u8] {
+                &self.bytes
+            }
+
+            fn dlc(&self) -> usize {
+                self.dlc
+            }
+        }
+
+        fn xor(data: &[u8]) -> u8 {
+            data.iter().fold(0, |acc, byte| acc ^ byte)
+        }
+
+        // bytes beyond the dlc are not part of the checksum
+        let checksum = Checksum::new(0, xor);
+        let frame = Frame {
+            bytes: vec![0x03, 0x01, 0x02, 0xFF],
+            dlc: 3,
+        };
+        assert_eq!(checksum.compute(&frame), Ok(0x03));
+        assert_eq!(checksum.verify(&frame), Ok(true));
+
+        // a dlc claiming more bytes than available must not panic
+        let checksum = Checksum::new(7, xor);
+        let frame = Frame {
+            bytes: vec![0x01, 0x02],
+            dlc: 8,
+        };
+        assert_eq!(checksum.verify(&frame), Err(DecodeError::NotEnoughData));
     }
 
     #[test]
-    fn test_decode_unsigned_min_max_003() {
-        let sig = Unsigned::new(6, 16, 1.0, 0.0, Endian::Little).unwrap();
-        assert_eq!(sig.min(), 0.0);
-        assert_eq!(sig.max(), 256.0 * 256.0 - 1.0);
+    fn test_multiplexed_001() {
+        let multiplexor = Unsigned::new(0, 4, 1.0, 0.0, Endian::Little).unwrap();
+
+        // layout 0: one 12-bit unsigned signal
+        let sig_0 = Unsigned::new(4, 12, 1.0, 0.0, Endian::Little).unwrap();
+        let mux_0 = Multiplexed::new(&multiplexor, 0, sig_0);
+
+        // layout 1: two 6-bit signed signals
+        let sig_1a = Signed::new(4, 6, 1.0, 0.0, Endian::Little).unwrap();
+        let sig_1b = Signed::new(10, 6, 1.0, 0.0, Endian::Little).unwrap();
+        let mux_1a = Multiplexed::new(&multiplexor, 1, sig_1a);
+        let mux_1b = Multiplexed::new(&multiplexor, 1, sig_1b);
+
+        let data = [0xF0u8, 0xFF];
+        assert_eq!(mux_0.try_decode(&data), Ok(Some(4095.0)));
+        assert_eq!(mux_1a.try_decode(&data), Ok(None));
+        assert_eq!(mux_1b.try_decode(&data), Ok(None));
+
+        let data = [0xF1u8, 0xFF];
+        assert_eq!(mux_0.try_decode(&data), Ok(None));
+        assert_eq!(mux_1a.try_decode(&data), Ok(Some(-1.0)));
+        assert_eq!(mux_1b.try_decode(&data), Ok(Some(-1.0)));
     }
 
     #[test]
-    fn test_encode_unsigned_001() {
-        let unsigned = Unsigned::new(0, 8, 1.0, 0.0, Endian::Little).unwrap();
-        for i in 0..=255u8 {
-            let mut data = [0u8];
+    fn test_multiplexed_002() {
+        let multiplexor = Unsigned::new(0, 4, 1.0, 0.0, Endian::Little).unwrap();
+        let sig = Unsigned::new(8, 8, 1.0, 0.0, Endian::Little).unwrap();
+        let multiplexed = Multiplexed::new(&multiplexor, 3, sig);
 
-            let result = unsigned.try_encode(&mut data, i as f64);
-            assert!(result.is_ok());
-            assert_eq!(data, [i])
+        let mut data = [0u8, 0u8];
+        assert_eq!(multiplexed.try_encode(&mut data, 42.0), Ok(()));
+        assert_eq!(data, [0x03, 42]);
+        assert_eq!(multiplexed.is_active(&data), Ok(true));
+        assert_eq!(
+            multiplexed.try_decode(&[0u8; 0]),
+            Err(DecodeError::StartOutOfRange { start: 0, dlc: 0 })
+        );
+    }
+
+    #[test]
+    fn test_multiplexed_003() {
+        let sig = || Unsigned::new(8, 8, 1.0, 0.0, Endian::Little).unwrap();
+
+        // every selector survives non-integer factors and offsets of the multiplexor
+        for (factor, offset) in [(0.1, 0.0), (0.1, 0.7), (0.3, -2.5)] {
+            let multiplexor = Unsigned::new(0, 8, factor, offset, Endian::Little).unwrap();
+            for value in 0..=255u64 {
+                let multiplexed = Multiplexed::new(&multiplexor, value, sig());
+                let mut data = [0u8, 0u8];
+                assert_eq!(multiplexed.try_encode(&mut data, 42.0), Ok(()));
+                assert_eq!(data, [value as u8, 42]);
+                assert_eq!(multiplexed.try_decode(&data), Ok(Some(42.0)));
+            }
         }
+
+        let multiplexor = Unsigned::new(0, 4, 0.1, 0.7, Endian::Little).unwrap();
+        let multiplexed = Multiplexed::new(&multiplexor, 16, sig());
+        assert_eq!(
+            multiplexed.try_encode(&mut [0u8, 0u8], 42.0),
+            Err(EncodeError::MaxError)
+        );
     }
 
     #[test]
-    fn test_encode_unsigned_002() {
-        let unsigned = Unsigned::new(3, 8, 1.0, 0.0, Endian::Big).unwrap();
-        let mut data = [0u8, 0u8];
+    fn test_signal_kind_parse_001() {
+        let sig = "8|4@1+ (0.5,10)".parse::<SignalKind>();
+        let expected = Unsigned::new(8, 4, 0.5, 10.0, Endian::Little).unwrap();
+        assert_eq!(sig, Ok(SignalKind::Unsigned(expected)));
+    }
 
-        let result = unsigned.try_encode(&mut data, 255.0);
-        assert!(result.is_ok());
-        assert_eq!(data, [0x0Fu8, 0xF0u8]);
+    #[test]
+    fn test_signal_kind_parse_002() {
+        let sig = "3|8@0- (1,0)".parse::<SignalKind>().unwrap();
+        let expected = Signed::new(3, 8, 1.0, 0.0, Endian::Big).unwrap();
+        assert_eq!(sig, SignalKind::Signed(expected));
+
+        let data = [0b0000_1000, 0b0000_1111];
+        assert_eq!(sig.try_decode(&data), Ok(-128.0));
     }
 
     #[test]
-    fn test_encode_unsigned_004() {
-        let unsigned = Unsigned::new(2, 8, 1.0, 0.0, Endian::Big).unwrap();
-        let mut data = [0u8, 0u8];
+    fn test_signal_kind_parse_003() {
+        assert_eq!(
+            "".parse::<SignalKind>(),
+            Err(SignalKindParseError::MissingStartData)
+        );
+        assert_eq!(
+            "x|8@1+ (1,0)".parse::<SignalKind>(),
+            Err(SignalKindParseError::ParseStartError)
+        );
+        assert_eq!(
+            "0|8@2+ (1,0)".parse::<SignalKind>(),
+            Err(SignalKindParseError::ParseByteOrderError)
+        );
+        assert_eq!(
+            "0|8@1* (1,0)".parse::<SignalKind>(),
+            Err(SignalKindParseError::ParseSignError)
+        );
+        assert_eq!(
+            "0|8@1+".parse::<SignalKind>(),
+            Err(SignalKindParseError::MissingFactorOffsetData)
+        );
+        assert_eq!(
+            "0|8@1+ (1,a)".parse::<SignalKind>(),
+            Err(SignalKindParseError::ParseOffsetError)
+        );
+        assert_eq!(
+            "0|65@1+ (1,0)".parse::<SignalKind>(),
+            Err(SignalKindParseError::ConstructionError(
+                LengthError::LengthGreater64
+            ))
+        );
+    }
 
-        let result = unsigned.try_encode(&mut data, 255.0);
-        assert!(result.is_ok());
-        assert_eq!(data, [0b0000_0111u8, 0b1111_1000u8]);
+    #[test]
+    fn test_min_max_signed_lengths_001() {
+        let cases = [
+            (1, -1.0, 0.0),
+            (2, -2.0, 1.0),
+            (63, -(2f64.powi(62)), 2f64.powi(62) - 1.0),
+            (64, i64::MIN as f64, i64::MAX as f64),
+        ];
+        for (length, min, max) in cases {
+            let sig = Signed::new(0, length, 1.0, 0.0, Endian::Little).unwrap();
+            assert_eq!(sig.min(), min, "length {}", length);
+            assert_eq!(sig.max(), max, "length {}", length);
+        }
+
+        let sig = Signed::new(0, 1, 2.0, 10.0, Endian::Little).unwrap();
+        assert_eq!((sig.min(), sig.max()), (8.0, 10.0));
+        let mut data = [0u8];
+        assert_eq!(sig.try_encode(&mut data, 8.0), Ok(()));
+        assert_eq!(data, [0x01]);
+        assert_eq!(sig.try_decode(&data), Ok(8.0));
+    }
+
+    #[test]
+    fn test_sign_bit_001() {
+        // a 7-bit value inside of an 8-bit field, the most significant bit is padding
+        let sig = Signed::builder().length(8).sign_bit(6).build().unwrap();
+        assert_eq!(sig.sign_bit(), 6);
+        assert_eq!(sig.try_decode(&[0b0100_0000u8]), Ok(-64.0));
+        assert_eq!(sig.try_decode(&[0b1100_0000u8]), Ok(-64.0));
+        assert_eq!(sig.try_decode(&[0b0011_1111u8]), Ok(63.0));
+        assert_eq!(sig.try_decode(&[0b1111_1111u8]), Ok(-1.0));
+        assert_eq!((sig.raw_min(), sig.raw_max()), (-64, 63));
+        assert_eq!((sig.min(), sig.max()), (-64.0, 63.0));
+
+        let mut value = 0.0;
+        let compiled = sig.compile();
+        assert_eq!(compiled.decode_into(&[0b1111_1111u8], &mut value), Ok(()));
+        assert_eq!(value, -1.0);
+
+        let mut data = [0xFFu8];
+        assert_eq!(sig.try_encode(&mut data, -1.0), Ok(()));
+        assert_eq!(data, [0b0111_1111]);
+        assert_eq!(sig.try_encode(&mut data, 64.0), Err(EncodeError::MaxError));
+    }
+
+    #[test]
+    fn test_sign_bit_002() {
+        let sig = Signed::builder()
+            .length(12)
+            .sign_bit(9)
+            .endian(Endian::Big)
+            .start(7)
+            .build()
+            .unwrap();
+        let mut data = [0u8; 2];
+        assert_eq!(sig.try_encode(&mut data, -3.0), Ok(()));
+        assert_eq!(data, [0b0011_1111, 0b1101_0000]);
+        assert_eq!(sig.try_decode(&data), Ok(-3.0));
+
+        assert_eq!(Signed::default().sign_bit(), 0);
+        assert_eq!(
+            Signed::builder().length(8).sign_bit(8).build(),
+            Err(LengthError::SignBitOutOfRange)
+        );
     }
 
     #[test]
-    fn test_encode_unsigned_005() {
-        let unsigned = Unsigned::new(3, 8, 1.0, 0.0, Endian::Big).unwrap();
-        let mut data = [0xA0u8, 0x0Bu8];
+    fn test_max_scalar_bits_001() {
+        assert!(Unsigned::new(0, MAX_SCALAR_BITS, 1.0, 0.0, Endian::Little).is_ok());
+        assert!(Signed::new(0, MAX_SCALAR_BITS, 1.0, 0.0, Endian::Big).is_ok());
+        assert_eq!(
+            Unsigned::new(0, MAX_SCALAR_BITS + 1, 1.0, 0.0, Endian::Little),
+            Err(LengthError::LengthGreater64)
+        );
+        assert_eq!(
+            Signed::builder().length(MAX_SCALAR_BITS + 1).build(),
+            Err(LengthError::LengthGreater64)
+        );
+        assert!(Bytes::new(0, MAX_SCALAR_BITS + 1, Endian::Little).is_ok());
+    }
 
-        let result = unsigned.try_encode(&mut data, 255.0);
-        assert!(result.is_ok());
-        assert_eq!(data, [0xAFu8, 0xFBu8]);
+    #[test]
+    fn test_bytes_001() {
+        let vin = b"WVWZZZ1JZXW000001";
+        let mut data = [0u8; 20];
+        data[1..18].copy_from_slice(vin);
+
+        let sig = Bytes::new(8, 136, Endian::Little).unwrap();
+        assert_eq!(sig.byte_length(), 17);
+        assert_eq!(sig.try_decode(&data), Ok(vin.to_vec()));
+
+        // the most significant bit of byte 1 starts the big-endian sequence
+        let sig = Bytes::new(15, 136, Endian::Big).unwrap();
+        assert_eq!(sig.try_decode(&data), Ok(vin.to_vec()));
+
+        let mut encoded = [0u8; 20];
+        assert_eq!(sig.try_encode(&mut encoded, &vin[..]), Ok(()));
+        assert_eq!(encoded, data);
     }
 
     #[test]
-    fn test_encode_unsigned_006() {
-        let unsigned = Unsigned::new(3, 8, 1.0, 0.0, Endian::Big).unwrap();
-        let mut data = [0xA0u8, 0x0Bu8];
+    fn test_bytes_002() {
+        let data = [0xABu8, 0xCD];
 
-        let result = unsigned.try_encode(&mut data, 254_f64);
-        assert!(result.is_ok());
-        assert_eq!(data, [0xAFu8, 0xEBu8]);
-    }
+        let sig = Bytes::new(4, 12, Endian::Little).unwrap();
+        assert_eq!(sig.try_decode(&data), Ok(vec![0xDA, 0x0C]));
 
-    /* TEST SIGNED */
+        let sig = Bytes::new(3, 12, Endian::Big).unwrap();
+        assert_eq!(sig.try_decode(&data), Ok(vec![0x0B, 0xCD]));
 
-    #[test]
-    fn test_decode_signed_001() {
-        let sig = Signed::new(0, 8, 1.0, 0.0, Endian::Little).unwrap();
-        let data = [0b0111_1111];
+        let unsigned = Unsigned::new(3, 12, 1.0, 0.0, Endian::Big).unwrap();
+        assert_eq!(unsigned.try_decode(&data), Ok(0xBCD as f64));
 
-        let decode = sig.try_decode(&data);
-        println!("{:?}", &decode);
-        assert_eq!(decode, Result::Ok(127.0));
+        let mut encoded = [0xA0u8, 0x00];
+        assert_eq!(sig.try_encode(&mut encoded, &[0x0B, 0xCD][..]), Ok(()));
+        assert_eq!(encoded, data);
     }
 
     #[test]
-    fn test_decode_signed_002() {
-        let sig = Signed::new(0, 8, 1.0, 0.0, Endian::Little).unwrap();
-        let data = [0b1000_0000];
-
-        let decode = sig.try_decode(&data);
-        println!("{:?}", &decode);
-        assert_eq!(decode, Result::Ok(-128.0));
+    fn test_bytes_003() {
+        assert_eq!(
+            Bytes::new(0, 0, Endian::Little),
+            Err(LengthError::LengthZero)
+        );
+
+        let sig = Bytes::new(0, 12, Endian::Little).unwrap();
+        assert_eq!(
+            sig.try_decode(&[0u8]),
+            Err(DecodeError::LengthExceedsBuffer {
+                needed: 12,
+                available: 8
+            })
+        );
+
+        let mut data = [0u8; 2];
+        assert_eq!(
+            sig.try_encode(&mut data, &[0x00][..]),
+            Err(EncodeError::NotEnoughData)
+        );
+        assert_eq!(
+            sig.try_encode(&mut data, &[0x00, 0x00, 0x00][..]),
+            Err(EncodeError::MaxError)
+        );
+        assert_eq!(
+            sig.try_encode(&mut data, &[0x00, 0x10][..]),
+            Err(EncodeError::MaxError)
+        );
+        assert_eq!(
+            sig.try_encode(&mut [0u8], &[0x00, 0x00][..]),
+            Err(EncodeError::NotEnoughData)
+        );
     }
 
     #[test]
-    fn test_decode_signed_003() {
-        let sig = Signed::new(3, 8, 1.0, 0.0, Endian::Big).unwrap();
-        let data = [0b0000_0111, 0b1111_0000];
+    fn test_text_001() {
+        let data = [0x01u8, b'H', b'E', b'L', b'L', b'O', 0x02];
+        let sig = Text::new(1, 5).unwrap();
+        assert_eq!(sig.try_decode(&data), Ok(String::from("HELLO")));
+
+        let data = [b'H', b'I', b' ', 0x00, 0x00];
+        let sig = Text::new(0, 5).unwrap();
+        assert_eq!(sig.try_decode(&data), Ok(String::from("HI")));
+
+        let sig = Text::new(0, 5).unwrap().trim(false);
+        assert_eq!(sig.try_decode(&data), Ok(String::from("HI \0\0")));
+        assert_eq!(sig.try_decode(&[0u8; 4]), Err(DecodeError::NotEnoughData));
+        assert_eq!(Text::new(0, 0), Err(LengthError::LengthZero));
+    }
 
-        let decode = sig.try_decode(&data);
-        println!("{:?}", &decode);
-        assert_eq!(decode, Result::Ok(127.0));
+    #[test]
+    fn test_text_002() {
+        let sig = Text::new(0, 5).unwrap().padding(b' ');
+        let mut data = [0xFFu8; 6];
+        assert_eq!(sig.try_encode(&mut data, "HI"), Ok(()));
+        assert_eq!(data, [b'H', b'I', b' ', b' ', b' ', 0xFF]);
+        assert_eq!(sig.try_decode(&data), Ok(String::from("HI")));
+
+        assert_eq!(sig.try_encode(&mut data, "HELLO WORLD"), Ok(()));
+        assert_eq!(sig.try_decode(&data), Ok(String::from("HELLO")));
+
+        // the two-byte character does not fit into the remaining byte
+        assert_eq!(sig.try_encode(&mut data, "HELL\u{e4}"), Ok(()));
+        assert_eq!(data[..5], [b'H', b'E', b'L', b'L', b' ']);
+
+        assert_eq!(
+            sig.try_encode(&mut [0u8; 4], "HI"),
+            Err(EncodeError::NotEnoughData)
+        );
     }
 
     #[test]
-    fn test_decode_signed_004() {
-        let sig = Signed::new(3, 8, 1.0, 0.0, Endian::Big).unwrap();
-        let data = [0b0000_1000, 0b0000_1111];
+    fn test_text_003() {
+        // the field ends beyond u16::MAX bytes without overflowing
+        let sig = Text::new(u16::MAX, u16::MAX).unwrap();
+        assert_eq!(sig.start(), u16::MAX);
+        assert_eq!(sig.length(), u16::MAX);
+        assert_eq!(sig.try_decode(&[0u8; 8]), Err(DecodeError::NotEnoughData));
+        assert_eq!(
+            sig.try_encode(&mut [0u8; 8], "HI"),
+            Err(EncodeError::NotEnoughData)
+        );
+
+        // the bits of the field lie beyond u16::MAX
+        let sig = Text::new(8192, 1).unwrap();
+        let mut data = vec![0u8; 8193];
+        assert_eq!(sig.try_decode(&data), Ok(String::new()));
+        assert_eq!(
+            sig.try_encode(&mut data, "H"),
+            Err(EncodeError::NotEnoughData)
+        );
+        assert_eq!(data[8192], 0x00);
+    }
 
-        let decode = sig.try_decode(&data);
-        println!("{:?}", &decode);
-        assert_eq!(decode, Result::Ok(-128.0));
+    #[test]
+    fn test_signal_kind_parse_error_001() {
+        let err: Box<dyn std::error::Error> =
+            "0|65@1+ (1,0)".parse::<SignalKind>().unwrap_err().into();
+        assert_eq!(
+            err.to_string(),
+            "invalid signal: signal length is greater than 64"
+        );
+        assert_eq!(
+            err.source().map(|source| source.to_string()),
+            Some(String::from("signal length is greater than 64"))
+        );
+        assert_eq!(LengthError::LengthZero.to_string(), "signal length is zero");
     }
 
     #[test]
-    fn test_decode_signed_005() {
-        let sig = Signed::new(0, 8, 42.0, 1337.0, Endian::Little).unwrap();
-        let data = [0b0111_1111];
+    fn test_compiled_signal_001() {
+        // xorshift generator producing reproducible pseudo-random data
+        let mut state = 0x2545_F491_4F6C_DD1Du64;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        for _ in 0..8 {
+            let data = next().to_le_bytes();
+            for start in 0..64 {
+                for length in 1..=64 {
+                    for endian in [Endian::Little, Endian::Big] {
+                        let factor = (next() % 100) as f64 / 10.0;
+                        let offset = (next() % 1000) as f64 - 500.0;
+
+                        let sig = Unsigned::new(start, length, factor, offset, endian).unwrap();
+                        let compiled = sig.compile();
+                        let mut value = f64::NAN;
+                        let result = compiled.decode_into(&data, &mut value).map(|_| value);
+                        assert_eq!(result, sig.try_decode(&data));
+                        assert_eq!(compiled.try_decode(&data), sig.try_decode(&data));
+                    }
 
-        let decode = sig.try_decode(&data);
-        println!("{:?}", &decode);
-        assert_eq!(decode, Result::Ok(127.0 * 42.0 + 1337.0));
+                    for endian in [Endian::Little, Endian::Big] {
+                        let sig = Signed::new(start, length, 1.0, 0.0, endian).unwrap();
+                        let compiled = sig.compile();
+                        let mut value = f64::NAN;
+                        let result = compiled.decode_into(&data, &mut value).map(|_| value);
+                        assert_eq!(result, sig.try_decode(&data));
+                    }
+                }
+            }
+        }
     }
 
     #[test]
-    fn test_decode_signed_006() {
-        let sig = Signed::new(3, 8, 42.0, 1337.0, Endian::Big).unwrap();
-        let data = [0b0000_0111, 0b1111_0000];
+    fn test_compiled_signal_002() {
+        let sig = Signed::new(6, 8, 42.0, 1337.0, Endian::Big).unwrap();
+        let mut value = 1.0;
+        let result = sig.compile().decode_into(&[0b0000_0111], &mut value);
+        assert_eq!(
+            result,
+            Err(DecodeError::LengthExceedsBuffer {
+                needed: 9,
+                available: 8
+            })
+        );
+        assert_eq!(value, 1.0);
+    }
 
-        let decode = sig.try_decode(&data);
-        println!("{:?}", &decode);
-        assert_eq!(decode, Result::Ok(127.0 * 42.0 + 1337.0));
+    #[test]
+    fn test_decoder_001() {
+        let data = [0x81u8, 0x42, 0x24, 0x18, 0xFF, 0x00, 0xA5, 0x5A, 0xC3, 0x3C];
+        let mut decoder = Decoder::new();
+
+        for big in [false, true] {
+            let endian = || if big { Endian::Big } else { Endian::Little };
+            for start in [0, 1, 7, 8, 15, 23, 31, 39, 63, 71] {
+                for length in [1, 2, 7, 8, 9, 16, 17, 32, 33, 57, 64] {
+                    let sig = Unsigned::new(start, length, 0.5, -3.0, endian()).unwrap();
+                    assert_eq!(
+                        decoder.decode(&sig, &data),
+                        sig.try_decode(&data),
+                        "start {} length {} big {}",
+                        start,
+                        length,
+                        big
+                    );
+                }
+            }
+        }
     }
 
     #[test]
-    fn test_decode_signed_007() {
-        let sig = Signed::new(1, 8, 42.0, 1337.0, Endian::Little).unwrap();
-        let data = [0b0000_0111];
+    fn test_decode_fd_001() {
+        let mut data = [0u8; 64];
+        data[60] = 0xA5;
+
+        let unsigned = Unsigned::new(480, 8, 1.0, 0.0, Endian::Little).unwrap();
+        assert_eq!(unsigned.try_decode(&data), Ok(165.0));
+        let signed = Signed::new(480, 8, 1.0, 0.0, Endian::Little).unwrap();
+        assert_eq!(signed.try_decode(&data), Ok(-91.0));
+
+        let mut value = 0.0;
+        assert_eq!(unsigned.compile().decode_into(&data, &mut value), Ok(()));
+        assert_eq!(value, 165.0);
+    }
 
-        let decode = sig.try_decode(&data);
-        println!("{:?}", &decode);
-        assert_eq!(decode, Result::Err(DecodeError::NotEnoughData));
+    #[test]
+    fn test_decode_fd_002() {
+        // the 64-bit signals start in the middle of byte 48 and cover the bytes 48 to 56
+        let mut data = [0u8; 64];
+        let unsigned = Unsigned::new(388, 64, 1.0, 0.0, Endian::Little).unwrap();
+        assert_eq!(unsigned.try_encode(&mut data, 2f64.powi(63)), Ok(()));
+        assert_eq!(data[48], 0x00);
+        assert_eq!(data[56], 0x08);
+        assert_eq!(unsigned.try_decode(&data), Ok(2f64.powi(63)));
+
+        let mut value = 0.0;
+        assert_eq!(unsigned.compile().decode_into(&data, &mut value), Ok(()));
+        assert_eq!(value, 2f64.powi(63));
+
+        let mut data = [0u8; 64];
+        let signed = Signed::new(388, 64, 1.0, 0.0, Endian::Little).unwrap();
+        assert_eq!(signed.try_encode(&mut data, -2.0), Ok(()));
+        assert_eq!(signed.try_decode(&data), Ok(-2.0));
+        assert_eq!(signed.compile().decode_into(&data, &mut value), Ok(()));
+        assert_eq!(value, -2.0);
     }
 
     #[test]
-    fn test_decode_signed_008() {
-        let sig = Signed::new(6, 8, 42.0, 1337.0, Endian::Big).unwrap();
-        let data = [0b0000_0111];
+    fn test_decode_little_endian_regression_001() {
+        // the little-endian byte copy as implemented before copying the bytes at once, extended
+        // to the ninth byte covered by signals that do not start at a byte boundary
+        fn reference(start: u16, length: u16, data: &[u8]) -> [u8; 16] {
+            let start_byte = start / 8;
+            let end_byte = (start + length - 1) / 8;
+            let mut slice = [0u8; 16];
+            for (i, byte_index) in (start_byte..=end_byte).enumerate() {
+                slice[i] = *data.get(byte_index as usize).unwrap_or(&0);
+            }
+            slice
+        }
 
-        let decode = sig.try_decode(&data);
-        println!("{:?}", &decode);
-        assert_eq!(decode, Result::Err(DecodeError::NotEnoughData));
+        let data = [0x81u8, 0x42, 0x24, 0x18, 0xFF, 0x00, 0xA5, 0x5A, 0xC3, 0x3C];
+        for start in [0, 1, 7, 8, 9, 15, 16, 23, 24, 31, 32, 39, 56, 63, 64, 71] {
+            for length in [1, 2, 7, 8, 9, 15, 16, 17, 31, 32, 33, 56, 57, 63, 64] {
+                if start + length > 8 * data.len() as u16 {
+                    continue;
+                }
+
+                let slice = reference(start, length, &data);
+                let unsigned = Unsigned::new(start, length, 1.0, 0.0, Endian::Little).unwrap();
+                let expected =
+                    (u128::from_le_bytes(slice) >> (start % 8)) as u64 & u64::mask(length, 0);
+                assert_eq!(unsigned.decode_bits(&data), Ok(expected));
+
+                let signed = Signed::new(start, length, 1.0, 0.0, Endian::Little).unwrap();
+                let mut expected = expected as i64;
+                if expected & i64::mask(1, length - 1) != 0 {
+                    expected += !i64::mask(length, 0);
+                }
+                assert_eq!(signed.decode_bits(&data), Ok(expected));
+            }
+        }
     }
 
     #[test]
-    fn test_decode_signed_min_max_001() {
-        let sig = Signed::new(6, 8, 2.0, 1337.0, Endian::Little).unwrap();
-        assert_eq!(sig.min(), -128.0 * 2.0 + 1337.0);
-        assert_eq!(sig.max(), 127.0 * 2.0 + 1337.0);
+    fn test_required_dlc_001() {
+        // the signal occupies the bits 13 to 20
+        let signals = [
+            SignalKind::Unsigned(Unsigned::new(0, 8, 1.0, 0.0, Endian::Little).unwrap()),
+            SignalKind::Signed(Signed::new(13, 8, 1.0, 0.0, Endian::Little).unwrap()),
+        ];
+        assert_eq!(required_dlc(&signals), 3);
+        assert_eq!(required_dlc(&signals[..1]), 1);
+        assert_eq!(required_dlc(&[]), 0);
     }
 
     #[test]
-    fn test_encode_signed_001() {
-        let unsigned = Signed::new(0, 8, 1.0, 0.0, Endian::Little).unwrap();
-        for i in 0..=127u8 {
-            let mut data = [0u8];
+    fn test_required_dlc_002() {
+        // the most significant bit is bit 4 of byte 1, the least significant bit is bit 5 of byte 2
+        let signals = [SignalKind::Unsigned(
+            Unsigned::new(12, 8, 1.0, 0.0, Endian::Big).unwrap(),
+        )];
+        assert_eq!(required_dlc(&signals), 3);
+
+        let signals = [SignalKind::Unsigned(
+            Unsigned::new(15, 16, 1.0, 0.0, Endian::Big).unwrap(),
+        )];
+        assert_eq!(required_dlc(&signals), 3);
+
+        let signals = [SignalKind::Unsigned(
+            Unsigned::new(7, 16, 1.0, 0.0, Endian::Big).unwrap(),
+        )];
+        assert_eq!(required_dlc(&signals), 2);
+    }
 
-            let result = unsigned.try_encode(&mut data, i as f64);
-            assert!(result.is_ok());
-            assert_eq!(data, [i])
+    #[test]
+    fn test_decode_scaled_001() {
+        let sig = Unsigned::new(0, 16, 0.1, 0.0, Endian::Little).unwrap();
+        for raw in 0..=u16::MAX {
+            let data = raw.to_le_bytes();
+            assert_eq!(sig.try_decode_scaled(&data, 1000), Ok(raw as i64 * 100));
         }
+
+        let sig = Signed::new(0, 8, 0.1, -40.0, Endian::Little).unwrap();
+        assert_eq!(sig.try_decode_scaled(&[0x7Fu8], 1000), Ok(-27300));
+        assert_eq!(sig.try_decode_scaled(&[0x80u8], 1000), Ok(-52800));
+        assert_eq!(sig.try_decode_scaled(&[0xFFu8], 10), Ok(-401));
     }
 
     #[test]
-    fn test_encode_signed_002() {
-        let unsigned = Signed::new(0, 8, 1.0, 0.0, Endian::Little).unwrap();
-        let mut data = [0u8];
+    fn test_decode_scaled_003() {
+        // 0.25 * 10 is not an integer, rounding it would decode raw 4 as 12 instead of 10
+        let sig = Unsigned::new(0, 8, 0.25, 0.0, Endian::Little).unwrap();
+        assert_eq!(
+            sig.try_decode_scaled(&[4u8], 10),
+            Err(DecodeError::InexactScale)
+        );
+        assert_eq!(sig.try_decode_scaled(&[4u8], 100), Ok(100));
+
+        let sig = Signed::new(0, 8, 0.001, 0.0, Endian::Little).unwrap();
+        assert_eq!(
+            sig.try_decode_scaled(&[4u8], 10),
+            Err(DecodeError::InexactScale)
+        );
+        assert_eq!(sig.try_decode_scaled(&[0xFCu8], 1000), Ok(-4));
+
+        let sig = Unsigned::new(0, 8, 1.0, 0.05, Endian::Little).unwrap();
+        assert_eq!(
+            sig.try_decode_scaled(&[4u8], 10),
+            Err(DecodeError::InexactScale)
+        );
+
+        // products off an integer only by floating-point noise are accepted
+        let sig = Unsigned::new(0, 8, 0.07, 0.0, Endian::Little).unwrap();
+        assert_eq!(sig.try_decode_scaled(&[3u8], 100), Ok(21));
+    }
 
-        let result = unsigned.try_encode(&mut data, -128_f64);
-        assert!(result.is_ok());
-        assert_eq!(data, [0b1000_0000u8])
+    #[test]
+    fn test_decode_scaled_002() {
+        let sig = Unsigned::new(0, 64, 1.0, 0.0, Endian::Little).unwrap();
+        assert_eq!(
+            sig.try_decode_scaled(&[0xFFu8; 8], 1),
+            Err(DecodeError::Overflow)
+        );
+        assert_eq!(
+            sig.try_decode_scaled(&[0u8; 7], 1),
+            Err(DecodeError::LengthExceedsBuffer {
+                needed: 64,
+                available: 56
+            })
+        );
     }
 
     #[test]
-    fn test_encode_signed_003() {
-        let unsigned = Signed::new(0, 8, 1.0, 0.0, Endian::Little).unwrap();
-        let mut data = [0u8];
+    fn test_decode_at_001() {
+        let data = [0x12u8, 0x34, 0xFE, 0xFF, 0x56];
 
-        let result = unsigned.try_encode(&mut data, -1_f64);
-        assert!(result.is_ok());
-        assert_eq!(data, [0b1111_1111])
+        let sig = Signed::new(0, 16, 1.0, 0.0, Endian::Little).unwrap();
+        assert_eq!(sig.try_decode_at(&data, 0), sig.try_decode(&data));
+        assert_eq!(sig.try_decode_at(&data, 2), Ok(-2.0));
+        assert_eq!(sig.try_decode_at(&data, 2), sig.try_decode(&&data[2..]));
+
+        let sig = Unsigned::new(7, 16, 2.0, 1.0, Endian::Big).unwrap();
+        assert_eq!(sig.try_decode_at(&data, 0), Ok(2.0 * 0x1234 as f64 + 1.0));
+        assert_eq!(sig.try_decode_at(&data, 2), sig.try_decode(&&data[2..]));
     }
 
     #[test]
-    fn test_encode_signed_004() {
-        let unsigned = Signed::new(0, 8, 1.0, 0.0, Endian::Little).unwrap();
-        let mut data = [0u8];
+    fn test_decode_at_002() {
+        let data = [0u8; 4];
+        let sig = Unsigned::new(0, 16, 1.0, 0.0, Endian::Little).unwrap();
+        assert_eq!(sig.try_decode_at(&data, 2), Ok(0.0));
+        assert_eq!(
+            sig.try_decode_at(&data, 3),
+            Err(DecodeError::LengthExceedsBuffer {
+                needed: 40,
+                available: 32
+            })
+        );
+        assert_eq!(
+            sig.try_decode_at(&data, usize::MAX),
+            Err(DecodeError::NotEnoughData)
+        );
+    }
 
-        let result = unsigned.try_encode(&mut data, 128_f64);
-        assert!(result.is_err());
-        assert_eq!(result, Err(EncodeError::MaxError));
+    /// Returns the bit positions of a big-endian signal from the most to the least significant bit
+    /// by walking the Motorola bit numbering one bit at a time.
+    fn motorola_bits(start: u16, length: u16) -> Vec<u16> {
+        let mut bits = vec![start];
+        while bits.len() < length as usize {
+            let last = *bits.last().unwrap();
+            bits.push(if last % 8 == 0 { last + 15 } else { last - 1 });
+        }
+        bits
     }
 
     #[test]
-    fn test_encode_signed_005() {
-        let unsigned = Signed::new(0, 8, 1.0, 0.0, Endian::Little).unwrap();
-        let mut data = [0u8];
-
-        let result = unsigned.try_encode(&mut data, -129_f64);
-        assert!(result.is_err());
-        assert_eq!(result, Err(EncodeError::MinError));
+    fn test_big_endian_001() {
+        let sig = Unsigned::new(5, 10, 1.0, 0.0, Endian::Big).unwrap();
+        let mut data = [0u8; 2];
+
+        assert_eq!(sig.try_encode(&mut data, 0b10_0000_0001 as f64), Ok(()));
+        assert_eq!(data, [0b0010_0000, 0b0001_0000]);
+        assert_eq!(sig.try_decode(&data), Ok(0b10_0000_0001 as f64));
+        assert_eq!(sig.try_decode(&[0x3Fu8, 0xF0]), Ok(1023.0));
     }
 
     #[test]
-    fn test_encode_signed_006() {
-        let unsigned = Signed::new(0, 8, 1.0, 0.0, Endian::Little).unwrap();
-        let mut data = [];
+    fn test_big_endian_round_trip_001() {
+        let background = [0xA5u8, 0x3C, 0x5A, 0xC3];
+
+        for start in 0..32 {
+            for length in 1..=16 {
+                let unsigned = Unsigned::new(start, length, 1.0, 0.0, Endian::Big).unwrap();
+                if unsigned.validate(4).is_err() {
+                    continue;
+                }
+                let signed = Signed::new(start, length, 1.0, 0.0, Endian::Big).unwrap();
+                let bits = motorola_bits(start, length);
 
-        let result = unsigned.try_encode(&mut data, -128_f64);
-        assert!(result.is_err());
-        assert_eq!(result, Err(EncodeError::NotEnoughData));
+                for raw in 0..(1u64 << length) {
+                    // keep the runtime low by skipping most values of long signals
+                    if length > 10 && raw % 61 != 0 && raw != (1 << length) - 1 {
+                        continue;
+                    }
+
+                    // the expected data sets each bit on its own
+                    let mut expected = background;
+                    for (i, bit) in bits.iter().enumerate() {
+                        let value = (raw >> (length as usize - 1 - i)) & 1 == 1;
+                        expected.set_bit(*bit, value).unwrap();
+                    }
+
+                    let mut data = background;
+                    assert_eq!(unsigned.try_encode(&mut data, raw as f64), Ok(()));
+                    assert_eq!(data, expected, "start {start}, length {length}, raw {raw}");
+                    assert_eq!(unsigned.try_decode(&data), Ok(raw as f64));
+                    assert_eq!(unsigned.compile().try_decode(&data), Ok(raw as f64));
+
+                    let value = if raw & (1 << (length - 1)) != 0 {
+                        raw as i64 - (1 << length)
+                    } else {
+                        raw as i64
+                    };
+                    assert_eq!(signed.try_decode(&expected), Ok(value as f64));
+                    assert_eq!(signed.compile().try_decode(&expected), Ok(value as f64));
+
+                    let mut data = background;
+                    assert_eq!(signed.try_encode(&mut data, value as f64), Ok(()));
+                    assert_eq!(data, expected, "start {start}, length {length}, raw {raw}");
+                }
+            }
+        }
     }
 
     #[test]
-    fn test_encode_signed_007() {
-        let unsigned = Signed::new(3, 8, 1.0, 0.0, Endian::Big).unwrap();
-        let mut data = [0u8, 0u8];
+    fn test_big_endian_round_trip_002() {
+        // a 64-bit signal not aligned to a byte boundary spans 9 bytes
+        let sig = Unsigned::new(3, 64, 1.0, 0.0, Endian::Big).unwrap();
+        let mut data = [0u8; 9];
+        for (i, bit) in motorola_bits(3, 64).into_iter().enumerate() {
+            data.set_bit(bit, i % 3 == 0).unwrap();
+        }
 
-        let result = unsigned.try_encode(&mut data, 127_f64);
-        assert!(result.is_ok());
-        assert_eq!(data, [0b0000_0111u8, 0b1111_0000u8]);
+        let expected = (0..64).fold(0u64, |acc, i| (acc << 1) | (i % 3 == 0) as u64);
+        assert_eq!(sig.decode_bits(&data), Ok(expected));
+        assert_eq!(sig.validate(9), Ok(()));
+        assert_eq!(
+            Signed::new(3, 64, 1.0, 0.0, Endian::Big)
+                .unwrap()
+                .decode_bits(&data),
+            Ok(expected as i64)
+        );
     }
 
-    #[test]
-    fn test_encode_signed_008() {
-        let signed = Signed::new(3, 8, 1.0, 0.0, Endian::Big).unwrap();
-        let mut data = [0b0000_0000u8, 0b0000_0000u8];
+    /// A buffer claiming to hold 8 bytes while only providing 2.
+    struct LyingBuffer([u8; 2]);
 
-        let result = signed.try_encode(&mut data, -128_f64);
-        assert!(result.is_ok());
-        assert_eq!(data, [0b0000_1000u8, 0b0000_0000u8]);
+    impl CANRead for LyingBuffer {
+        fn data(&self) -> &[u8] {
+            &self.0
+        }
+
+        fn dlc(&self) -> usize {
+            8
+        }
+    }
+
+    impl CANWrite for LyingBuffer {
+        fn mut_data(&mut self) -> &mut [u8] {
+            &mut self.0
+        }
     }
 
     #[test]
-    fn test_encode_signed_009() {
-        let signed = Signed::new(3, 8, 1.0, 0.0, Endian::Big).unwrap();
-        let mut data = [0b1000_0000u8, 0b0000_0001u8];
+    fn test_encode_lying_buffer_001() {
+        let unsigned = [
+            Unsigned::new(16, 8, 1.0, 0.0, Endian::Little).unwrap(),
+            Unsigned::new(23, 8, 1.0, 0.0, Endian::Big).unwrap(),
+        ];
+        for signal in unsigned {
+            let mut data = LyingBuffer([0u8; 2]);
+            assert_eq!(
+                signal.try_encode(&mut data, 1.0),
+                Err(EncodeError::NotEnoughData)
+            );
+        }
 
-        let result = signed.try_encode(&mut data, -1_f64);
-        assert!(result.is_ok());
-        assert_eq!(data, [0b1000_1111u8, 0b1111_0001u8]);
+        let signed = [
+            Signed::new(16, 8, 1.0, 0.0, Endian::Little).unwrap(),
+            Signed::new(23, 8, 1.0, 0.0, Endian::Big).unwrap(),
+        ];
+        for signal in signed {
+            let mut data = LyingBuffer([0u8; 2]);
+            assert_eq!(
+                signal.try_encode(&mut data, -1.0),
+                Err(EncodeError::NotEnoughData)
+            );
+        }
     }
 
     // #[test]
@@ -1505,4 +4879,314 @@ mod tests {
     //     let decode = sig.try_decode(&data);
     //     assert_eq!(decode, Result::Ok(f32::from_be_bytes([1, 2, 3, 4])));
     // }
+
+    #[test]
+    fn test_decode_physical_raw_001() {
+        let sig = Unsigned::new(4, 8, 0.25, -5.0, Endian::Little).unwrap();
+        let data = [0b1000_0000u8, 0b0000_0001];
+        assert_eq!(sig.decode_raw(&data), Ok(Raw(24)));
+        assert_eq!(sig.decode_physical(&data), Ok(Physical(1.0)));
+        assert_eq!(
+            sig.decode_physical(&data).map(|v| v.0),
+            sig.try_decode(&data)
+        );
+
+        let sig = Signed::new(0, 4, 2.0, 0.0, Endian::Little).unwrap();
+        assert_eq!(sig.decode_raw(&[0x0Fu8]), Ok(Raw(0x0F)));
+        assert_eq!(sig.decode_physical(&[0x0Fu8]), Ok(Physical(-2.0)));
+        assert_eq!(
+            sig.decode_raw(&[]),
+            Err(DecodeError::StartOutOfRange { start: 0, dlc: 0 })
+        );
+    }
+
+    #[test]
+    fn test_encode_named_001() {
+        let mut table = ValueTable::new();
+        table.insert(0, "Off");
+        table.insert(1, "On");
+        table.insert(4, "Invalid");
+        let sig = Unsigned::builder()
+            .start(4)
+            .length(2)
+            .value_table(table)
+            .build()
+            .unwrap();
+
+        let mut data = [0xFFu8];
+        assert_eq!(sig.try_encode_named(&mut data, "On"), Ok(()));
+        assert_eq!(data, [0b1101_1111]);
+        assert_eq!(sig.decode_named(&data), Ok(Some("On")));
+
+        assert_eq!(
+            sig.try_encode_named(&mut data, "Unknown"),
+            Err(EncodeError::UnknownLabel)
+        );
+        assert_eq!(
+            sig.try_encode_named(&mut data, "Invalid"),
+            Err(EncodeError::MaxError)
+        );
+        assert_eq!(data, [0b1101_1111]);
+
+        let sig = Unsigned::new(0, 2, 1.0, 0.0, Endian::Little).unwrap();
+        assert_eq!(
+            sig.try_encode_named(&mut data, "On"),
+            Err(EncodeError::UnknownLabel)
+        );
+    }
+
+    #[test]
+    fn test_decode_clamped_001() {
+        let sig = Unsigned::builder()
+            .length(8)
+            .factor(0.5)
+            .range(0.0, 100.0)
+            .build()
+            .unwrap();
+        assert_eq!(sig.range(), Some((0.0, 100.0)));
+        assert_eq!(sig.max(), 100.0);
+
+        // 250 * 0.5 = 125 exceeds the documented maximum
+        assert_eq!(sig.try_decode(&[250u8]), Ok(125.0));
+        assert!(sig.try_decode(&[250u8]).unwrap() > sig.max());
+        assert_eq!(sig.try_decode_clamped(&[250u8]), Ok(100.0));
+        assert_eq!(sig.try_decode_clamped(&[100u8]), Ok(50.0));
+
+        let mut data = [0u8];
+        assert_eq!(sig.try_encode(&mut data, 125.0), Err(EncodeError::MaxError));
+    }
+
+    #[test]
+    fn test_decode_clamped_002() {
+        let sig = Signed::builder()
+            .length(8)
+            .range(-10.0, 10.0)
+            .build()
+            .unwrap();
+        assert_eq!(sig.try_decode_clamped(&[0xF0u8]), Ok(-10.0));
+        assert_eq!(sig.try_decode_clamped(&[0x05u8]), Ok(5.0));
+
+        // without a documented range, clamping keeps every decodable value
+        let sig = Signed::new(0, 8, 1.0, 0.0, Endian::Little).unwrap();
+        assert_eq!(sig.range(), None);
+        assert_eq!(sig.try_decode_clamped(&[0x80u8]), Ok(-128.0));
+    }
+
+    #[test]
+    fn test_decode_display_001() {
+        let sig = Unsigned::builder()
+            .length(8)
+            .factor(0.1)
+            .unit("V")
+            .build()
+            .unwrap();
+        assert_eq!(sig.decode_display(&[120u8]), Ok(String::from("12.0 V")));
+        assert_eq!(sig.decode_display(&[7u8]), Ok(String::from("0.7 V")));
+
+        let sig = Signed::builder().length(8).factor(0.25).build().unwrap();
+        assert_eq!(sig.decode_display(&[0xFFu8]), Ok(String::from("-0.25")));
+
+        let sig = Unsigned::builder()
+            .length(8)
+            .offset(-40.0)
+            .unit("°C")
+            .build()
+            .unwrap();
+        assert_eq!(sig.decode_display(&[60u8]), Ok(String::from("20 °C")));
+    }
+
+    #[test]
+    fn test_decimal_places_001() {
+        assert_eq!(decimal_places(1.0), 0);
+        assert_eq!(decimal_places(20.0), 0);
+        assert_eq!(decimal_places(0.1), 1);
+        assert_eq!(decimal_places(0.01), 2);
+        assert_eq!(decimal_places(-0.005), 3);
+        assert_eq!(decimal_places(1.0 / 3.0), 9);
+    }
+
+    #[test]
+    fn test_self_check_001() {
+        for start in (0..64).step_by(5) {
+            for length in [1, 8, 13, 20, 33, 64] {
+                if let Ok(sig) = Unsigned::new(start, length, 0.5, 3.0, Endian::Big) {
+                    assert_eq!(self_check(&sig, 8), sig.validate(8).is_ok());
+                }
+                if let Ok(sig) = Signed::new(start, length, 1.0, 0.0, Endian::Little) {
+                    assert_eq!(self_check(&sig, 8), sig.validate(8).is_ok());
+                }
+            }
+        }
+
+        let sig = Signed::builder()
+            .start(0)
+            .length(12)
+            .sign_bit(7)
+            .build()
+            .unwrap();
+        assert!(self_check(&sig, 2));
+    }
+
+    #[test]
+    fn test_self_check_002() {
+        // writes the most significant bit of big-endian signals into the wrong byte
+        struct Broken(Unsigned);
+
+        impl RawCodec for Broken {
+            fn raw_bits(&self) -> u16 {
+                self.0.length()
+            }
+
+            fn try_encode_raw<D: CANWrite>(
+                &self,
+                data: &mut D,
+                raw: u64,
+            ) -> Result<(), EncodeError> {
+                let low = raw & u64::mask(self.0.length() - 1, 0);
+                let high = raw >> (self.0.length() - 1) != 0;
+                self.0.try_encode_raw(data, low)?;
+                data.set_bit(self.0.start() + 8, high)
+            }
+
+            fn try_decode_raw<D: CANRead>(&self, data: &D) -> Result<u64, DecodeError> {
+                self.0.try_decode_raw(data)
+            }
+        }
+
+        let sig = Unsigned::new(3, 12, 1.0, 0.0, Endian::Big).unwrap();
+        assert!(self_check(&sig, 8));
+        assert!(!self_check(&Broken(sig), 8));
+    }
+
+    #[test]
+    fn test_bit_positions_001() {
+        let sig = Unsigned::new(3, 10, 1.0, 0.0, Endian::Little).unwrap();
+        assert_eq!(sig.bit_positions(), (3..13).collect::<Vec<_>>());
+
+        let sig = Signed::new(3, 10, 1.0, 0.0, Endian::Little).unwrap();
+        assert_eq!(sig.bit_positions(), (3..13).collect::<Vec<_>>());
+        assert_eq!(Bit::new(42).bit_positions(), vec![42]);
+    }
+
+    #[test]
+    fn test_bit_positions_002() {
+        let sig = Unsigned::new(3, 10, 1.0, 0.0, Endian::Big).unwrap();
+        assert_eq!(
+            sig.bit_positions(),
+            vec![10, 11, 12, 13, 14, 15, 0, 1, 2, 3]
+        );
+
+        for start in 0..64 {
+            for length in 1..=64 {
+                let sig = Unsigned::new(start, length, 1.0, 0.0, Endian::Big).unwrap();
+                if sig.validate(9).is_err() {
+                    continue;
+                }
+                let mut expected = motorola_bits(start, length);
+                expected.reverse();
+                assert_eq!(sig.bit_positions(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_bit_positions_003() {
+        // setting the bits at the positions equals encoding the raw value
+        for endian in [Endian::Little, Endian::Big] {
+            let sig = Unsigned::new(13, 10, 1.0, 0.0, endian).unwrap();
+            let raw = 0b10_1100_0111u64;
+
+            let mut expected = [0u8; 4];
+            sig.try_encode(&mut expected, raw as f64).unwrap();
+
+            let mut data = [0u8; 4];
+            for (i, bit) in sig.bit_positions().into_iter().enumerate() {
+                data.set_bit(bit, (raw >> i) & 1 == 1).unwrap();
+            }
+            assert_eq!(data, expected);
+        }
+    }
+
+    #[test]
+    fn test_decode_enum_001() {
+        #[derive(Debug, PartialEq)]
+        #[repr(i64)]
+        enum State {
+            Off = 0,
+            On = 1,
+            Fault = -1,
+        }
+
+        impl TryFrom<i64> for State {
+            type Error = ();
+
+            fn try_from(value: i64) -> Result<Self, Self::Error> {
+                match value {
+                    0 => Ok(State::Off),
+                    1 => Ok(State::On),
+                    -1 => Ok(State::Fault),
+                    _ => Err(()),
+                }
+            }
+        }
+
+        let sig = Unsigned::new(2, 2, 1.0, 0.0, Endian::Little).unwrap();
+        assert_eq!(sig.try_decode_enum(&[0b0000_0100u8]), Ok(State::On));
+        assert_eq!(
+            sig.try_decode_enum::<State, _>(&[0b0000_1100u8]),
+            Err(EnumDecodeError::UnknownValue(3))
+        );
+        assert_eq!(
+            sig.try_decode_enum::<State, _>(&[0u8; 0]),
+            Err(EnumDecodeError::Decode(DecodeError::StartOutOfRange {
+                start: 2,
+                dlc: 0
+            }))
+        );
+
+        let sig = Signed::new(0, 2, 1.0, 0.0, Endian::Little).unwrap();
+        assert_eq!(sig.try_decode_enum(&[0b0000_0011u8]), Ok(State::Fault));
+        assert_eq!(sig.try_decode_enum(&[0b0000_0000u8]), Ok(State::Off));
+
+        // a 64-bit raw value above i64::MAX must not wrap around to -1
+        let sig = Unsigned::new(0, 64, 1.0, 0.0, Endian::Little).unwrap();
+        assert_eq!(
+            sig.try_decode_enum::<State, _>(&[0xFFu8; 8]),
+            Err(EnumDecodeError::Decode(DecodeError::Overflow))
+        );
+    }
+
+    #[test]
+    fn test_set_factor_001() {
+        let mut sig = Unsigned::new(0, 8, 1.0, 0.0, Endian::Little).unwrap();
+        assert_eq!(sig.try_decode(&[100u8]), Ok(100.0));
+
+        sig.set_factor(0.5).unwrap();
+        sig.set_offset(-10.0).unwrap();
+        assert_eq!(sig.try_decode(&[100u8]), Ok(40.0));
+        assert_eq!(sig.min(), -10.0);
+        assert_eq!(sig.max(), 117.5);
+
+        // a non-finite range is rejected and leaves the signal unchanged
+        assert_eq!(
+            sig.set_factor(f64::INFINITY),
+            Err(LengthError::NonFiniteRange)
+        );
+        assert_eq!(sig.factor(), 0.5);
+    }
+
+    #[test]
+    fn test_set_factor_002() {
+        let mut sig = Signed::new(0, 8, 1.0, 0.0, Endian::Little).unwrap();
+        assert_eq!(sig.try_decode(&[0xFEu8]), Ok(-2.0));
+
+        sig.set_factor(2.0).unwrap();
+        sig.set_offset(1.0).unwrap();
+        assert_eq!(sig.try_decode(&[0xFEu8]), Ok(-3.0));
+        assert_eq!(sig.min(), -255.0);
+        assert_eq!(sig.max(), 255.0);
+
+        assert_eq!(sig.set_offset(f64::NAN), Err(LengthError::NonFiniteRange));
+        assert_eq!(sig.offset(), 1.0);
+    }
 }
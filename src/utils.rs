@@ -1,5 +1,6 @@
 //! Module providing utility traits used for bit-masks (see [Mask]) and describing endianess
-//! (see [Endian]).
+//! (see [Endian]). Both are re-exported at the crate root and by the [prelude](crate::prelude);
+//! there are no separate `mask` or `endian` modules.
 //!
 //! The [Mask] trait provides methods that model different ways to create bit-masks. The
 //! [mask](Mask::mask) method creates a bit-mask of a specified `length` and `left-shift`.
@@ -8,8 +9,29 @@
 //!
 //! The [Endian] type models two variants: [Little](Endian::Little) and [Big](Endian::Big) endian
 //! used to describe the byte layout. These differentiation is essential for modelling different bit
-//! layouts used by signals to decode and encode data.
+//! layouts used by signals to decode and encode data. [native](Endian::native) returns the variant
+//! matching the byte-order of the target architecture. Both variants can be parsed from strings,
+//! e.g., from configuration files. [dbc_start_to_bit] and [bit_to_dbc_start] convert start bits
+//! between the numbering of DBC files and the plain bit numbering.
 //!
+//! [fd_dlc_to_len] and [len_to_fd_dlc] translate between the DLC of CAN-FD frames and their
+//! number of data bytes.
+//!
+//! [extract_bits] and [insert_bits] read and write an arbitrary range of bits without constructing
+//! a signal. The signal types are implemented on top of them.
+//!
+//! Finally, [parse_hex_bytes] and [format_hex_bytes] convert between CAN-bus payloads and their
+//! hexadecimal representation used by logfiles, e.g., `"DEADBEEF"`. The [crc] module provides
+//! checksums commonly used by CAN-bus messages.
+
+pub mod crc;
+
+use crate::data::CANWrite;
+use crate::encode::EncodeError;
+use std::cmp::min;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
 
 /// A trait providing methods for construction different kinds of bit-masks.
 pub trait Mask {
@@ -31,6 +53,9 @@ pub trait Mask {
     /// use cantools::utils::Mask;
     /// let value: u8 = Mask::bit_mask(&[7,4,3,0]);
     /// assert_eq!(value, 0b10011001);
+    ///
+    /// let value = u64::bit_mask(&[63, 32, 0]);
+    /// assert_eq!(value, 0x8000_0001_0000_0001);
     /// ```
     fn bit_mask(bits: &[u16]) -> Self;
     /// Creates a bit-mask where every (possible) bit is set to `1`.
@@ -245,25 +270,418 @@ impl Mask for i64 {
 }
 
 /// Type for describing the underlying byte-order.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Default, PartialEq)]
 pub enum Endian {
     /// The byte-order is little-endian, or in other words, the least significant byte is stored
     /// at the lowest memory address.
+    #[default]
     Little,
-    /// The byte-order is little-endian, or in other words, the most significant byte is stored
+    /// The byte-order is big-endian, or in other words, the most significant byte is stored
     /// at the lowest memory address.
+    ///
+    /// Big-endian signals follow the Motorola bit numbering used by DBC files: bit `8 * i + j`
+    /// is bit `j` of byte `i`, where bit `0` is the least significant bit of the byte. The start
+    /// bit of a big-endian signal is its most significant bit. The remaining bits follow towards
+    /// bit `0` of the same byte and continue with bit `7` of the next byte. For example, a
+    /// 12-bit signal starting at bit `3` covers bits `3..=0` of byte `0` followed by bits
+    /// `7..=0` of byte `1`.
     Big,
 }
 
-impl Default for Endian {
-    fn default() -> Self {
-        Endian::Little
+#[allow(non_upper_case_globals)]
+impl Endian {
+    /// Alias of [Endian::Little] following the naming of DBC files and automotive specifications.
+    pub const Intel: Endian = Endian::Little;
+
+    /// Alias of [Endian::Big] following the naming of DBC files and automotive specifications.
+    pub const Motorola: Endian = Endian::Big;
+
+    /// Returns the byte-order of the target architecture.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::utils::Endian;
+    /// if cfg!(target_endian = "little") {
+    ///     assert_eq!(Endian::native(), Endian::Little);
+    /// }
+    /// ```
+    pub fn native() -> Endian {
+        if cfg!(target_endian = "big") {
+            Endian::Big
+        } else {
+            Endian::Little
+        }
+    }
+}
+
+/// Converts the start bit `dbc_start` of a DBC file into the plain bit numbering, where bit
+/// `8 * i + j` of a big-endian signal is bit `7 - j` of byte `i`, i.e., bits are numbered from the
+/// most significant bit of the first byte onwards. Little-endian start bits are the same in both
+/// numberings.
+///
+/// [Unsigned](crate::signals::Unsigned) and [Signed](crate::signals::Signed) take the DBC start
+/// bit directly (see [Endian::Big]). The conversion helps when exchanging start bits with tools
+/// using the plain numbering.
+///
+/// # Example
+/// ```
+/// use cantools::utils::{dbc_start_to_bit, Endian};
+/// assert_eq!(dbc_start_to_bit(7, &Endian::Big), 0);
+/// assert_eq!(dbc_start_to_bit(8, &Endian::Big), 15);
+/// assert_eq!(dbc_start_to_bit(8, &Endian::Little), 8);
+/// ```
+pub fn dbc_start_to_bit(dbc_start: u16, endian: &Endian) -> u16 {
+    match endian {
+        Endian::Little => dbc_start,
+        Endian::Big => 8 * (dbc_start / 8) + (7 - dbc_start % 8),
+    }
+}
+
+/// Converts the start bit `bit` of the plain bit numbering into the start bit of a DBC file. This
+/// is the inverse of [dbc_start_to_bit].
+///
+/// # Example
+/// ```
+/// use cantools::utils::{bit_to_dbc_start, Endian};
+/// assert_eq!(bit_to_dbc_start(0, &Endian::Big), 7);
+/// assert_eq!(bit_to_dbc_start(15, &Endian::Big), 8);
+/// assert_eq!(bit_to_dbc_start(15, &Endian::Little), 15);
+/// ```
+pub fn bit_to_dbc_start(bit: u16, endian: &Endian) -> u16 {
+    // mirroring the bits inside of a byte is its own inverse
+    dbc_start_to_bit(bit, endian)
+}
+
+/// The number of data bytes of a CAN-FD frame indexed by its 4-bit DLC.
+const FD_DLC_TO_LEN: [usize; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 12, 16, 20, 24, 32, 48, 64];
+
+/// Converts the 4-bit DLC of a CAN-FD frame into its number of data bytes. Up to `8`, the DLC
+/// equals the number of bytes; the codes `9` to `15` map to `12`, `16`, `20`, `24`, `32`, `48`, and
+/// `64` bytes. Only the lower four bits of `dlc` are considered.
+///
+/// # Example
+/// ```
+/// use cantools::utils::fd_dlc_to_len;
+/// assert_eq!(fd_dlc_to_len(8), 8);
+/// assert_eq!(fd_dlc_to_len(9), 12);
+/// assert_eq!(fd_dlc_to_len(15), 64);
+/// ```
+pub fn fd_dlc_to_len(dlc: u8) -> usize {
+    FD_DLC_TO_LEN[(dlc & 0x0F) as usize]
+}
+
+/// Converts a number of data bytes into the 4-bit DLC of a CAN-FD frame. Returns `None` if no DLC
+/// encodes exactly `len` bytes, e.g., for `13` bytes, which must be padded to `16` bytes first.
+///
+/// # Example
+/// ```
+/// use cantools::utils::len_to_fd_dlc;
+/// assert_eq!(len_to_fd_dlc(8), Some(8));
+/// assert_eq!(len_to_fd_dlc(12), Some(9));
+/// assert_eq!(len_to_fd_dlc(13), None);
+/// ```
+pub fn len_to_fd_dlc(len: usize) -> Option<u8> {
+    FD_DLC_TO_LEN
+        .iter()
+        .position(|&length| length == len)
+        .map(|dlc| dlc as u8)
+}
+
+/// Extracts the `length` bits starting at `start` from `data` given the bit numbering imposed by
+/// `endian`, i.e., decodes them like an [Unsigned](crate::signals::Unsigned) signal with a factor
+/// of `1` and an offset of `0` without constructing one. Returns `None` if `length` is `0` or
+/// exceeds `64`, or if the bits do not fit into `data`.
+///
+/// # Example
+/// ```
+/// use cantools::utils::{extract_bits, Endian};
+/// let data = [0b0101_0000u8, 0b0000_0011];
+/// assert_eq!(extract_bits(&data, 4, 8, &Endian::Little), Some(0x35));
+/// assert_eq!(extract_bits(&data, 7, 8, &Endian::Big), Some(0x50));
+/// assert_eq!(extract_bits(&data, 12, 8, &Endian::Little), None);
+/// ```
+pub fn extract_bits(data: &[u8], start: u16, length: u16, endian: &Endian) -> Option<u64> {
+    if length == 0 || length > 64 {
+        return None;
+    }
+
+    let start_bit = start as usize;
+    let needed = match endian {
+        Endian::Little => start_bit + length as usize,
+        Endian::Big => (7 - start_bit % 8) + 8 * (start_bit / 8) + length as usize,
+    };
+    if needed > 8 * data.len() {
+        return None;
+    }
+
+    match endian {
+        Endian::Little => Some(little_endian_bits(start, length, data)),
+        Endian::Big => Some(big_endian_bits(start, length, data)),
+    }
+}
+
+/// Sets the `length` bits starting at `start` to the least significant bits of `value` given the
+/// bit numbering imposed by `endian`, i.e., the inverse of [extract_bits]. Bits of `data` outside
+/// of the range remain unchanged. Returns [EncodeError::NotEnoughData] if the bits do not fit into
+/// `data`.
+///
+/// The bits are written through [set_bit](CANWrite::set_bit), which is how the signal types
+/// encode their values.
+///
+/// # Example
+/// ```
+/// use cantools::utils::{insert_bits, Endian};
+/// let mut data = [0u8; 2];
+/// insert_bits(&mut data, 4, 8, &Endian::Little, 0x35).unwrap();
+/// assert_eq!(data, [0b0101_0000, 0b0000_0011]);
+/// ```
+pub fn insert_bits<D: CANWrite>(
+    data: &mut D,
+    start: u16,
+    length: u16,
+    endian: &Endian,
+    value: u64,
+) -> Result<(), EncodeError> {
+    let start_bit = start as usize;
+    let needed = match endian {
+        Endian::Little => start_bit + length as usize,
+        Endian::Big => (7 - start_bit % 8) + 8 * (start_bit / 8) + length as usize,
+    };
+    if needed > 8 * data.dlc() {
+        return Err(EncodeError::NotEnoughData);
+    }
+
+    for (index, bit) in bit_positions(start, length, endian).enumerate() {
+        // bits of lengths beyond 64 are zero
+        let bit_value = value.checked_shr(index as u32).unwrap_or(0) & 1 != 0;
+        data.set_bit(bit, bit_value)?;
+    }
+
+    Ok(())
+}
+
+/// Returns the absolute positions of the `length` bits starting at `start` given the bit numbering
+/// imposed by `endian`, ordered from the least to the most significant bit. Position `8 * i + j`
+/// is bit `j` of byte `i`.
+///
+/// Big-endian bits are counted along the Motorola bit numbering: the bit `k` places after the most
+/// significant bit `start` lies `k` places further on the sequence `7, 6, ..., 0, 15, 14, ...`.
+pub(crate) fn bit_positions(start: u16, length: u16, endian: &Endian) -> impl Iterator<Item = u16> {
+    let big = *endian == Endian::Big;
+    // position of the start bit along the sequence 7, 6, ..., 0, 15, 14, ...
+    let msb = 8 * (start / 8) + (7 - start % 8);
+    (0..length).map(move |index| {
+        if big {
+            let position = msb + (length - 1 - index);
+            8 * (position / 8) + (7 - position % 8)
+        } else {
+            start + index
+        }
+    })
+}
+
+/// Extracts the bits of a little-endian signal with the least significant bit at `start` from
+/// `data`. Bytes missing from `data` are treated as zero.
+pub(crate) fn little_endian_bits(start: u16, length: u16, data: &[u8]) -> u64 {
+    little_endian_bits_in(&mut [0u8; 16], start, length, data)
+}
+
+/// Extracts the bits like [little_endian_bits] but copies the covered bytes into `scratch`.
+pub(crate) fn little_endian_bits_in(
+    scratch: &mut [u8; 16],
+    start: u16,
+    length: u16,
+    data: &[u8],
+) -> u64 {
+    let start_byte = (start / 8) as usize;
+    let end_byte = (start as usize + length as usize - 1) / 8;
+
+    // a signal of up to 64 bits spans at most 9 bytes, copy the covered bytes at once
+    let bytes = data.get(start_byte..).unwrap_or(&[]);
+    let count = min(end_byte - start_byte + 1, bytes.len());
+    scratch.fill(0);
+    scratch[..count].copy_from_slice(&bytes[..count]);
+
+    (u128::from_le_bytes(*scratch) >> (start % 8)) as u64 & u64::mask(length, 0)
+}
+
+/// Extracts the bits of a big-endian signal with the most significant bit at `start` from `data`.
+/// Bytes missing from `data` are treated as zero. See [Endian::Big] for the bit numbering.
+pub(crate) fn big_endian_bits(start: u16, length: u16, data: &[u8]) -> u64 {
+    let start_byte = (start / 8) as usize;
+    // position of the least significant bit when counting from the most significant bit of the
+    // start byte
+    let end = (7 - start % 8) as usize + 8 * start_byte + length as usize - 1;
+
+    // a signal of up to 64 bits spans at most 9 bytes
+    let mut word = 0u128;
+    for byte_index in start_byte..=end / 8 {
+        word = (word << 8) | *data.get(byte_index).unwrap_or(&0) as u128;
+    }
+
+    (word >> (7 - end % 8)) as u64 & u64::mask(length, 0)
+}
+
+/// Type representing possible errors when parsing an [Endian].
+#[derive(Debug, PartialEq)]
+pub enum EndianParseError {
+    /// The string names no known byte-order.
+    UnknownEndian,
+}
+
+impl fmt::Display for EndianParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EndianParseError::UnknownEndian => write!(f, "unknown byte-order"),
+        }
+    }
+}
+
+impl Error for EndianParseError {}
+
+impl FromStr for Endian {
+    type Err = EndianParseError;
+
+    /// Parses `"little"`, `"big"`, `"intel"`, `"motorola"`, or `"native"`, ignoring the ASCII
+    /// case.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::utils::Endian;
+    /// assert_eq!("little".parse(), Ok(Endian::Little));
+    /// assert_eq!("Big".parse(), Ok(Endian::Big));
+    /// assert_eq!("Motorola".parse(), Ok(Endian::Big));
+    /// assert_eq!("native".parse(), Ok(Endian::native()));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("little") || s.eq_ignore_ascii_case("intel") {
+            Ok(Endian::Little)
+        } else if s.eq_ignore_ascii_case("big") || s.eq_ignore_ascii_case("motorola") {
+            Ok(Endian::Big)
+        } else if s.eq_ignore_ascii_case("native") {
+            Ok(Endian::native())
+        } else {
+            Err(EndianParseError::UnknownEndian)
+        }
+    }
+}
+
+/// Type representing possible errors when parsing hexadecimal bytes.
+#[derive(Debug, PartialEq)]
+pub enum HexError {
+    /// The string consists of an odd number of digits, i.e., the last byte is incomplete.
+    OddLength,
+    /// The string contains a character that is no hexadecimal digit.
+    InvalidDigit,
+    /// The output buffer is too small to hold every parsed byte.
+    BufferTooSmall,
+}
+
+impl fmt::Display for HexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HexError::OddLength => write!(f, "odd number of hexadecimal digits"),
+            HexError::InvalidDigit => write!(f, "invalid hexadecimal digit"),
+            HexError::BufferTooSmall => write!(f, "output buffer is too small"),
+        }
+    }
+}
+
+impl Error for HexError {}
+
+/// Parses a string of hexadecimal digits without separators, two digits per byte, into `out`.
+/// Returns the number of parsed bytes. Both lower and upper case digits are accepted.
+///
+/// # Example
+/// ```
+/// use cantools::utils::parse_hex_bytes_into;
+/// let mut out = [0u8; 8];
+/// assert_eq!(parse_hex_bytes_into("0aFF", &mut out), Ok(2));
+/// assert_eq!(out[..2], [0x0A, 0xFF]);
+/// ```
+pub fn parse_hex_bytes_into(s: &str, out: &mut [u8]) -> Result<usize, HexError> {
+    let digits = s.as_bytes();
+    if !digits.len().is_multiple_of(2) {
+        return Err(HexError::OddLength);
+    }
+
+    let count = digits.len() / 2;
+    if count > out.len() {
+        return Err(HexError::BufferTooSmall);
+    }
+
+    for (byte, pair) in out.iter_mut().zip(digits.chunks_exact(2)) {
+        let high = (pair[0] as char)
+            .to_digit(16)
+            .ok_or(HexError::InvalidDigit)?;
+        let low = (pair[1] as char)
+            .to_digit(16)
+            .ok_or(HexError::InvalidDigit)?;
+        *byte = (high << 4 | low) as u8;
+    }
+    Ok(count)
+}
+
+/// Parses a string of hexadecimal digits without separators, two digits per byte.
+///
+/// # Example
+/// ```
+/// use cantools::utils::{parse_hex_bytes, HexError};
+/// assert_eq!(parse_hex_bytes("DEADbeef"), Ok(vec![0xDE, 0xAD, 0xBE, 0xEF]));
+/// assert_eq!(parse_hex_bytes("ABC"), Err(HexError::OddLength));
+/// ```
+pub fn parse_hex_bytes(s: &str) -> Result<Vec<u8>, HexError> {
+    let mut bytes = vec![0u8; s.len() / 2];
+    let count = parse_hex_bytes_into(s, &mut bytes)?;
+    bytes.truncate(count);
+    Ok(bytes)
+}
+
+/// Formats `bytes` as upper case hexadecimal digits, two digits per byte, joined by `sep`.
+///
+/// # Example
+/// ```
+/// use cantools::utils::format_hex_bytes;
+/// assert_eq!(format_hex_bytes(&[0xDE, 0xAD], ""), "DEAD");
+/// assert_eq!(format_hex_bytes(&[0xDE, 0xAD], " "), "DE AD");
+/// ```
+pub fn format_hex_bytes(bytes: &[u8], sep: &str) -> String {
+    let mut string = String::with_capacity(bytes.len() * (2 + sep.len()));
+    // writing into a String cannot fail
+    let _ = write_hex_bytes(&mut string, bytes, sep);
+    string
+}
+
+/// Writes `bytes` in the format of [format_hex_bytes] into `out` without allocating, e.g., from
+/// within a [Display](fmt::Display) implementation.
+///
+/// # Example
+/// ```
+/// use cantools::utils::write_hex_bytes;
+/// let mut string = String::from("data: ");
+/// write_hex_bytes(&mut string, &[0xDE, 0xAD], " ").unwrap();
+/// assert_eq!(string, "data: DE AD");
+/// ```
+pub fn write_hex_bytes<W: fmt::Write>(out: &mut W, bytes: &[u8], sep: &str) -> fmt::Result {
+    for (i, byte) in bytes.iter().enumerate() {
+        if i > 0 {
+            out.write_str(sep)?;
+        }
+        write!(out, "{:02X}", byte)?;
     }
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Mask;
+    use super::{
+        bit_to_dbc_start, dbc_start_to_bit, extract_bits, fd_dlc_to_len, format_hex_bytes,
+        insert_bits, len_to_fd_dlc, parse_hex_bytes, parse_hex_bytes_into, Endian,
+        EndianParseError, HexError, Mask,
+    };
+    use crate::decode::TryDecode;
+    use crate::encode::{EncodeError, TryEncode};
+    use crate::signals::Unsigned;
 
     #[test]
     fn test_mask_u8_001() {
@@ -540,4 +958,187 @@ mod tests {
         let value: i64 = Mask::mask(1, 63);
         assert_eq!(value, i64::MIN);
     }
+
+    // Endian
+    #[test]
+    fn test_endian_001() {
+        assert_eq!("little".parse::<Endian>(), Ok(Endian::Little));
+        assert_eq!("LITTLE".parse::<Endian>(), Ok(Endian::Little));
+    }
+
+    #[test]
+    fn test_endian_002() {
+        assert_eq!("big".parse::<Endian>(), Ok(Endian::Big));
+        assert_eq!("Big".parse::<Endian>(), Ok(Endian::Big));
+    }
+
+    #[test]
+    fn test_endian_003() {
+        let expected = if cfg!(target_endian = "big") {
+            Endian::Big
+        } else {
+            Endian::Little
+        };
+        assert_eq!(Endian::native(), expected);
+        assert_eq!("native".parse::<Endian>(), Ok(expected));
+    }
+
+    #[test]
+    fn test_endian_004() {
+        assert_eq!(
+            "middle".parse::<Endian>(),
+            Err(EndianParseError::UnknownEndian)
+        );
+        assert_eq!("".parse::<Endian>(), Err(EndianParseError::UnknownEndian));
+    }
+
+    #[test]
+    fn test_endian_005() {
+        assert_eq!(Endian::default(), Endian::Little);
+    }
+
+    #[test]
+    fn test_endian_006() {
+        assert_eq!("intel".parse::<Endian>(), Ok(Endian::Intel));
+        assert_eq!("Intel".parse::<Endian>(), Ok(Endian::Little));
+        assert_eq!("motorola".parse::<Endian>(), Ok(Endian::Motorola));
+        assert_eq!("MOTOROLA".parse::<Endian>(), Ok(Endian::Big));
+        assert_eq!(Endian::Intel, Endian::Little);
+        assert_eq!(Endian::Motorola, Endian::Big);
+    }
+
+    #[test]
+    fn test_dbc_start_001() {
+        // (DBC start bit, plain start bit) of big-endian signals
+        let pairs = [
+            (7, 0),
+            (0, 7),
+            (15, 8),
+            (12, 11),
+            (39, 32),
+            (56, 63),
+            (63, 56),
+        ];
+        for (dbc_start, bit) in pairs {
+            assert_eq!(dbc_start_to_bit(dbc_start, &Endian::Big), bit);
+            assert_eq!(bit_to_dbc_start(bit, &Endian::Big), dbc_start);
+        }
+    }
+
+    #[test]
+    fn test_dbc_start_002() {
+        for bit in 0..512 {
+            assert_eq!(dbc_start_to_bit(bit, &Endian::Little), bit);
+            assert_eq!(bit_to_dbc_start(bit, &Endian::Little), bit);
+            assert_eq!(
+                bit_to_dbc_start(dbc_start_to_bit(bit, &Endian::Big), &Endian::Big),
+                bit
+            );
+        }
+    }
+
+    #[test]
+    fn test_fd_dlc_001() {
+        let lengths = [0, 1, 2, 3, 4, 5, 6, 7, 8, 12, 16, 20, 24, 32, 48, 64];
+        for (dlc, len) in lengths.into_iter().enumerate() {
+            assert_eq!(fd_dlc_to_len(dlc as u8), len);
+            assert_eq!(len_to_fd_dlc(len), Some(dlc as u8));
+        }
+        assert_eq!(fd_dlc_to_len(0x1F), 64);
+    }
+
+    #[test]
+    fn test_fd_dlc_002() {
+        for len in [9, 10, 11, 13, 17, 33, 49, 63, 65] {
+            assert_eq!(len_to_fd_dlc(len), None);
+        }
+    }
+
+    #[test]
+    fn test_hex_001() {
+        assert_eq!(parse_hex_bytes(""), Ok(vec![]));
+        assert_eq!(parse_hex_bytes("00"), Ok(vec![0x00]));
+        assert_eq!(parse_hex_bytes("0a1B"), Ok(vec![0x0A, 0x1B]));
+        assert_eq!(format_hex_bytes(&[], " "), "");
+        assert_eq!(format_hex_bytes(&[0x0A, 0x1B], " "), "0A 1B");
+    }
+
+    #[test]
+    fn test_hex_002() {
+        assert_eq!(parse_hex_bytes("1"), Err(HexError::OddLength));
+        assert_eq!(parse_hex_bytes("123"), Err(HexError::OddLength));
+        assert_eq!(parse_hex_bytes("1G"), Err(HexError::InvalidDigit));
+        assert_eq!(parse_hex_bytes("+1"), Err(HexError::InvalidDigit));
+        assert_eq!(parse_hex_bytes("\u{e4}"), Err(HexError::InvalidDigit));
+    }
+
+    #[test]
+    fn test_hex_003() {
+        let mut out = [0u8; 2];
+        assert_eq!(parse_hex_bytes_into("0102", &mut out), Ok(2));
+        assert_eq!(out, [0x01, 0x02]);
+        assert_eq!(
+            parse_hex_bytes_into("010203", &mut out),
+            Err(HexError::BufferTooSmall)
+        );
+    }
+
+    #[test]
+    fn test_hex_004() {
+        let bytes = (0..=255).collect::<Vec<u8>>();
+        assert_eq!(parse_hex_bytes(&format_hex_bytes(&bytes, "")), Ok(bytes));
+    }
+
+    #[test]
+    fn test_extract_bits_001() {
+        let data = [0x12u8, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0];
+        for big in [false, true] {
+            let endian = || if big { Endian::Big } else { Endian::Little };
+            for start in 0..64 {
+                for length in 1..=64 {
+                    let sig = match Unsigned::new(start, length, 1.0, 0.0, endian()) {
+                        Ok(sig) => sig,
+                        Err(_) => continue,
+                    };
+                    let expected: Option<f64> = sig.try_decode(&data).ok();
+                    let bits = extract_bits(&data, start, length, &endian());
+                    assert_eq!(bits.map(|bits| bits as f64), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_extract_bits_002() {
+        let data = [0xFFu8; 8];
+        assert_eq!(extract_bits(&data, 0, 0, &Endian::Little), None);
+        assert_eq!(extract_bits(&data, 0, 65, &Endian::Little), None);
+        assert_eq!(extract_bits(&data, 0, 64, &Endian::Little), Some(u64::MAX));
+        assert_eq!(extract_bits(&data[..1], 0, 2, &Endian::Big), None);
+        assert_eq!(extract_bits(&data[..1], 7, 8, &Endian::Big), Some(0xFF));
+        assert_eq!(extract_bits(&data[..1], 6, 8, &Endian::Big), None);
+    }
+
+    #[test]
+    fn test_insert_bits_001() {
+        for big in [false, true] {
+            let endian = || if big { Endian::Big } else { Endian::Little };
+            let sig = Unsigned::new(11, 12, 1.0, 0.0, endian()).unwrap();
+
+            let mut expected = [0xA5u8; 4];
+            sig.try_encode(&mut expected, 0xABC as f64).unwrap();
+
+            let mut data = [0xA5u8; 4];
+            assert_eq!(insert_bits(&mut data, 11, 12, &endian(), 0xABC), Ok(()));
+            assert_eq!(data, expected);
+            assert_eq!(extract_bits(&data, 11, 12, &endian()), Some(0xABC));
+        }
+
+        let mut data = [0u8; 1];
+        assert_eq!(
+            insert_bits(&mut data, 4, 8, &Endian::Little, 0xFF),
+            Err(EncodeError::NotEnoughData)
+        );
+        assert_eq!(data, [0x00]);
+    }
 }
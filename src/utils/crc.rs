@@ -0,0 +1,81 @@
+//! Module providing cyclic redundancy checks commonly used to protect CAN-bus messages.
+//!
+//! Both functions operate on plain byte slices. [crc8_sae_j1850] returns a single byte and can
+//! therefore be used as the algorithm of a [Checksum](crate::signals::Checksum) directly.
+//!
+//! # Example
+//! ```
+//! use cantools::signals::Checksum;
+//! use cantools::utils::crc::crc8_sae_j1850;
+//!
+//! let checksum = Checksum::new(3, crc8_sae_j1850);
+//! let mut data = [0x01u8, 0x02, 0x03, 0x00];
+//! checksum.try_update(&mut data).unwrap();
+//! assert_eq!(data[3], crc8_sae_j1850(&[0x01, 0x02, 0x03]));
+//! ```
+
+/// Computes the CRC-8-SAE-J1850 of `data`, i.e., polynomial `0x1D`, initial value `0xFF`, no
+/// reflection, and a final XOR with `0xFF`.
+///
+/// # Example
+/// ```
+/// use cantools::utils::crc::crc8_sae_j1850;
+/// assert_eq!(crc8_sae_j1850(b"123456789"), 0x4B);
+/// ```
+pub fn crc8_sae_j1850(data: &[u8]) -> u8 {
+    let mut crc = 0xFFu8;
+    for byte in data {
+        crc ^= byte;
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x1D
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc ^ 0xFF
+}
+
+/// Computes the CRC-16-CCITT of `data` in its common variant (also known as CRC-16/CCITT-FALSE),
+/// i.e., polynomial `0x1021`, initial value `0xFFFF`, no reflection, and no final XOR.
+///
+/// # Example
+/// ```
+/// use cantools::utils::crc::crc16_ccitt;
+/// assert_eq!(crc16_ccitt(b"123456789"), 0x29B1);
+/// ```
+pub fn crc16_ccitt(data: &[u8]) -> u16 {
+    let mut crc = 0xFFFFu16;
+    for byte in data {
+        crc ^= (*byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{crc16_ccitt, crc8_sae_j1850};
+
+    #[test]
+    fn test_crc8_sae_j1850_001() {
+        assert_eq!(crc8_sae_j1850(b"123456789"), 0x4B);
+        assert_eq!(crc8_sae_j1850(&[]), 0x00);
+        assert_eq!(crc8_sae_j1850(&[0x00, 0x00, 0x00, 0x00]), 0x59);
+        assert_eq!(crc8_sae_j1850(&[0xFF, 0xFF, 0xFF, 0xFF]), 0x74);
+    }
+
+    #[test]
+    fn test_crc16_ccitt_001() {
+        assert_eq!(crc16_ccitt(b"123456789"), 0x29B1);
+        assert_eq!(crc16_ccitt(&[]), 0xFFFF);
+        assert_eq!(crc16_ccitt(b"A"), 0xB915);
+    }
+}
@@ -0,0 +1,219 @@
+//! Module providing value tables that assign labels to raw signal values.
+//!
+//! Many signals do not carry a quantity but a state, e.g., `0 = "Off"` and `1 = "On"`. A
+//! [ValueTable] stores these labels and is attached to a signal such that decoding can return the
+//! label together with the value (see [decode_full](crate::signals::Unsigned::decode_full)).
+//! Besides single values, a label can also be assigned to an inclusive range of values, e.g.,
+//! `0..=9 = "Low"`.
+//!
+//! # Example
+//! ```
+//! use cantools::values::ValueTable;
+//!
+//! let mut table = ValueTable::new();
+//! table.insert(0, "Off");
+//! table.insert(1, "On");
+//! table.insert_range(10..=19, "Mid");
+//!
+//! assert_eq!(table.label(1), Some("On"));
+//! assert_eq!(table.label(2), None);
+//! assert_eq!(table.label(15), Some("Mid"));
+//! ```
+
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+/// A type mapping raw signal values, or ranges thereof, to labels.
+///
+/// The ranges stored inside the table never overlap. Inserting a value or range that overlaps
+/// existing entries replaces the overlapped values; the values of these entries outside of the
+/// inserted range keep their labels.
+#[derive(Debug, Default, PartialEq)]
+pub struct ValueTable {
+    // maps the first value of each range to the last value of the range and its label
+    labels: BTreeMap<i64, (i64, String)>,
+}
+
+impl ValueTable {
+    /// Constructs a new, empty value table.
+    pub fn new() -> ValueTable {
+        ValueTable::default()
+    }
+
+    /// Assigns `label` to the raw `value`, replacing its previous label, if any.
+    pub fn insert(&mut self, value: i64, label: &str) {
+        self.insert_range(value..=value, label);
+    }
+
+    /// Assigns `label` to every raw value inside of `range`. Entries overlapping `range` are split
+    /// such that only the values inside of `range` are relabeled. An empty range leaves the table
+    /// unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::values::ValueTable;
+    ///
+    /// let mut table = ValueTable::new();
+    /// table.insert_range(0..=9, "Low");
+    /// table.insert_range(5..=5, "Five");
+    ///
+    /// assert_eq!(table.label(4), Some("Low"));
+    /// assert_eq!(table.label(5), Some("Five"));
+    /// assert_eq!(table.label(6), Some("Low"));
+    /// ```
+    pub fn insert_range(&mut self, range: RangeInclusive<i64>, label: &str) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        let overlapping = self
+            .labels
+            .range(..=end)
+            .filter(|(_, (other_end, _))| *other_end >= start)
+            .map(|(other_start, _)| *other_start)
+            .collect::<Vec<_>>();
+        for other_start in overlapping {
+            if let Some((other_end, other_label)) = self.labels.remove(&other_start) {
+                // keep the parts of the entry before and after the range
+                if other_start < start {
+                    self.labels
+                        .insert(other_start, (start - 1, other_label.clone()));
+                }
+                if other_end > end {
+                    self.labels.insert(end + 1, (other_end, other_label));
+                }
+            }
+        }
+
+        self.labels.insert(start, (end, String::from(label)));
+    }
+
+    /// Returns the label assigned to the raw `value`, if any.
+    pub fn label(&self, value: i64) -> Option<&str> {
+        match self.labels.range(..=value).next_back() {
+            Some((_, (end, label))) if value <= *end => Some(label.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Returns the raw value `label` is assigned to, i.e., the reverse of [label](Self::label). For
+    /// a label assigned to a range, the first value of the range is returned. If several entries
+    /// share `label`, the smallest value is returned.
+    ///
+    /// # Example
+    /// ```
+    /// use cantools::values::ValueTable;
+    ///
+    /// let mut table = ValueTable::new();
+    /// table.insert(1, "On");
+    /// table.insert_range(10..=19, "Mid");
+    ///
+    /// assert_eq!(table.value("On"), Some(1));
+    /// assert_eq!(table.value("Mid"), Some(10));
+    /// assert_eq!(table.value("Off"), None);
+    /// ```
+    pub fn value(&self, label: &str) -> Option<i64> {
+        self.labels
+            .iter()
+            .find(|(_, (_, other))| other == label)
+            .map(|(start, _)| *start)
+    }
+
+    /// Returns the number of entries, i.e., single values and ranges.
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    /// Returns `true` if the table contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ValueTable;
+    use std::ops::RangeInclusive;
+
+    #[test]
+    fn test_value_table_001() {
+        let mut table = ValueTable::new();
+        assert!(table.is_empty());
+
+        table.insert(0, "Off");
+        table.insert(1, "On");
+        table.insert(1, "Active");
+
+        assert_eq!(table.len(), 2);
+        assert_eq!(table.label(0), Some("Off"));
+        assert_eq!(table.label(1), Some("Active"));
+        assert_eq!(table.label(-1), None);
+    }
+
+    #[test]
+    fn test_value_table_002() {
+        let mut table = ValueTable::new();
+        table.insert_range(10..=19, "Mid");
+        table.insert_range(0..=9, "Low");
+
+        assert_eq!(table.len(), 2);
+        assert_eq!(table.label(-1), None);
+        assert_eq!(table.label(0), Some("Low"));
+        assert_eq!(table.label(9), Some("Low"));
+        assert_eq!(table.label(10), Some("Mid"));
+        assert_eq!(table.label(19), Some("Mid"));
+        assert_eq!(table.label(20), None);
+    }
+
+    #[test]
+    fn test_value_table_003() {
+        let mut table = ValueTable::new();
+        table.insert_range(0..=9, "Low");
+        table.insert_range(10..=19, "Mid");
+        table.insert_range(20..=29, "High");
+        table.insert_range(5..=14, "Replaced");
+        table.insert_range(RangeInclusive::new(30, 20), "Empty");
+
+        assert_eq!(table.len(), 4);
+        assert_eq!(table.label(4), Some("Low"));
+        assert_eq!(table.label(7), Some("Replaced"));
+        assert_eq!(table.label(15), Some("Mid"));
+        assert_eq!(table.label(25), Some("High"));
+
+        table.insert_range(0..=19, "Covered");
+        assert_eq!(table.len(), 2);
+        assert_eq!(table.label(0), Some("Covered"));
+        assert_eq!(table.label(19), Some("Covered"));
+    }
+
+    #[test]
+    fn test_value_table_004() {
+        // a catch-all range combined with specific values
+        let mut table = ValueTable::new();
+        table.insert_range(i64::MIN..=i64::MAX, "Reserved");
+        table.insert(0, "Off");
+        table.insert(1, "On");
+        table.insert(i64::MAX, "Invalid");
+
+        assert_eq!(table.len(), 5);
+        assert_eq!(table.label(i64::MIN), Some("Reserved"));
+        assert_eq!(table.label(-1), Some("Reserved"));
+        assert_eq!(table.label(0), Some("Off"));
+        assert_eq!(table.label(1), Some("On"));
+        assert_eq!(table.label(2), Some("Reserved"));
+        assert_eq!(table.label(i64::MAX - 1), Some("Reserved"));
+        assert_eq!(table.label(i64::MAX), Some("Invalid"));
+
+        // splitting a single range into three parts
+        let mut table = ValueTable::new();
+        table.insert_range(0..=9, "Low");
+        table.insert(5, "Five");
+        assert_eq!(table.len(), 3);
+        assert_eq!(table.label(0), Some("Low"));
+        assert_eq!(table.label(4), Some("Low"));
+        assert_eq!(table.label(5), Some("Five"));
+        assert_eq!(table.label(6), Some("Low"));
+        assert_eq!(table.label(9), Some("Low"));
+    }
+}
@@ -1,4 +1,13 @@
-use cantools::logging::{CANDumpLog, CANDumpLogEntry};
+use cantools::frame::CanId;
+use cantools::logging::{
+    merge_logs, signal_series, sort_by_time, windows_by_time, CANDumpBuilder, CANDumpEntry,
+    CANDumpLog, CANDumpLogEntry, CANDumpLogEntryConstructionError, CANDumpLogEntryParseError,
+    LogEvent,
+};
+use cantools::signals::Unsigned;
+use cantools::utils::Endian;
+use std::fmt::Write;
+use std::io::Cursor;
 
 #[test]
 fn can_dump_log_empty() {
@@ -31,3 +40,591 @@ fn can_dump_log_once_002() {
     );
     assert_eq!(iterator.next(), None);
 }
+
+#[test]
+fn can_dump_log_for_each_ref_001() {
+    let candump = CANDumpLog::open("candump/logs/once_2.log").unwrap();
+    let mut entries = Vec::new();
+    candump
+        .for_each_ref(|entry| entries.push(entry.into_owned()))
+        .unwrap();
+
+    assert_eq!(
+        entries,
+        vec![
+            CANDumpLogEntry::new(1647037105.079609, "vcan0", 0x42, vec![0x12], Some(0xA)).unwrap()
+        ]
+    );
+}
+
+#[test]
+fn can_dump_log_with_deltas_001() {
+    let candump = CANDumpLog::open("candump/logs/deltas.log").unwrap();
+    let mut iterator = candump.into_iter().with_deltas();
+
+    assert_eq!(
+        iterator.next(),
+        Some((
+            CANDumpLogEntry::new(1647037105.0, "vcan0", 0x42, vec![0x12], None).unwrap(),
+            None
+        ))
+    );
+    assert_eq!(
+        iterator.next(),
+        Some((
+            CANDumpLogEntry::new(1647037105.25, "vcan0", 0x43, vec![0x12, 0x34], None).unwrap(),
+            None
+        ))
+    );
+    assert_eq!(
+        iterator.next(),
+        Some((
+            CANDumpLogEntry::new(1647037105.5, "vcan0", 0x42, vec![0x13], None).unwrap(),
+            Some(0.5)
+        ))
+    );
+    assert_eq!(iterator.next(), None);
+}
+
+#[test]
+fn can_dump_log_merge_001() {
+    let logs = vec![
+        CANDumpLog::open("candump/logs/merge_1.log").unwrap(),
+        CANDumpLog::open("candump/logs/merge_2.log").unwrap(),
+    ];
+    let entries = merge_logs(logs).collect::<Vec<_>>();
+
+    let expected = [
+        (0.5, "can1", 0x02, 0x11),
+        (1.0, "can0", 0x01, 0x01),
+        (1.5, "can0", 0x01, 0x02),
+        (1.5, "can1", 0x02, 0x12),
+        (2.0, "can1", 0x02, 0x13),
+        (3.0, "can0", 0x01, 0x03),
+        (4.0, "can1", 0x02, 0x14),
+    ]
+    .into_iter()
+    .map(|(timestamp, interface, can_id, data)| {
+        CANDumpLogEntry::new(timestamp, interface, can_id, vec![data], None).unwrap()
+    })
+    .collect::<Vec<_>>();
+    assert_eq!(entries, expected);
+}
+
+#[test]
+fn can_dump_log_merge_002() {
+    let logs = vec![
+        CANDumpLog::open("candump/logs/empty.log").unwrap(),
+        CANDumpLog::open("candump/logs/once_1.log").unwrap(),
+    ];
+    let mut iterator = merge_logs(logs);
+    assert_eq!(
+        iterator.next(),
+        Some(CANDumpLogEntry::new(1647037105.079609, "vcan0", 0x42, vec![0x12], None).unwrap())
+    );
+    assert_eq!(iterator.next(), None);
+}
+
+#[test]
+fn can_dump_log_from_reader_001() {
+    let mut iterator = CANDumpLog::from_reader(Cursor::new(
+        "(1.0) can0 00001337#01\ninvalid\n(2.0) can1 000001FF#\n",
+    ));
+    assert_eq!(
+        iterator.next(),
+        Some(CANDumpLogEntry::new(1.0, "can0", 0x1337, vec![0x01], None).unwrap())
+    );
+    assert_eq!(
+        iterator.next(),
+        Some(CANDumpLogEntry::new(2.0, "can1", 0x1FF, vec![], None).unwrap())
+    );
+    assert_eq!(iterator.next(), None);
+}
+
+#[test]
+fn can_dump_log_with_errors_001() {
+    let candump = CANDumpLog::open("candump/logs/mixed.log").unwrap();
+    let mut iterator = candump.into_iter_with_errors();
+
+    assert_eq!(
+        iterator.next(),
+        Some(Ok(CANDumpLogEntry::new(
+            1647037105.079609,
+            "vcan0",
+            0x42,
+            vec![0x12],
+            None
+        )
+        .unwrap()))
+    );
+    assert_eq!(
+        iterator.next(),
+        Some(Err(CANDumpLogEntryParseError::ParseCanIdError))
+    );
+    assert_eq!(iterator.next(), None);
+
+    // the default iterator skips the malformed line
+    let candump = CANDumpLog::open("candump/logs/mixed.log").unwrap();
+    assert_eq!(candump.into_iter().count(), 1);
+}
+
+#[test]
+fn can_dump_log_parse_odd_data_001() {
+    let entry = "(1.0) vcan0 00000042#123".parse::<CANDumpLogEntry>();
+    assert_eq!(entry, Err(CANDumpLogEntryParseError::ParseCanDataError));
+}
+
+#[test]
+fn can_dump_log_parse_flag_001() {
+    let entry = "(1.5) vcan0 00000042##F51234"
+        .parse::<CANDumpLogEntry>()
+        .unwrap();
+    assert_eq!(
+        entry,
+        CANDumpLogEntry::new(1.5, "vcan0", 0x42, vec![0x12, 0x34], Some(0xF5)).unwrap()
+    );
+    assert_eq!(entry.to_string(), "(1.5) vcan0 00000042##F51234");
+
+    // a single nibble flag is still accepted
+    let entry = "(1.5) vcan0 00000042##51234"
+        .parse::<CANDumpLogEntry>()
+        .unwrap();
+    assert_eq!(
+        entry,
+        CANDumpLogEntry::new(1.5, "vcan0", 0x42, vec![0x12, 0x34], Some(0x05)).unwrap()
+    );
+    assert_eq!(entry.to_string(), "(1.5) vcan0 00000042##51234");
+
+    let entry = "(1.5) vcan0 00000042##F5"
+        .parse::<CANDumpLogEntry>()
+        .unwrap();
+    assert_eq!(
+        entry,
+        CANDumpLogEntry::new(1.5, "vcan0", 0x42, vec![], Some(0xF5)).unwrap()
+    );
+
+    let entry = "(1.5) vcan0 00000042##".parse::<CANDumpLogEntry>();
+    assert_eq!(entry, Err(CANDumpLogEntryParseError::MissingFlagData));
+
+    let entry = "(1.5) vcan0 00000042##G51234".parse::<CANDumpLogEntry>();
+    assert_eq!(entry, Err(CANDumpLogEntryParseError::ParseFlagError));
+}
+
+#[test]
+fn can_dump_log_can_id_round_trip_001() {
+    for line in ["(1.5) vcan0 123#1234", "(1.5) vcan0 1FFFFFFF#1234"] {
+        let entry = line.parse::<CANDumpLogEntry>().unwrap();
+        assert_eq!(entry.to_string(), line);
+    }
+
+    let entry = "(1.5) vcan0 123#1234".parse::<CANDumpLogEntry>().unwrap();
+    assert_eq!(
+        entry,
+        CANDumpLogEntry::new(1.5, "vcan0", CanId::Standard(0x123), vec![0x12, 0x34], None).unwrap()
+    );
+
+    let entry = "(1.5) vcan0 1FFFFFFF##21234"
+        .parse::<CANDumpLogEntry>()
+        .unwrap();
+    assert_eq!(
+        entry,
+        CANDumpLogEntry::new(1.5, "vcan0", 0x1FFFFFFF, vec![0x12, 0x34], Some(2)).unwrap()
+    );
+    assert_eq!(entry.to_string(), "(1.5) vcan0 1FFFFFFF##21234");
+}
+
+#[test]
+fn can_dump_log_write_001() {
+    let lines = ["(1.5) vcan0 123#1234", "(1.5) vcan0 00000042##F51234"];
+
+    let mut string = String::new();
+    for line in lines {
+        let entry = line.parse::<CANDumpLogEntry>().unwrap();
+        writeln!(string, "{}", entry).unwrap();
+    }
+    assert_eq!(
+        string,
+        "(1.5) vcan0 123#1234\n(1.5) vcan0 00000042##F51234\n"
+    );
+}
+
+#[test]
+fn can_dump_log_builder_001() {
+    let entries = vec![
+        CANDumpLogEntry::new(1.5, "vcan0", CanId::Standard(0x123), vec![0x12, 0x34], None).unwrap(),
+        CANDumpLogEntry::new(2.5, "vcan1", 0x1FFFFFFF, vec![0x56], Some(0x05)).unwrap(),
+    ];
+
+    let mut builder = CANDumpBuilder::new();
+    for line in ["(1.5) vcan0 123#1234", "(2.5) vcan1 1FFFFFFF##556"] {
+        builder.push(line.parse::<CANDumpLogEntry>().unwrap());
+    }
+
+    let parsed = CANDumpLog::from_reader(Cursor::new(builder.build())).collect::<Vec<_>>();
+    assert_eq!(parsed, entries);
+}
+
+#[test]
+fn can_dump_log_builder_002() {
+    let path = std::env::temp_dir().join("cantools_can_dump_log_builder_002.log");
+    CANDumpBuilder::new()
+        .entry(CANDumpLogEntry::new(1.5, "vcan0", 0x42, vec![0x12], None).unwrap())
+        .entry(CANDumpLogEntry::new(2.5, "vcan0", 0x43, vec![], None).unwrap())
+        .write_to(&path)
+        .unwrap();
+
+    let parsed = CANDumpLog::open(&path)
+        .unwrap()
+        .into_iter()
+        .collect::<Vec<_>>();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(
+        parsed,
+        vec![
+            CANDumpLogEntry::new(1.5, "vcan0", 0x42, vec![0x12], None).unwrap(),
+            CANDumpLogEntry::new(2.5, "vcan0", 0x43, vec![], None).unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn can_dump_log_whitespace_001() {
+    let expected = CANDumpLogEntry::new(1.5, "vcan0", 0x42, vec![0x12, 0x34], None).unwrap();
+
+    let entry = "(1.5) vcan0 00000042#1234 ".parse::<CANDumpLogEntry>();
+    assert_eq!(entry.as_ref(), Ok(&expected));
+
+    let entry = "(1.5)  vcan0 00000042#1234".parse::<CANDumpLogEntry>();
+    assert_eq!(entry.as_ref(), Ok(&expected));
+
+    let entry = "(1.5)\tvcan0 \t00000042#1234\r".parse::<CANDumpLogEntry>();
+    assert_eq!(entry, Ok(expected));
+}
+
+#[cfg(feature = "flate2")]
+#[test]
+fn can_dump_log_open_gz_001() {
+    let expected = CANDumpLog::open("candump/logs/deltas.log")
+        .unwrap()
+        .into_iter()
+        .collect::<Vec<_>>();
+    let entries = CANDumpLog::open_gz("candump/logs/deltas.log.gz")
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert!(!entries.is_empty());
+    assert_eq!(entries, expected);
+}
+
+#[cfg(feature = "flate2")]
+#[test]
+fn can_dump_log_open_gz_002() {
+    // the stream breaks off in the second entry, so only the first one is read
+    let entries = CANDumpLog::open_gz("candump/logs/truncated.log.gz")
+        .unwrap()
+        .collect::<Vec<_>>();
+    let expected = CANDumpLog::open("candump/logs/deltas.log")
+        .unwrap()
+        .into_iter()
+        .take(1)
+        .collect::<Vec<_>>();
+    assert_eq!(entries, expected);
+}
+
+#[test]
+fn can_dump_log_error_display_001() {
+    let err = "(1.0) can0".parse::<CANDumpLogEntry>().unwrap_err();
+    assert_eq!(err, CANDumpLogEntryParseError::MissingCompoundCanData);
+    assert_eq!(err.to_string(), "missing CAN-bus identifier and data");
+
+    let err = "(inf) can0 123#".parse::<CANDumpLogEntry>().unwrap_err();
+    assert_eq!(err.to_string(), "invalid entry: timestamp is not finite");
+}
+
+#[test]
+fn can_dump_log_error_boxed_001() {
+    fn parse(s: &str) -> Result<CANDumpLogEntry, Box<dyn std::error::Error>> {
+        Ok(s.parse::<CANDumpLogEntry>()?)
+    }
+
+    assert!(parse("(1.0) can0 123#1122").is_ok());
+
+    let err = parse("(inf) can0 123#").unwrap_err();
+    assert_eq!(err.to_string(), "invalid entry: timestamp is not finite");
+    assert_eq!(
+        err.source().map(|source| source.to_string()),
+        Some(String::from("timestamp is not finite"))
+    );
+}
+
+#[test]
+fn can_dump_log_signal_series_001() {
+    let log = CANDumpLog::open("candump/logs/deltas.log").unwrap();
+    let signal = Unsigned::new(0, 8, 0.5, 0.0, Endian::Little).unwrap();
+
+    let series = signal_series(log, 0x42, &signal).collect::<Vec<_>>();
+    assert_eq!(series, vec![(1647037105.0, 9.0), (1647037105.5, 9.5)]);
+
+    // the 8-bit signal at bit 8 only fits into the two-byte frame of 0x43
+    let log = CANDumpLog::open("candump/logs/deltas.log").unwrap();
+    let signal = Unsigned::new(8, 8, 1.0, 0.0, Endian::Little).unwrap();
+    let series = signal_series(log, 0x43, &signal).collect::<Vec<_>>();
+    assert_eq!(series, vec![(1647037105.25, 52.0)]);
+}
+
+#[test]
+fn can_dump_log_sort_by_time_001() {
+    let entry = |timestamp: f64, id: u32| {
+        CANDumpLogEntry::new(timestamp, "can0", id, vec![id as u8], None).unwrap()
+    };
+
+    let mut entries = vec![
+        entry(3.0, 0x42),
+        entry(1.0, 0x43),
+        entry(4.0, 0x42),
+        entry(1.0, 0x41),
+        entry(2.0, 0x43),
+    ];
+    sort_by_time(&mut entries);
+    assert_eq!(
+        entries,
+        vec![
+            entry(1.0, 0x43),
+            entry(1.0, 0x41),
+            entry(2.0, 0x43),
+            entry(3.0, 0x42),
+            entry(4.0, 0x42),
+        ]
+    );
+
+    entries.sort_by_key(|entry| entry.can_id());
+    assert_eq!(
+        entries
+            .iter()
+            .map(|entry| (entry.can_id().raw(), entry.timestamp()))
+            .collect::<Vec<_>>(),
+        vec![
+            (0x41, 1.0),
+            (0x42, 3.0),
+            (0x42, 4.0),
+            (0x43, 1.0),
+            (0x43, 2.0)
+        ]
+    );
+}
+
+#[test]
+fn can_dump_log_parse_timestamp_001() {
+    let entry = "(1704207845.500000) can0 123#11".parse::<CANDumpLogEntry>();
+    assert_eq!(
+        entry,
+        Ok(CANDumpLogEntry::new(
+            1704207845.5,
+            "can0",
+            CanId::Standard(0x123),
+            vec![0x11],
+            None
+        )
+        .unwrap())
+    );
+
+    let entry = "() can0 123#11".parse::<CANDumpLogEntry>();
+    assert_eq!(entry, Err(CANDumpLogEntryParseError::ParseTimestampError));
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn can_dump_log_parse_timestamp_002() {
+    let entry = "(2024-01-02 15:04:05.500000) can0 123#11".parse::<CANDumpLogEntry>();
+    assert_eq!(
+        entry,
+        Ok(CANDumpLogEntry::new(
+            1704207845.5,
+            "can0",
+            CanId::Standard(0x123),
+            vec![0x11],
+            None
+        )
+        .unwrap())
+    );
+
+    let entry = "(2024-01-02 15:04:05) can0 123#11"
+        .parse::<CANDumpLogEntry>()
+        .unwrap();
+    assert_eq!(entry.timestamp(), 1704207845.0);
+
+    let entry = "(2024-13-02 15:04:05.5) can0 123#11".parse::<CANDumpLogEntry>();
+    assert_eq!(entry, Err(CANDumpLogEntryParseError::ParseTimestampError));
+}
+
+#[cfg(not(feature = "chrono"))]
+#[test]
+fn can_dump_log_parse_timestamp_002() {
+    let entry = "(2024-01-02 15:04:05.500000) can0 123#11".parse::<CANDumpLogEntry>();
+    assert_eq!(entry, Err(CANDumpLogEntryParseError::ParseTimestampError));
+}
+
+#[test]
+fn can_dump_log_open_at_001() {
+    let entries = CANDumpLog::open("candump/logs/deltas.log")
+        .unwrap()
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    let mut iterator = CANDumpLog::open("candump/logs/deltas.log")
+        .unwrap()
+        .into_iter();
+    assert_eq!(iterator.byte_offset(), 0);
+    iterator.next();
+    let offset = iterator.byte_offset();
+    assert_eq!(offset, 38);
+
+    let resumed = CANDumpLog::open_at("candump/logs/deltas.log", offset).unwrap();
+    assert_eq!(resumed.byte_offset(), offset);
+    assert_eq!(resumed.collect::<Vec<_>>(), entries[1..]);
+}
+
+#[test]
+fn can_dump_log_windows_by_time_001() {
+    let candump = CANDumpLog::open("candump/logs/deltas.log").unwrap();
+    let windows = windows_by_time(candump, 0.5).collect::<Vec<_>>();
+
+    assert_eq!(windows.len(), 2);
+    assert_eq!(windows[0].0, 1647037105.0);
+    assert_eq!(windows[1].0, 1647037105.5);
+
+    let ids = windows
+        .iter()
+        .map(|(_, entries)| entries.iter().map(|e| e.can_id().raw()).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec![vec![0x42, 0x43], vec![0x42]]);
+}
+
+#[test]
+fn can_dump_log_with_errors_002() {
+    // a non-UTF-8 line between two valid ones
+    let bytes = b"(1.0) can0 00000042#12\n(1.5) can0 \xFF#\n(2.0) can1 000001FF#\n";
+    let mut iterator = CANDumpLog::from_reader(Cursor::new(&bytes[..])).with_errors();
+
+    assert_eq!(
+        iterator.next(),
+        Some(Ok(CANDumpLogEntry::new(
+            1.0,
+            "can0",
+            0x42,
+            vec![0x12],
+            None
+        )
+        .unwrap()))
+    );
+    assert_eq!(
+        iterator.next(),
+        Some(Err(CANDumpLogEntryParseError::InvalidUtf8))
+    );
+    assert_eq!(iterator.byte_offset(), 37);
+    assert_eq!(
+        iterator.next(),
+        Some(Ok(
+            CANDumpLogEntry::new(2.0, "can1", 0x1FF, vec![], None).unwrap()
+        ))
+    );
+    assert_eq!(iterator.next(), None);
+
+    // the default iterator skips the non-UTF-8 line
+    assert_eq!(CANDumpLog::from_reader(Cursor::new(&bytes[..])).count(), 2);
+}
+
+#[test]
+fn can_dump_log_events_001() {
+    let candump = CANDumpLog::open("candump/logs/events.log").unwrap();
+    let events = candump.into_events().collect::<Vec<_>>();
+    assert_eq!(
+        events,
+        vec![
+            LogEvent::Comment(String::from("capture of vcan0")),
+            LogEvent::Frame(
+                CANDumpLogEntry::new(1647037105.0, "vcan0", 0x42, vec![0x12], None).unwrap()
+            ),
+            LogEvent::Restart,
+            LogEvent::Frame(
+                CANDumpLogEntry::new(0.5, "vcan0", 0x43, vec![0x12, 0x34], None).unwrap()
+            ),
+        ]
+    );
+
+    // the default iterator only yields the frames
+    let candump = CANDumpLog::open("candump/logs/events.log").unwrap();
+    assert_eq!(candump.into_iter().count(), 2);
+}
+
+#[test]
+fn can_dump_log_events_002() {
+    // the comment after the first frame is not valid UTF-8 and is skipped
+    let candump = CANDumpLog::open("candump/logs/events_utf8.log").unwrap();
+    let events = candump.into_events().collect::<Vec<_>>();
+    let expected = CANDumpLog::open("candump/logs/events.log")
+        .unwrap()
+        .into_events()
+        .collect::<Vec<_>>();
+    assert_eq!(events.len(), 4);
+    assert_eq!(events, expected);
+}
+
+#[test]
+fn can_dump_log_from_raw_001() {
+    let raw = CANDumpEntry::new("vcan0", 0x1337, vec![0x01, 0x02]).unwrap();
+    let entry = CANDumpLogEntry::from(raw);
+    assert_eq!(
+        entry,
+        CANDumpLogEntry::new(
+            0.0,
+            "vcan0",
+            CanId::Extended(0x1337),
+            vec![0x01, 0x02],
+            None
+        )
+        .unwrap()
+    );
+
+    let raw = CANDumpEntry::from(entry);
+    assert_eq!(
+        raw,
+        CANDumpEntry::new("vcan0", 0x1337, vec![0x01, 0x02]).unwrap()
+    );
+}
+
+#[test]
+fn can_dump_log_to_raw_001() {
+    let entry: CANDumpLogEntry = "(1.5) can1 1FF#AABB R".parse().unwrap();
+    let raw = CANDumpEntry::from(entry);
+    assert_eq!(
+        raw,
+        CANDumpEntry::new("can1", 0x1FF, vec![0xAA, 0xBB]).unwrap()
+    );
+
+    // the timestamp, flag, and standard identifier are lost
+    let entry = CANDumpLogEntry::from(raw);
+    assert_eq!(entry.timestamp(), 0.0);
+    assert_eq!(entry.flag(), None);
+    assert_eq!(entry.can_id(), CanId::Extended(0x1FF));
+    assert_eq!(entry.interface(), "can1");
+}
+
+#[test]
+fn can_dump_log_data_too_long_001() {
+    let entry = CANDumpLogEntry::new(1.0, "can0", 0x42, vec![0u8; 100], None);
+    assert_eq!(entry, Err(CANDumpLogEntryConstructionError::DataTooLong));
+
+    let entry = CANDumpLogEntry::new(1.0, "can0", 0x42, vec![0u8; 64], Some(0x01));
+    assert!(entry.is_ok());
+
+    let line = format!("(1.0) can0 00000042##1{}", "01".repeat(65));
+    assert_eq!(
+        line.parse::<CANDumpLogEntry>(),
+        Err(CANDumpLogEntryParseError::ConstructionError(
+            CANDumpLogEntryConstructionError::DataTooLong
+        ))
+    );
+
+    let line = format!("(1.0) can0 00000042##1{}", "01".repeat(64));
+    assert!(line.parse::<CANDumpLogEntry>().is_ok());
+}
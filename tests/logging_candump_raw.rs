@@ -1,4 +1,10 @@
-use cantools::logging::{CANDump, CANDumpEntry, CANDumpEntryParseError};
+use cantools::logging::{
+    CANDump, CANDumpBuilder, CANDumpEntry, CANDumpEntryConstructionError, CANDumpEntryParseError,
+    CANDumpFormat,
+};
+use cantools::utils::Endian;
+use std::fmt::Write;
+use std::io::Cursor;
 
 #[test]
 fn can_dump_raw_empty() {
@@ -54,3 +60,296 @@ fn can_dump_raw_parse_3() {
     let entry2 = entry_string.parse();
     assert_eq!(entry, entry2.unwrap());
 }
+
+#[test]
+fn can_dump_raw_parse_with_1() {
+    let format = CANDumpFormat {
+        separator: '\t',
+        ..CANDumpFormat::default()
+    };
+    let entry = CANDumpEntry::parse_with("can0\t00001337\t[2]\t01\t02", &format);
+    assert_eq!(
+        entry,
+        Ok(CANDumpEntry::new("can0", 0x1337, vec![0x01, 0x02]).unwrap())
+    );
+
+    // the default format splits on any whitespace, including tabs
+    let entry = "can0\t00001337\t[2]\t01\t02".parse::<CANDumpEntry>();
+    assert_eq!(
+        entry,
+        Ok(CANDumpEntry::new("can0", 0x1337, vec![0x01, 0x02]).unwrap())
+    );
+
+    // a separator other than whitespace only matches itself
+    let format = CANDumpFormat {
+        separator: ';',
+        ..CANDumpFormat::default()
+    };
+    let entry = CANDumpEntry::parse_with("can0;1FF;[2];01 02", &format);
+    assert_eq!(entry, Err(CANDumpEntryParseError::ParseCanDataError));
+}
+
+#[test]
+fn can_dump_raw_parse_with_2() {
+    let entry = CANDumpEntry::parse_with("can0 1ab [2] a 0f", &CANDumpFormat::default());
+    assert_eq!(
+        entry,
+        Ok(CANDumpEntry::new("can0", 0x1AB, vec![0x0A, 0x0F]).unwrap())
+    );
+}
+
+#[test]
+fn can_dump_raw_parse_with_3() {
+    let format = CANDumpFormat {
+        id_radix: 10,
+        strict_dlc: false,
+        ..CANDumpFormat::default()
+    };
+    let entry = CANDumpEntry::parse_with("can0 427 [8] 01", &format);
+    assert_eq!(
+        entry,
+        Ok(CANDumpEntry::new("can0", 427, vec![0x01]).unwrap())
+    );
+
+    let entry = CANDumpEntry::parse_with("can0 427 [8] 01", &CANDumpFormat::default());
+    assert_eq!(entry, Err(CANDumpEntryParseError::DlcDataMismatch));
+}
+
+#[test]
+fn can_dump_raw_parse_with_4() {
+    let entry = CANDumpEntry::parse_with("can0 1FF ] 01", &CANDumpFormat::default());
+    assert_eq!(entry, Err(CANDumpEntryParseError::ParseDlcError));
+}
+
+#[test]
+fn can_dump_raw_from_reader_1() {
+    let mut iterator =
+        CANDump::from_reader(Cursor::new("can0 00001337 [1] 01\ninvalid\ncan1 1FF [0]\n"));
+    assert_eq!(
+        iterator.next(),
+        Some(CANDumpEntry::new("can0", 0x1337, vec![0x01]).unwrap())
+    );
+    assert_eq!(
+        iterator.next(),
+        Some(CANDumpEntry::new("can1", 0x1FF, vec![]).unwrap())
+    );
+    assert_eq!(iterator.next(), None);
+}
+
+#[test]
+fn can_dump_raw_with_errors_1() {
+    let candump = CANDump::open("candump/raw/mixed.log").unwrap();
+    let mut iterator = candump.into_iter_with_errors();
+
+    assert_eq!(
+        iterator.next(),
+        Some(Ok(CANDumpEntry::new("can0", 0x42, vec![0x12]).unwrap()))
+    );
+    assert_eq!(
+        iterator.next(),
+        Some(Err(CANDumpEntryParseError::DlcDataMismatch))
+    );
+    assert_eq!(iterator.next(), None);
+
+    // the default iterator skips the malformed line
+    let candump = CANDump::open("candump/raw/mixed.log").unwrap();
+    assert_eq!(candump.into_iter().count(), 1);
+}
+
+#[test]
+fn can_dump_raw_with_errors_2() {
+    // a non-UTF-8 line between two valid ones
+    let bytes = b"can0 00000042 [1] 12\ncan0 \xFF [0]\ncan1 1FF [0]\n";
+    let mut iterator = CANDump::from_reader(Cursor::new(&bytes[..])).with_errors();
+
+    assert_eq!(
+        iterator.next(),
+        Some(Ok(CANDumpEntry::new("can0", 0x42, vec![0x12]).unwrap()))
+    );
+    assert_eq!(
+        iterator.next(),
+        Some(Err(CANDumpEntryParseError::InvalidUtf8))
+    );
+    assert_eq!(iterator.byte_offset(), 32);
+    assert_eq!(
+        iterator.next(),
+        Some(Ok(CANDumpEntry::new("can1", 0x1FF, vec![]).unwrap()))
+    );
+    assert_eq!(iterator.next(), None);
+
+    // the default iterator skips the non-UTF-8 line
+    assert_eq!(CANDump::from_reader(Cursor::new(&bytes[..])).count(), 2);
+}
+
+#[test]
+fn can_dump_raw_write_1() {
+    let entry = "vcan0 00000042 [2] 12 34".parse::<CANDumpEntry>().unwrap();
+
+    let mut string = String::new();
+    write!(string, "{}|{}", entry, entry).unwrap();
+    assert_eq!(string, "vcan0 00000042 [2] 12 34|vcan0 00000042 [2] 12 34");
+}
+
+#[test]
+fn can_dump_raw_builder_1() {
+    let text = CANDumpBuilder::new()
+        .entry(CANDumpEntry::new("vcan0", 0x42, vec![0x12, 0x34]).unwrap())
+        .entry(CANDumpEntry::new("can1", 0x1337, vec![]).unwrap())
+        .build();
+
+    let parsed = CANDump::from_reader(Cursor::new(text)).collect::<Vec<_>>();
+    assert_eq!(
+        parsed,
+        vec![
+            CANDumpEntry::new("vcan0", 0x42, vec![0x12, 0x34]).unwrap(),
+            CANDumpEntry::new("can1", 0x1337, vec![]).unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn can_dump_raw_whitespace_1() {
+    let expected = CANDumpEntry::new("vcan0", 0x1337, vec![0x01, 0x02]).unwrap();
+
+    let entry = "vcan0 00001337 [2] 01 02 ".parse::<CANDumpEntry>();
+    assert_eq!(entry.as_ref(), Ok(&expected));
+
+    let entry = "vcan0  00001337 [2]  01 02".parse::<CANDumpEntry>();
+    assert_eq!(entry, Ok(expected));
+
+    let entry = "  can0  123   [0]  ".parse::<CANDumpEntry>();
+    assert_eq!(entry, Ok(CANDumpEntry::new("can0", 0x123, vec![]).unwrap()));
+
+    let entry = "can0\t123\t[1]\t01".parse::<CANDumpEntry>();
+    assert_eq!(
+        entry,
+        Ok(CANDumpEntry::new("can0", 0x123, vec![0x01]).unwrap())
+    );
+
+    let entry = "vcan0 \t00001337\t [2]  \t01\t02\r".parse::<CANDumpEntry>();
+    assert_eq!(
+        entry,
+        Ok(CANDumpEntry::new("vcan0", 0x1337, vec![0x01, 0x02]).unwrap())
+    );
+}
+
+#[cfg(feature = "flate2")]
+#[test]
+fn can_dump_raw_open_gz_1() {
+    let expected = CANDump::open("candump/raw/mixed.log")
+        .unwrap()
+        .into_iter()
+        .collect::<Vec<_>>();
+    let entries = CANDump::open_gz("candump/raw/mixed.log.gz")
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert!(!entries.is_empty());
+    assert_eq!(entries, expected);
+}
+
+#[cfg(feature = "flate2")]
+#[test]
+fn can_dump_raw_open_gz_2() {
+    // the stream breaks off in the second entry, so only the first one is read
+    let entries = CANDump::open_gz("candump/raw/truncated.log.gz")
+        .unwrap()
+        .collect::<Vec<_>>();
+    let expected = CANDump::open("candump/raw/mixed.log")
+        .unwrap()
+        .into_iter()
+        .take(1)
+        .collect::<Vec<_>>();
+    assert_eq!(entries, expected);
+}
+
+#[test]
+fn can_dump_raw_error_display_1() {
+    let err = "can0 00001337 [2] 01".parse::<CANDumpEntry>().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "dlc does not match the number of data bytes"
+    );
+
+    let err = "can0 00001337 ] 01".parse::<CANDumpEntry>().unwrap_err();
+    assert_eq!(err.to_string(), "invalid dlc");
+}
+
+#[test]
+fn can_dump_raw_parse_with_5() {
+    let format = CANDumpFormat {
+        id_endian: Endian::Little,
+        ..CANDumpFormat::default()
+    };
+
+    let entry = CANDumpEntry::parse_with("can0 37130000 [1] 01", &format);
+    assert_eq!(
+        entry,
+        Ok(CANDumpEntry::new("can0", 0x1337, vec![0x01]).unwrap())
+    );
+
+    let entry = CANDumpEntry::parse_with("can0 FF01 [0]", &format);
+    assert_eq!(entry, Ok(CANDumpEntry::new("can0", 0x1FF, vec![]).unwrap()));
+
+    let entry = CANDumpEntry::parse_with("can0 1FF [0]", &format);
+    assert_eq!(entry, Err(CANDumpEntryParseError::MissingCanIdData));
+
+    let entry = CANDumpEntry::parse_with("can0 0000001337 [0]", &format);
+    assert_eq!(entry, Err(CANDumpEntryParseError::MissingCanIdData));
+}
+
+#[test]
+fn can_dump_raw_open_at_1() {
+    let text = "can0 00000042 [1] 12\ninvalid\ncan1 1FF [0]\ncan1 1FF [1] 01\n";
+    let mut iterator = CANDump::from_reader(Cursor::new(text));
+    iterator.next();
+    assert_eq!(iterator.byte_offset(), 21);
+
+    // skipped lines advance the offset as well
+    iterator.next();
+    assert_eq!(iterator.byte_offset(), 42);
+
+    let candump = CANDump::open("candump/raw/mixed.log").unwrap();
+    let entries = candump.into_iter().collect::<Vec<_>>();
+    let mut iterator = CANDump::open("candump/raw/mixed.log").unwrap().into_iter();
+    iterator.next();
+    let resumed = CANDump::open_at("candump/raw/mixed.log", iterator.byte_offset()).unwrap();
+    assert_eq!(resumed.collect::<Vec<_>>(), entries[1..]);
+}
+
+#[test]
+fn can_dump_raw_without_dlc_1() {
+    let expected = CANDumpEntry::new("can0", 0x1FF, vec![0x01, 0x02, 0x03]).unwrap();
+
+    let entry = "can0 1FF [3] 01 02 03".parse::<CANDumpEntry>();
+    assert_eq!(entry.as_ref(), Ok(&expected));
+
+    let entry = "can0 1FF 01 02 03".parse::<CANDumpEntry>();
+    assert_eq!(entry.as_ref(), Ok(&expected));
+    assert_eq!(entry.unwrap().to_string(), "can0 000001FF [3] 01 02 03");
+
+    let entry = "can0 1FF 01 zz".parse::<CANDumpEntry>();
+    assert_eq!(entry, Err(CANDumpEntryParseError::ParseCanDataError));
+
+    let entry = "can0 1FF [3 01 02 03".parse::<CANDumpEntry>();
+    assert_eq!(entry, Err(CANDumpEntryParseError::ParseDlcError));
+
+    let entry = "can0 1FF".parse::<CANDumpEntry>();
+    assert_eq!(entry, Err(CANDumpEntryParseError::MissingDlcData));
+}
+
+#[test]
+fn can_dump_raw_data_too_long_1() {
+    let entry = CANDumpEntry::new("can0", 0x42, vec![0u8; 100]);
+    assert_eq!(entry, Err(CANDumpEntryConstructionError::DataTooLong));
+    assert_eq!(entry.unwrap_err().to_string(), "data exceeds 64 bytes");
+
+    assert!(CANDumpEntry::new("can0", 0x42, vec![0u8; 64]).is_ok());
+
+    let line = format!("can0 42 [65]{}", " 01".repeat(65));
+    assert_eq!(
+        line.parse::<CANDumpEntry>(),
+        Err(CANDumpEntryParseError::ConstructionError(
+            CANDumpEntryConstructionError::DataTooLong
+        ))
+    );
+}
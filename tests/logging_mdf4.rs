@@ -0,0 +1,72 @@
+use cantools::data::CANRead;
+use cantools::frame::CanId;
+use cantools::logging::{Mdf4, Mdf4Error};
+
+/// The fixture contains two data groups. The first one stores frames with an IDE channel and
+/// integer timestamps in microseconds interleaved with records of another channel group. The
+/// second one stores floating-point timestamps and flags extended identifiers by bit 31 of the ID.
+#[test]
+fn mdf4_open_001() {
+    let file = Mdf4::open("mdf4/can.mf4").unwrap();
+    assert_eq!(file.len(), 4);
+
+    let entries = file
+        .into_iter()
+        .map(|entry| (entry.timestamp(), entry.can_id(), entry.data().to_vec()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        entries,
+        vec![
+            (0.5, CanId::Standard(0x123), vec![0x11, 0x22]),
+            (1.0, CanId::Extended(0x42), vec![0xDE, 0xAD, 0xBE, 0xEF]),
+            (
+                1.5,
+                CanId::Extended(0x1FFFFFFF),
+                vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]
+            ),
+            (2.25, CanId::Standard(0x7FF), vec![0xAA, 0xBB, 0xCC, 0xDD]),
+        ]
+    );
+}
+
+#[test]
+fn mdf4_open_002() {
+    let bytes = std::fs::read("mdf4/can.mf4").unwrap();
+    assert_eq!(
+        Mdf4::from_bytes(&bytes[..bytes.len() - 1]),
+        Err(Mdf4Error::InvalidBlock)
+    );
+    assert_eq!(
+        Mdf4::open("mdf4/missing.mf4"),
+        Err(Mdf4Error::Io(std::io::ErrorKind::NotFound))
+    );
+    assert_eq!(
+        Mdf4::open("candump/logs/mixed.log"),
+        Err(Mdf4Error::InvalidIdentification)
+    );
+}
+
+#[test]
+fn mdf4_open_003() {
+    let mut bytes = std::fs::read("mdf4/can.mf4").unwrap();
+    let link = |bytes: &[u8], offset: usize| {
+        u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap()) as usize
+    };
+
+    // locate the channel blocks whose name links the text `CAN_DataFrame.ID`
+    let channels = (0..bytes.len() - 4)
+        .filter(|offset| &bytes[*offset..*offset + 4] == b"##CN")
+        .filter(|offset| {
+            let text = link(&bytes, offset + 24 + 16);
+            bytes[text + 24..].starts_with(b"CAN_DataFrame.ID\0")
+        })
+        .collect::<Vec<_>>();
+    assert!(!channels.is_empty());
+
+    // a bit offset beyond the first byte must be rejected instead of read out of bounds
+    for offset in channels {
+        let link_count = link(&bytes, offset + 16);
+        bytes[offset + 24 + 8 * link_count + 3] = 250;
+    }
+    assert_eq!(Mdf4::from_bytes(&bytes), Err(Mdf4Error::InvalidBlock));
+}
@@ -0,0 +1,138 @@
+//! Property-based round-trip tests of the Unsigned and Signed signals.
+//!
+//! The number of cases per property defaults to 64 to keep the test suite fast. Set the
+//! `PROPTEST_CASES` environment variable for a more thorough run, e.g.,
+//! `PROPTEST_CASES=100000 cargo test --test signals_round_trip`.
+//!
+//! The signals are limited to 52 bits such that every raw value is exactly representable by an
+//! `f64`.
+
+use cantools::decode::TryDecode;
+use cantools::encode::TryEncode;
+use cantools::signals::{Max, Min, Signed, Unsigned};
+use cantools::utils::Endian;
+use proptest::prelude::*;
+
+fn cases() -> u32 {
+    std::env::var("PROPTEST_CASES")
+        .ok()
+        .and_then(|cases| cases.parse().ok())
+        .unwrap_or(64)
+}
+
+fn endian(big: bool) -> Endian {
+    if big {
+        Endian::Big
+    } else {
+        Endian::Little
+    }
+}
+
+/// Generates `(big, start, length)` of signals fitting into 8 bytes.
+fn layout(min_length: u16) -> impl Strategy<Value = (bool, u16, u16)> {
+    (any::<bool>(), 0u16..64, min_length..=52).prop_filter(
+        "signal exceeds 8 bytes",
+        |(big, start, length)| {
+            Unsigned::new(*start, *length, 1.0, 0.0, endian(*big))
+                .unwrap()
+                .validate(8)
+                .is_ok()
+        },
+    )
+}
+
+/// Generates a factor that is a power of two and an integer offset. Scaling raw values by these
+/// is exact in `f64`.
+fn exact_scaling() -> impl Strategy<Value = (f64, f64)> {
+    (-2i32..=2, -1000i32..=1000).prop_map(|(exp, offset)| (2f64.powi(exp), offset as f64))
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(cases()))]
+
+    #[test]
+    fn unsigned_encode_decoded_value_keeps_data(
+        (big, start, length) in layout(1),
+        (factor, offset) in exact_scaling(),
+        data in any::<[u8; 8]>(),
+    ) {
+        let sig = Unsigned::new(start, length, factor, offset, endian(big)).unwrap();
+        let value: f64 = sig.try_decode(&data).unwrap();
+
+        let mut encoded = data;
+        prop_assert_eq!(sig.try_encode(&mut encoded, value), Ok(()));
+        prop_assert_eq!(encoded, data);
+    }
+
+    #[test]
+    fn signed_encode_decoded_value_keeps_data(
+        (big, start, length) in layout(1),
+        (factor, offset) in exact_scaling(),
+        data in any::<[u8; 8]>(),
+    ) {
+        let sig = Signed::new(start, length, factor, offset, endian(big)).unwrap();
+        let value: f64 = sig.try_decode(&data).unwrap();
+
+        let mut encoded = data;
+        prop_assert_eq!(sig.try_encode(&mut encoded, value), Ok(()));
+        prop_assert_eq!(encoded, data);
+    }
+
+    #[test]
+    fn unsigned_decode_encoded_value_within_one_lsb(
+        (big, start, length) in layout(1),
+        factor in 0.001f64..100.0,
+        offset in -1000f64..1000.0,
+        position in 0f64..=1.0,
+        data in any::<[u8; 8]>(),
+    ) {
+        let sig = Unsigned::new(start, length, factor, offset, endian(big)).unwrap();
+        let value = sig.min() + position * (sig.max() - sig.min());
+
+        let mut encoded = data;
+        prop_assert_eq!(sig.try_encode(&mut encoded, value), Ok(()));
+        let decoded: f64 = sig.try_decode(&encoded).unwrap();
+        prop_assert!((decoded - value).abs() <= factor, "{} != {}", decoded, value);
+    }
+
+    #[test]
+    fn signed_decode_encoded_value_within_one_lsb(
+        (big, start, length) in layout(1),
+        factor in 0.001f64..100.0,
+        offset in -1000f64..1000.0,
+        position in 0f64..=1.0,
+        data in any::<[u8; 8]>(),
+    ) {
+        let sig = Signed::new(start, length, factor, offset, endian(big)).unwrap();
+        let value = sig.min() + position * (sig.max() - sig.min());
+
+        let mut encoded = data;
+        prop_assert_eq!(sig.try_encode(&mut encoded, value), Ok(()));
+        let decoded: f64 = sig.try_decode(&encoded).unwrap();
+        prop_assert!((decoded - value).abs() <= factor, "{} != {}", decoded, value);
+    }
+
+    #[test]
+    fn encode_only_writes_signal_bits(
+        (big, start, length) in layout(1),
+        raw in any::<u64>(),
+        data in any::<[u8; 8]>(),
+    ) {
+        let sig = Unsigned::new(start, length, 1.0, 0.0, endian(big)).unwrap();
+        let raw = raw & (u64::MAX >> (64 - length));
+
+        // clearing the signal bits of both buffers must yield the same data
+        let mut encoded = data;
+        prop_assert_eq!(sig.try_encode(&mut encoded, raw as f64), Ok(()));
+        let mut cleared = data;
+        sig.try_encode(&mut cleared, 0.0).unwrap();
+        sig.try_encode(&mut encoded, 0.0).unwrap();
+        prop_assert_eq!(encoded, cleared);
+
+        // setting every bit of the signal touches exactly `length` bits
+        let mut ones = [0u8; 8];
+        sig.try_encode(&mut ones, sig.max()).unwrap();
+        let count = ones.iter().map(|byte| byte.count_ones()).sum::<u32>();
+        prop_assert_eq!(count, length as u32);
+    }
+}